@@ -9,6 +9,7 @@ use ratatui::Terminal;
 use throbber_widgets_tui::ThrobberState;
 use tui_textarea::TextArea;
 
+use crate::app::app_logic::code_snippet_popup::CodeSnippetPopup;
 use crate::app::app_logic::new_request_popup::NewRequestPopup;
 use crate::app::app_states::AppState;
 use crate::app::files::config::Config;
@@ -21,6 +22,8 @@ use crate::request::request::KeyValue;
 
 use crate::utils::choice_popup::ChoicePopup;
 use crate::utils::cookies_popup::CookiesPopup;
+use crate::utils::graphql_editor::GraphQLEditor;
+use crate::utils::graphql_schema_popup::GraphQLSchemaPopup;
 use crate::utils::help_popup::HelpPopup;
 use crate::utils::script_console::ScriptConsole;
 use crate::utils::settings_popup::SettingsPopup;
@@ -70,12 +73,18 @@ pub struct App<'a> {
     pub rename_collection_input: TextInput,
     pub new_request_popup: NewRequestPopup,
     pub rename_request_input: TextInput,
+    pub new_folder_input: TextInput,
+    /// Tree path of the collection or folder in which the folder will be created
+    pub new_folder_parent: Vec<usize>,
+    pub rename_folder_input: TextInput,
 
     pub delete_collection_popup: ValidationPopup,
     pub delete_request_popup: ValidationPopup,
+    pub delete_folder_popup: ValidationPopup,
 
     /* Request */
     pub url_text_input: TextInput,
+    pub method_text_input: TextInput,
 
     pub query_params_table: StatefulCustomTable,
 
@@ -83,6 +92,7 @@ pub struct App<'a> {
     pub auth_basic_username_text_input: TextInput,
     pub auth_basic_password_text_input: TextInput,
     pub auth_bearer_token_text_input: TextInput,
+    pub auth_field_text_input: TextInput,
 
     pub headers_table: StatefulCustomTable,
 
@@ -90,12 +100,16 @@ pub struct App<'a> {
     pub body_form_table: StatefulCustomTable,
     pub body_text_area: TextArea<'a>,
     pub body_text_area_vim_emulation: Vim,
+    pub graphql_editor: GraphQLEditor<'a>,
 
     pub request_settings_popup: SettingsPopup,
+    pub code_snippet_popup: CodeSnippetPopup,
+    pub graphql_schema_popup: GraphQLSchemaPopup,
 
     pub result_throbber_state: ThrobberState,
     pub result_vertical_scrollbar: StatefulScrollbar,
     pub result_horizontal_scrollbar: StatefulScrollbar,
+    pub websocket_message_text_input: TextInput,
 
     pub script_console: ScriptConsole<'a>,
 
@@ -136,7 +150,11 @@ impl App<'_> {
             request_result_tab: RequestResultTabs::Body,
 
             creation_popup: ChoicePopup {
-                choices: vec![String::from("Collection"), String::from("Request")],
+                choices: vec![
+                    String::from("Collection"),
+                    String::from("Request"),
+                    String::from("Folder"),
+                ],
                 selection: 0,
             },
 
@@ -144,12 +162,17 @@ impl App<'_> {
             rename_collection_input: TextInput::default(),
             new_request_popup: NewRequestPopup::default(),
             rename_request_input: TextInput::default(),
+            new_folder_input: TextInput::default(),
+            new_folder_parent: vec![],
+            rename_folder_input: TextInput::default(),
 
             delete_collection_popup: ValidationPopup::default(),
             delete_request_popup: ValidationPopup::default(),
+            delete_folder_popup: ValidationPopup::default(),
 
             /* Request */
             url_text_input: TextInput::default(),
+            method_text_input: TextInput::default(),
 
             query_params_table: StatefulCustomTable::default(),
 
@@ -157,6 +180,7 @@ impl App<'_> {
             auth_basic_username_text_input: TextInput::default(),
             auth_basic_password_text_input: TextInput::default(),
             auth_bearer_token_text_input: TextInput::default(),
+            auth_field_text_input: TextInput::default(),
 
             headers_table: StatefulCustomTable::default(),
 
@@ -164,12 +188,16 @@ impl App<'_> {
             body_form_table: StatefulCustomTable::default(),
             body_text_area: TextArea::default(),
             body_text_area_vim_emulation: Vim::default(),
+            graphql_editor: GraphQLEditor::default(),
 
             request_settings_popup: SettingsPopup::default(),
+            code_snippet_popup: CodeSnippetPopup::default(),
+            graphql_schema_popup: GraphQLSchemaPopup::default(),
 
             result_throbber_state: ThrobberState::default(),
             result_vertical_scrollbar: StatefulScrollbar::default(),
             result_horizontal_scrollbar: StatefulScrollbar::default(),
+            websocket_message_text_input: TextInput::default(),
 
             script_console: ScriptConsole::default(),
 
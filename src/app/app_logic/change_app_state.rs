@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::app::app::App;
 use crate::app::app_states::AppState;
 use crate::app::ui::param_tabs::param_tabs::RequestParamsTabs;
+use crate::app::ui::result_tabs::RequestResultTabs;
 use crate::request::body::ContentType;
 use crate::utils::cookie_table::cookie_to_row;
 
@@ -80,11 +81,32 @@ impl App<'_> {
             0
         };
 
+        // Same for the folder
+        self.new_request_popup.selected_folder = self.get_tree_selection_folder();
         self.new_request_popup.selected_collection = popup_selected_collection_index;
         self.new_request_popup.max_selection = collections_length;
         self.state = AppState::CreatingNewRequest;
     }
 
+    pub fn create_new_folder_state(&mut self) {
+        // Cannot create a folder if there is no collection
+        if self.collections.is_empty() {
+            return;
+        }
+
+        let selected_collection = &self.collections_tree.state.selected();
+
+        // The folder is created next to the selected element, or in the first collection
+        let collection_index = if !selected_collection.is_empty() {
+            selected_collection[0]
+        } else {
+            0
+        };
+
+        self.new_folder_parent = [vec![collection_index], self.get_tree_selection_folder()].concat();
+        self.state = AppState::CreatingNewFolder;
+    }
+
     pub fn delete_collection_state(&mut self) {
         self.delete_collection_popup.state = false;
         self.state = AppState::DeletingCollection;
@@ -95,6 +117,11 @@ impl App<'_> {
         self.state = AppState::DeletingRequest;
     }
 
+    pub fn delete_folder_state(&mut self) {
+        self.delete_folder_popup.state = false;
+        self.state = AppState::DeletingFolder;
+    }
+
     pub fn rename_collection_state(&mut self) {
         let selected_request_index = self.collections_tree.state.selected();
 
@@ -109,9 +136,9 @@ impl App<'_> {
         let selected_request_index = self.collections_tree.state.selected();
 
         {
-            let selected_request = self.collections[selected_request_index[0]].requests
-                [selected_request_index[1]]
-                .read();
+            let local_selected_request =
+                self.get_request_as_local_from_indexes(selected_request_index);
+            let selected_request = local_selected_request.read();
             self.rename_request_input.text.clone_from(&selected_request.name);
             self.rename_request_input.cursor_position = selected_request.name.len();
         }
@@ -119,6 +146,19 @@ impl App<'_> {
         self.state = AppState::RenamingRequest;
     }
 
+    pub fn rename_folder_state(&mut self) {
+        let selected_folder_index = self.collections_tree.state.selected();
+
+        let folder_name = &self.collections[selected_folder_index[0]]
+            .get_folder(&selected_folder_index[1..])
+            .unwrap()
+            .name;
+        self.rename_folder_input.text.clone_from(folder_name);
+        self.rename_folder_input.cursor_position = folder_name.len();
+
+        self.state = AppState::RenamingFolder;
+    }
+
     pub fn select_request_state(&mut self) {
         self.state = AppState::SelectedRequest;
         self.update_inputs();
@@ -130,6 +170,11 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn edit_request_method_state(&mut self) {
+        self.state = AppState::EditingRequestMethod;
+        self.update_inputs();
+    }
+
     pub fn edit_request_param_state(&mut self) {
         self.state = AppState::EditingRequestParam;
         self.update_inputs();
@@ -150,6 +195,11 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn edit_request_auth_field_state(&mut self) {
+        self.state = AppState::EditingRequestAuthField;
+        self.update_inputs();
+    }
+
     pub fn edit_request_header_state(&mut self) {
         self.state = AppState::EditingRequestHeader;
         self.update_inputs();
@@ -188,7 +238,8 @@ impl App<'_> {
                 | ContentType::Json(_)
                 | ContentType::Xml(_)
                 | ContentType::Html(_)
-                | ContentType::Javascript(_) => {
+                | ContentType::Javascript(_)
+                | ContentType::GraphQL { .. } => {
                     self.state = AppState::EditingRequestBodyString;
                 }
                 _ => {
@@ -198,6 +249,7 @@ impl App<'_> {
         }
 
         self.request_param_tab = RequestParamsTabs::Body;
+        self.graphql_editor.completions = vec![];
         self.update_inputs();
     }
 
@@ -223,4 +275,42 @@ impl App<'_> {
 
         self.state = AppState::EditingRequestSettings;
     }
+
+    pub fn edit_websocket_message_state(&mut self) {
+        self.request_result_tab = RequestResultTabs::Body;
+        self.state = AppState::EditingWebSocketMessage;
+    }
+
+    pub fn display_code_snippet_state(&mut self) {
+        self.update_code_snippet();
+
+        self.state = AppState::DisplayingCodeSnippet;
+    }
+
+    pub fn display_graphql_schema_state(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        {
+            let selected_request = local_selected_request.read();
+
+            let schema = match &selected_request.graphql_schema {
+                None => return,
+                Some(schema) => schema,
+            };
+
+            // Start from the query root type
+            let query_type_index = schema
+                .types
+                .iter()
+                .position(|graphql_type| Some(&graphql_type.name) == schema.query_type.as_ref())
+                .unwrap_or(0);
+
+            self.graphql_schema_popup.schema = Some(Arc::clone(schema));
+            self.graphql_schema_popup.types_state.select(Some(query_type_index));
+        }
+
+        self.graphql_schema_popup.scroll = 0;
+
+        self.state = AppState::DisplayingGraphQLSchema;
+    }
 }
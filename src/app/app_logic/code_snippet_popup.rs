@@ -0,0 +1,35 @@
+use ratatui::text::Line;
+use strum::IntoEnumIterator;
+
+use crate::app::files::export::code_snippets::CodeLanguage;
+
+#[derive(Default)]
+pub struct CodeSnippetPopup {
+    pub language: CodeLanguage,
+    pub snippet: String,
+    pub highlighted_snippet: Vec<Line<'static>>,
+    pub scroll: u16,
+}
+
+impl CodeSnippetPopup {
+    pub fn next_language(&mut self) {
+        self.language = CodeLanguage::from_repr(self.language as usize + 1).unwrap_or_default();
+    }
+
+    pub fn previous_language(&mut self) {
+        self.language = match self.language as usize {
+            0 => CodeLanguage::iter().next_back().unwrap(),
+            index => CodeLanguage::from_repr(index - 1).unwrap(),
+        };
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        if (self.scroll as usize) + 1 < self.highlighted_snippet.len() {
+            self.scroll += 1;
+        }
+    }
+}
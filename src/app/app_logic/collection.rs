@@ -1,22 +1,25 @@
 use std::sync::Arc;
 
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 
 use crate::app::app::App;
 use crate::app::startup::args::ARGS;
-use crate::request::auth::Auth;
+use crate::request::auth::{Auth, AwsSigV4, Jwt, OAuth1};
 use crate::request::body::ContentType;
-use crate::request::collection::Collection;
+use crate::request::collection::{Collection, CollectionElement, Folder};
 use crate::request::request::{Request, DEFAULT_HEADERS};
 use crate::request::settings::RequestSettings;
 
 impl App<'_> {
     pub fn reset_inputs(&mut self) {
         self.url_text_input.reset_input();
+        self.method_text_input.reset_input();
         self.query_params_table.selection_text_input.reset_input();
         self.auth_basic_username_text_input.reset_input();
         self.auth_basic_password_text_input.reset_input();
         self.auth_bearer_token_text_input.reset_input();
+        self.auth_field_text_input.reset_input();
         self.headers_table.selection_text_input.reset_input();
         self.body_form_table.selection_text_input.reset_input();
         self.body_file_text_input.reset_input();
@@ -30,6 +33,8 @@ impl App<'_> {
 
         self.url_text_input
             .enter_str(&selected_request.url_with_params_to_string());
+        self.method_text_input
+            .enter_str(&selected_request.method.to_string());
         self.query_params_table
             .rows
             .clone_from(&selected_request.params);
@@ -50,11 +55,11 @@ impl App<'_> {
         }
 
         match &selected_request.auth {
-            Auth::NoAuth => {
+            Auth::NoAuth | Auth::InheritFromCollection => {
                 self.auth_text_input_selection.max_selection = 0;
                 self.auth_text_input_selection.usable = false;
             }
-            Auth::BasicAuth(username, password) => {
+            Auth::BasicAuth(username, password) | Auth::Digest(username, password) => {
                 self.auth_text_input_selection.max_selection = 2;
                 self.auth_text_input_selection.usable = true;
 
@@ -67,6 +72,54 @@ impl App<'_> {
 
                 self.auth_bearer_token_text_input.enter_str(bearer_token);
             }
+            Auth::ApiKey(api_key) => {
+                self.auth_text_input_selection.max_selection = 3;
+                self.auth_text_input_selection.usable = true;
+
+                match self.auth_text_input_selection.selected {
+                    0 => self.auth_field_text_input.enter_str(&api_key.key),
+                    1 => self.auth_field_text_input.enter_str(&api_key.value),
+                    _ => {}
+                }
+            }
+            Auth::AwsSigV4(aws_sig_v4) => {
+                self.auth_text_input_selection.max_selection = AwsSigV4::FIELDS.len();
+                self.auth_text_input_selection.usable = true;
+
+                if let Some(field) = AwsSigV4::FIELDS.get(self.auth_text_input_selection.selected) {
+                    self.auth_field_text_input
+                        .enter_str(aws_sig_v4.get_field(*field));
+                }
+            }
+            Auth::Jwt(jwt) => {
+                self.auth_text_input_selection.max_selection = Jwt::FIELDS.len();
+                self.auth_text_input_selection.usable = true;
+
+                if let Some(field) = Jwt::FIELDS.get(self.auth_text_input_selection.selected) {
+                    self.auth_field_text_input
+                        .enter_str(&jwt.get_field(*field));
+                }
+            }
+            Auth::OAuth1(oauth1) => {
+                self.auth_text_input_selection.max_selection = OAuth1::FIELDS.len();
+                self.auth_text_input_selection.usable = true;
+
+                if let Some(field) = OAuth1::FIELDS.get(self.auth_text_input_selection.selected) {
+                    self.auth_field_text_input
+                        .enter_str(oauth1.get_field(*field));
+                }
+            }
+            Auth::OAuth2(oauth2) => {
+                let fields = oauth2.fields();
+
+                self.auth_text_input_selection.max_selection = fields.len();
+                self.auth_text_input_selection.usable = true;
+
+                if let Some(field) = fields.get(self.auth_text_input_selection.selected) {
+                    self.auth_field_text_input
+                        .enter_str(&oauth2.get_field(*field));
+                }
+            }
         }
 
         if !selected_request.headers.is_empty() {
@@ -118,6 +171,16 @@ impl App<'_> {
                 self.body_form_table.rows = Vec::new();
                 self.refresh_body_textarea(body);
             }
+            ContentType::GraphQL { query, variables } => {
+                self.body_form_table.rows = Vec::new();
+                self.refresh_body_textarea(query);
+                self.refresh_graphql_variables_textarea(variables);
+            }
+        }
+
+        // Only GraphQL bodies have a second text area
+        if !matches!(selected_request.body, ContentType::GraphQL { .. }) {
+            self.graphql_editor.selection = 0;
         }
 
         let pre_request_script = match &selected_request.scripts.pre_request_script {
@@ -136,17 +199,19 @@ impl App<'_> {
 
     pub fn reset_cursors(&mut self) {
         self.url_text_input.reset_cursor();
+        self.method_text_input.reset_cursor();
         self.query_params_table.selection_text_input.reset_cursor();
         self.auth_basic_username_text_input.reset_cursor();
         self.auth_basic_password_text_input.reset_cursor();
         self.auth_bearer_token_text_input.reset_cursor();
+        self.auth_field_text_input.reset_cursor();
         self.headers_table.selection_text_input.reset_cursor();
         self.body_form_table.selection_text_input.reset_cursor();
         self.body_file_text_input.reset_cursor();
     }
 
     pub fn select_request(&mut self) {
-        if self.collections_tree.state.selected().len() == 2 {
+        if self.is_request_tree_path(self.collections_tree.state.selected()) {
             self.collections_tree.set_selected();
             self.update_query_params_selection();
             self.update_headers_selection();
@@ -164,14 +229,11 @@ impl App<'_> {
     }
 
     pub fn select_request_or_expand_collection(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            1 => {
+        match self.is_request_tree_path(self.collections_tree.state.selected()) {
+            true => self.select_request(),
+            false => {
                 self.collections_tree.state.toggle_selected();
             }
-            2 => {
-                self.select_request();
-            }
-            _ => {}
         }
     }
 
@@ -179,6 +241,7 @@ impl App<'_> {
         match self.creation_popup.selection {
             0 => self.create_new_collection_state(),
             1 => self.create_new_request_state(),
+            2 => self.create_new_folder_state(),
             _ => {}
         }
     }
@@ -201,7 +264,11 @@ impl App<'_> {
 
         let new_collection = Collection {
             name: new_collection_name.clone(),
+            headers: vec![],
+            auth: Auth::NoAuth,
+            variables: IndexMap::new(),
             requests: vec![],
+            folders: vec![],
             path: ARGS
                 .directory
                 .join(format!("{}.{}", new_collection_name.clone(), file_format)),
@@ -231,22 +298,58 @@ impl App<'_> {
         };
 
         let selected_collection = self.new_request_popup.selected_collection;
+        let selected_folder = &self.new_request_popup.selected_folder;
 
-        self.collections[selected_collection]
-            .requests
-            .push(Arc::new(RwLock::new(new_request)));
+        let (_, requests) = self.collections[selected_collection]
+            .get_children_mut(selected_folder)
+            .unwrap();
+
+        requests.push(Arc::new(RwLock::new(new_request)));
 
         self.save_collection_to_file(selected_collection);
         self.normal_state();
     }
 
+    pub fn new_folder(&mut self) {
+        let new_folder_name = &self.new_folder_input.text;
+
+        if new_folder_name.trim().is_empty() {
+            return;
+        }
+
+        let new_folder = Folder {
+            name: new_folder_name.clone(),
+            requests: vec![],
+            folders: vec![],
+        };
+
+        let parent_tree_path = &self.new_folder_parent;
+
+        let (folders, _) = self.collections[parent_tree_path[0]]
+            .get_children_mut(&parent_tree_path[1..])
+            .unwrap();
+
+        folders.push(new_folder);
+
+        // The new folder shifts the tree identifiers of the requests next to it
+        let collection_index = parent_tree_path[0];
+        self.collections_tree.state.select(Vec::new());
+        self.collections_tree.selected = None;
+
+        self.save_collection_to_file(collection_index);
+        self.normal_state();
+    }
+
     pub fn delete_element(&mut self) {
         match self.collections_tree.state.selected().len() {
+            0 => {}
             // Selection on a collection
             1 => self.delete_collection_state(),
-            // Selection on a request
-            2 => self.delete_request_state(),
-            _ => {}
+            // Selection on a request or on a folder
+            _ => match self.is_request_tree_path(self.collections_tree.state.selected()) {
+                true => self.delete_request_state(),
+                false => self.delete_folder_state(),
+            },
         }
     }
 
@@ -264,12 +367,14 @@ impl App<'_> {
 
     pub fn delete_request(&mut self) {
         let selected_request_index = self.collections_tree.state.selected().to_vec();
-        let collection_index = selected_request_index[0];
-        let request_index = selected_request_index[1];
+        let (parent_folder, element_index) = split_tree_path(&selected_request_index);
 
-        self.collections[collection_index]
-            .requests
-            .remove(request_index);
+        let (folders, requests) = self.collections[selected_request_index[0]]
+            .get_children_mut(parent_folder)
+            .unwrap();
+
+        let request_index = element_index - folders.len();
+        requests.remove(request_index);
 
         self.collections_tree.state.select(Vec::new());
         self.collections_tree.selected = None;
@@ -278,13 +383,33 @@ impl App<'_> {
         self.normal_state();
     }
 
+    pub fn delete_folder(&mut self) {
+        let selected_folder_index = self.collections_tree.state.selected().to_vec();
+        let (parent_folder, folder_index) = split_tree_path(&selected_folder_index);
+
+        let (folders, _) = self.collections[selected_folder_index[0]]
+            .get_children_mut(parent_folder)
+            .unwrap();
+
+        folders.remove(folder_index);
+
+        self.collections_tree.state.select(Vec::new());
+        self.collections_tree.selected = None;
+
+        self.save_collection_to_file(selected_folder_index[0]);
+        self.normal_state();
+    }
+
     pub fn rename_element(&mut self) {
         match self.collections_tree.state.selected().len() {
+            0 => {}
             // Selection on a collection
             1 => self.rename_collection_state(),
-            // Selection on a request
-            2 => self.rename_request_state(),
-            _ => {}
+            // Selection on a request or on a folder
+            _ => match self.is_request_tree_path(self.collections_tree.state.selected()) {
+                true => self.rename_request_state(),
+                false => self.rename_folder_state(),
+            },
         }
     }
 
@@ -311,10 +436,7 @@ impl App<'_> {
         }
 
         let selected_request_index = self.collections_tree.state.selected();
-        let local_selected_request = self.get_request_as_local_from_indexes(&(
-            selected_request_index[0],
-            selected_request_index[1],
-        ));
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
             let mut selected_request = local_selected_request.write();
@@ -326,61 +448,240 @@ impl App<'_> {
         self.normal_state();
     }
 
-    pub fn move_request_up(&mut self) {
-        if self.collections_tree.state.selected().len() != 2 {
+    pub fn rename_folder(&mut self) {
+        let new_folder_name = self.rename_folder_input.text.clone();
+
+        if new_folder_name.trim().is_empty() {
             return;
         }
 
-        let mut selection = self.collections_tree.state.selected().to_vec();
+        let selected_folder_index = self.collections_tree.state.selected().to_vec();
+        let (parent_folder, folder_index) = split_tree_path(&selected_folder_index);
+
+        let (folders, _) = self.collections[selected_folder_index[0]]
+            .get_children_mut(parent_folder)
+            .unwrap();
+
+        folders[folder_index].name = new_folder_name;
+
+        self.save_collection_to_file(selected_folder_index[0]);
+        self.normal_state();
+    }
+
+    pub fn move_element_up(&mut self) {
+        self.move_element(false);
+    }
 
-        // Cannot decrement selection further
-        if selection[1] == 0 {
+    pub fn move_element_down(&mut self) {
+        self.move_element(true);
+    }
+
+    /// Swap the selected request or folder with its previous or next sibling of the same kind
+    fn move_element(&mut self, down: bool) {
+        if self.collections_tree.state.selected().len() < 2 {
             return;
         }
 
-        // Retrieve the request
-        let request = self.collections[selection[0]].requests.remove(selection[1]);
+        let selection = self.collections_tree.state.selected().to_vec();
+        let is_request = self.is_request_tree_path(&selection);
+        let (parent_folder, element_index) = split_tree_path(&selection);
+
+        let (folders, requests) = self.collections[selection[0]]
+            .get_children_mut(parent_folder)
+            .unwrap();
+
+        // Requests are placed after the folders in the tree
+        let (first_index, elements_length) = match is_request {
+            true => (folders.len(), requests.len()),
+            false => (0, folders.len()),
+        };
+
+        let index = element_index - first_index;
+
+        let new_index = match down {
+            // Cannot increment selection further
+            true if index + 1 >= elements_length => return,
+            true => index + 1,
+            // Cannot decrement selection further
+            false if index == 0 => return,
+            false => index - 1,
+        };
 
-        // Increment selection
-        selection[1] -= 1;
+        match is_request {
+            true => requests.swap(index, new_index),
+            false => folders.swap(index, new_index),
+        }
+
+        let mut new_selection = selection.clone();
+        *new_selection.last_mut().unwrap() = first_index + new_index;
 
-        // Insert the request at its new index
-        self.collections[selection[0]]
-            .requests
-            .insert(selection[1], request);
+        // The opened folders follow the moved folder
+        if !is_request {
+            self.collections_tree
+                .swap_opened(&selection, &new_selection);
+        }
 
         // Update the selection in order to move with the element
-        self.collections_tree.state.select(selection.clone());
+        self.collections_tree.state.select(new_selection.clone());
+
+        if self.collections_tree.selected.as_ref() == Some(&selection) {
+            self.collections_tree.selected = Some(new_selection);
+        } else if self.collections_tree.selected.as_ref() == Some(&new_selection) {
+            self.collections_tree.selected = Some(selection.clone());
+        }
 
         self.save_collection_to_file(selection[0]);
     }
 
-    pub fn move_request_down(&mut self) {
-        if self.collections_tree.state.selected().len() != 2 {
+    /// Move the selected request or folder at the end of the folder right above it
+    pub fn move_element_into_folder(&mut self) {
+        if self.collections_tree.state.selected().len() < 2 {
             return;
         }
 
-        let mut selection = self.collections_tree.state.selected().to_vec();
+        let selection = self.collections_tree.state.selected().to_vec();
+        let is_request = self.is_request_tree_path(&selection);
+        let (parent_folder, element_index) = split_tree_path(&selection);
+
+        let (folders, requests) = self.collections[selection[0]]
+            .get_children_mut(parent_folder)
+            .unwrap();
+
+        // The last folder for a request, since requests are placed after the folders
+        let target_index = match is_request {
+            true if folders.is_empty() => return,
+            true => folders.len() - 1,
+            false if element_index == 0 => return,
+            false => element_index - 1,
+        };
+
+        let new_index = match is_request {
+            true => {
+                let request = requests.remove(element_index - folders.len());
+                let target = &mut folders[target_index];
+
+                target.requests.push(request);
+                target.folders.len() + target.requests.len() - 1
+            }
+            false => {
+                let folder = folders.remove(element_index);
+                let target = &mut folders[target_index];
+
+                target.folders.push(folder);
+                target.folders.len() - 1
+            }
+        };
+
+        let target_folder = [&selection[..selection.len() - 1], &[target_index]].concat();
+        let new_selection = [target_folder.as_slice(), &[new_index]].concat();
+
+        self.collections_tree.move_node(&selection, &new_selection);
+        self.collections_tree.state.open(target_folder);
+
+        self.save_collection_to_file(selection[0]);
+    }
 
-        // Cannot increment selection further
-        if selection[1] == self.collections[selection[0]].requests.len() - 1 {
+    /// Move the selected request or folder out of its folder, into the parent one
+    pub fn move_element_out_of_folder(&mut self) {
+        // Collection, folder and element
+        if self.collections_tree.state.selected().len() < 3 {
             return;
         }
 
-        // Retrieve the request
-        let request = self.collections[selection[0]].requests.remove(selection[1]);
+        let selection = self.collections_tree.state.selected().to_vec();
+        let is_request = self.is_request_tree_path(&selection);
+        let (parent_folder, element_index) = split_tree_path(&selection);
+        let (&parent_index, grandparent_folder) = parent_folder.split_last().unwrap();
 
-        // Increment selection
-        selection[1] += 1;
+        let collection = &mut self.collections[selection[0]];
+        let (folders, requests) = collection.get_children_mut(parent_folder).unwrap();
 
-        // Insert the request at its new index
-        self.collections[selection[0]]
-            .requests
-            .insert(selection[1], request);
+        // A folder is placed right after its former parent, a request after the other requests
+        let new_index = match is_request {
+            true => {
+                let request = requests.remove(element_index - folders.len());
+                let (folders, requests) = collection.get_children_mut(grandparent_folder).unwrap();
 
-        // Update the selection in order to move with the element
-        self.collections_tree.state.select(selection.clone());
+                requests.push(request);
+                folders.len() + requests.len() - 1
+            }
+            false => {
+                let folder = folders.remove(element_index);
+                let (folders, _) = collection.get_children_mut(grandparent_folder).unwrap();
+
+                folders.insert(parent_index + 1, folder);
+                parent_index + 1
+            }
+        };
+
+        let new_selection = [&selection[..selection.len() - 2], &[new_index]].concat();
+
+        self.collections_tree.move_node(&selection, &new_selection);
 
         self.save_collection_to_file(selection[0]);
     }
+
+    /// Tree path (collection index excluded) of the folder containing the tree selection, or of the selected folder itself
+    pub fn get_tree_selection_folder(&self) -> Vec<usize> {
+        let selection = self.collections_tree.state.selected();
+
+        match selection.len() {
+            0 | 1 => vec![],
+            _ => match self.is_request_tree_path(selection) {
+                true => selection[1..selection.len() - 1].to_vec(),
+                false => selection[1..].to_vec(),
+            },
+        }
+    }
+
+    /// Collection name followed by the folder names, e.g. "Collection > Folder > Sub folder"
+    pub fn get_folder_display_path(&self, collection_index: usize, folder_path: &[usize]) -> String {
+        let collection = &self.collections[collection_index];
+        let mut names = vec![collection.name.clone()];
+
+        for depth in 1..=folder_path.len() {
+            if let Some(folder) = collection.get_folder(&folder_path[..depth]) {
+                names.push(folder.name.clone());
+            }
+        }
+
+        names.join(" > ")
+    }
+
+    pub fn is_request_tree_path(&self, tree_path: &[usize]) -> bool {
+        if tree_path.len() < 2 {
+            return false;
+        }
+
+        matches!(
+            self.collections[tree_path[0]].get_element(&tree_path[1..]),
+            Some(CollectionElement::Request(_))
+        )
+    }
+
+    /// Copy of the request with the collection headers, auth and variables applied.
+    /// The request values take precedence, so do the selected environment values over the collection variables
+    pub fn apply_collection_to_request(&self, collection_index: usize, request: &Request) -> Request {
+        let collection = &self.collections[collection_index];
+        let mut request = request.clone();
+
+        collection.apply_headers(&mut request);
+        request.auth = collection.resolve_auth(&request.auth).clone();
+
+        let env_keys: Vec<String> = match self.get_selected_env_as_local() {
+            None => vec![],
+            Some(local_env) => local_env.read().values.keys().cloned().collect(),
+        };
+
+        collection.apply_variables(&mut request, &env_keys);
+
+        request
+    }
+}
+
+/// Split a tree path between its parent folder path (collection index excluded) and the last element index
+fn split_tree_path(tree_path: &[usize]) -> (&[usize], usize) {
+    let (element_index, parent_path) = tree_path.split_last().unwrap();
+
+    (&parent_path[1..], *element_index)
 }
@@ -1,7 +1,8 @@
 pub mod change_app_state;
+pub mod code_snippet_popup;
 mod collection;
 mod environment;
 pub mod new_request_popup;
 mod param_tabs;
-mod request;
+pub mod request;
 mod result_tabs;
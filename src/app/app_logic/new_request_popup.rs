@@ -3,6 +3,8 @@ use crate::utils::text_input::TextInput;
 #[derive(Default)]
 pub struct NewRequestPopup {
     pub selected_collection: usize,
+    /// Tree path of the folder in which the request will be created, empty for the collection root
+    pub selected_folder: Vec<usize>,
     pub max_selection: usize,
     pub text_input: TextInput,
 }
@@ -14,6 +16,8 @@ impl NewRequestPopup {
         } else {
             self.selected_collection = 0;
         }
+
+        self.selected_folder.clear();
     }
 
     pub fn previous_collection(&mut self) {
@@ -22,5 +26,7 @@ impl NewRequestPopup {
         } else {
             self.selected_collection = self.max_selection - 1;
         }
+
+        self.selected_folder.clear();
     }
 }
@@ -1,10 +1,17 @@
 use crate::app::app::App;
 use crate::utils::next_auth;
-use crate::request::auth::Auth::{BasicAuth, BearerToken, NoAuth};
+use crate::request::auth::Auth::{
+    ApiKey, AwsSigV4, BasicAuth, BearerToken, Digest, InheritFromCollection, Jwt, NoAuth, OAuth1,
+    OAuth2,
+};
+use crate::request::auth::{
+    next_api_key_location, next_jwt_algorithm, next_oauth2_grant_type, JwtField, OAuth2Field,
+};
+use crate::request::auth;
 
 impl App<'_> {
     pub fn modify_request_auth(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -13,7 +20,7 @@ impl App<'_> {
             selected_request.auth = next_auth(&selected_request.auth);
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.load_request_auth_param_tab();
     }
 
@@ -22,8 +29,8 @@ impl App<'_> {
         let selected_request = local_selected_request.read();
 
         match selected_request.auth {
-            NoAuth => {}
-            BasicAuth(_, _) => match self.auth_text_input_selection.selected {
+            NoAuth | InheritFromCollection => {}
+            BasicAuth(_, _) | Digest(_, _) => match self.auth_text_input_selection.selected {
                 0 => self.edit_request_auth_username_state(),
                 1 => self.edit_request_auth_password_state(),
                 _ => {}
@@ -33,49 +40,102 @@ impl App<'_> {
                     self.edit_request_auth_bearer_token_state()
                 }
             }
+            ApiKey(_) => match self.auth_text_input_selection.selected {
+                0 | 1 => self.edit_request_auth_field_state(),
+                // The location is not a text, it is toggled instead
+                2 => {
+                    drop(selected_request);
+                    self.modify_request_api_key_location();
+                }
+                _ => {}
+            },
+            AwsSigV4(_) => {
+                if self.auth_text_input_selection.selected < auth::AwsSigV4::FIELDS.len() {
+                    self.edit_request_auth_field_state()
+                }
+            }
+            OAuth1(_) => {
+                if self.auth_text_input_selection.selected < auth::OAuth1::FIELDS.len() {
+                    self.edit_request_auth_field_state()
+                }
+            }
+            Jwt(_) => match auth::Jwt::FIELDS.get(self.auth_text_input_selection.selected) {
+                None => {}
+                // The algorithm is not a text, it is cycled through instead
+                Some(JwtField::Algorithm) => {
+                    drop(selected_request);
+                    self.modify_request_jwt_algorithm();
+                }
+                Some(_) => self.edit_request_auth_field_state(),
+            },
+            OAuth2(ref oauth2) => {
+                let field = oauth2.fields().get(self.auth_text_input_selection.selected).copied();
+
+                match field {
+                    None => {}
+                    // The grant type is not a text, it is cycled through instead
+                    Some(OAuth2Field::GrantType) => {
+                        drop(selected_request);
+                        self.modify_request_oauth2_grant_type();
+                    }
+                    Some(_) => self.edit_request_auth_field_state(),
+                }
+            }
         }
     }
 
     pub fn modify_request_auth_basic_username(&mut self) {
         let input_text = self.auth_basic_username_text_input.text.clone();
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
             let mut selected_request = local_selected_request.write();
 
-            if let BasicAuth(_, password) = &selected_request.auth {
-                selected_request.auth = BasicAuth(input_text, password.to_string());
+            match &selected_request.auth {
+                BasicAuth(_, password) => {
+                    selected_request.auth = BasicAuth(input_text, password.to_string())
+                }
+                Digest(_, password) => {
+                    selected_request.auth = Digest(input_text, password.to_string())
+                }
+                _ => {}
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn modify_request_auth_basic_password(&mut self) {
         let input_text = self.auth_basic_password_text_input.text.clone();
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
             let mut selected_request = local_selected_request.write();
 
-            if let BasicAuth(username, _) = &selected_request.auth {
-                selected_request.auth = BasicAuth(username.to_string(), input_text);
+            match &selected_request.auth {
+                BasicAuth(username, _) => {
+                    selected_request.auth = BasicAuth(username.to_string(), input_text)
+                }
+                Digest(username, _) => {
+                    selected_request.auth = Digest(username.to_string(), input_text)
+                }
+                _ => {}
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn modify_request_auth_bearer_token(&mut self) {
         let input_text = self.auth_bearer_token_text_input.text.clone();
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -86,7 +146,109 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
+        self.select_request_state();
+    }
+
+    pub fn modify_request_api_key_location(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            if let ApiKey(api_key) = &mut selected_request.auth {
+                api_key.location = next_api_key_location(&api_key.location);
+            }
+        }
+
+        self.save_collection_to_file(selected_request_index[0]);
+        self.update_inputs();
+    }
+
+    pub fn modify_request_oauth2_grant_type(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            if let OAuth2(oauth2) = &mut selected_request.auth {
+                oauth2.grant_type = next_oauth2_grant_type(&oauth2.grant_type);
+            }
+        }
+
+        self.save_collection_to_file(selected_request_index[0]);
+        self.update_inputs();
+    }
+
+    pub fn modify_request_jwt_algorithm(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            if let Jwt(jwt) = &mut selected_request.auth {
+                jwt.algorithm = next_jwt_algorithm(&jwt.algorithm);
+            }
+        }
+
+        self.save_collection_to_file(selected_request_index[0]);
+        self.update_inputs();
+    }
+
+    pub fn modify_request_auth_field(&mut self) {
+        let input_text = self.auth_field_text_input.text.clone();
+
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &mut selected_request.auth {
+                ApiKey(api_key) => match self.auth_text_input_selection.selected {
+                    0 => api_key.key = input_text,
+                    1 => api_key.value = input_text,
+                    _ => {}
+                },
+                AwsSigV4(aws_sig_v4) => {
+                    let field = auth::AwsSigV4::FIELDS.get(self.auth_text_input_selection.selected);
+
+                    if let Some(field) = field {
+                        *aws_sig_v4.get_field_mut(*field) = input_text;
+                    }
+                }
+                OAuth1(oauth1) => {
+                    let field = auth::OAuth1::FIELDS.get(self.auth_text_input_selection.selected);
+
+                    if let Some(field) = field {
+                        *oauth1.get_field_mut(*field) = input_text;
+                    }
+                }
+                Jwt(jwt) => {
+                    let field = auth::Jwt::FIELDS.get(self.auth_text_input_selection.selected);
+
+                    if let Some(value) = field.and_then(|field| jwt.get_field_mut(*field)) {
+                        *value = input_text;
+                    }
+                }
+                OAuth2(oauth2) => {
+                    let field = oauth2
+                        .fields()
+                        .get(self.auth_text_input_selection.selected)
+                        .copied();
+
+                    if let Some(value) = field.and_then(|field| oauth2.get_field_mut(field)) {
+                        *value = input_text;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 }
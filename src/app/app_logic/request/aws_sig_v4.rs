@@ -0,0 +1,269 @@
+use hmac::{Hmac, Mac};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION, HOST};
+use reqwest::{Request, RequestBuilder, Response};
+use sha2::{Digest, Sha256};
+use time::OffsetDateTime;
+
+use crate::app::app_logic::request::digest::hex;
+use crate::request::auth::AwsSigV4;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// Used when the body is streamed, e.g. a multipart form
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Headers that can be changed on the way, they are not signed
+const UNSIGNED_HEADERS: [&str; 5] = [
+    "authorization",
+    "connection",
+    "expect",
+    "user-agent",
+    "x-amzn-trace-id",
+];
+
+/// Everything but the unreserved characters is encoded
+const AWS_URI_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Sign the final request with AWS Signature Version 4, then send it.
+/// The credentials must already be resolved
+pub async fn send_with_aws_sig_v4(
+    request: RequestBuilder,
+    aws_sig_v4: &AwsSigV4,
+) -> reqwest::Result<Response> {
+    let (client, request) = request.build_split();
+    let mut request = request?;
+
+    sign_request(&mut request, aws_sig_v4, OffsetDateTime::now_utc());
+
+    client.execute(request).await
+}
+
+fn sign_request(request: &mut Request, aws_sig_v4: &AwsSigV4, now: OffsetDateTime) {
+    // e.g. 20240115T093000Z
+    let amz_date = format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        now.year(),
+        now.month() as u8,
+        now.day(),
+        now.hour(),
+        now.minute(),
+        now.second()
+    );
+    let date = &amz_date[..8];
+
+    let payload_hash = match request.body().and_then(|body| body.as_bytes()) {
+        Some(body) => hex(&Sha256::digest(body)),
+        None if request.body().is_none() => hex(&Sha256::digest(b"")),
+        None => String::from(UNSIGNED_PAYLOAD),
+    };
+
+    /* HEADERS */
+
+    let url = request.url().clone();
+
+    // The port is only written when it is not the default one, like the HTTP client does
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), None) => host.to_string(),
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (None, _) => String::new(),
+    };
+
+    let mut amz_headers = vec![
+        (HOST, host),
+        (HeaderName::from_static("x-amz-date"), amz_date.clone()),
+    ];
+
+    // Only S3 expects the payload hash as a header
+    if aws_sig_v4.service == "s3" {
+        amz_headers.push((
+            HeaderName::from_static("x-amz-content-sha256"),
+            payload_hash.clone(),
+        ));
+    }
+
+    if !aws_sig_v4.session_token.is_empty() {
+        amz_headers.push((
+            HeaderName::from_static("x-amz-security-token"),
+            aws_sig_v4.session_token.clone(),
+        ));
+    }
+
+    for (header_name, value) in amz_headers {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            request.headers_mut().insert(header_name, value);
+        }
+    }
+
+    let mut canonical_headers: Vec<(String, String)> = vec![];
+
+    for (header_name, value) in request.headers() {
+        if UNSIGNED_HEADERS.contains(&header_name.as_str()) {
+            continue;
+        }
+
+        // Spaces are collapsed, since they can be changed on the way too
+        let value = String::from_utf8_lossy(value.as_bytes())
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        match canonical_headers
+            .iter_mut()
+            .find(|(name, _)| name == header_name.as_str())
+        {
+            // Repeated headers are signed as a single comma separated one
+            Some((_, values)) => *values += &format!(",{value}"),
+            None => canonical_headers.push((header_name.to_string(), value)),
+        }
+    }
+
+    canonical_headers.sort();
+
+    let signed_headers = canonical_headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<&str>>()
+        .join(";");
+
+    /* CANONICAL REQUEST */
+
+    // Every service but S3 expects the path to be encoded twice
+    let canonical_uri = match aws_sig_v4.service.as_str() {
+        "s3" => url
+            .path()
+            .split('/')
+            .map(|segment| aws_uri_encode(&percent_decode_str(segment).decode_utf8_lossy()))
+            .collect::<Vec<String>>()
+            .join("/"),
+        _ => url
+            .path()
+            .split('/')
+            .map(aws_uri_encode)
+            .collect::<Vec<String>>()
+            .join("/"),
+    };
+
+    let mut query_pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| (aws_uri_encode(&key), aws_uri_encode(&value)))
+        .collect();
+
+    query_pairs.sort();
+
+    let canonical_query = query_pairs
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    // Sent as signed, the form encoding of the URL would write the spaces as +
+    match canonical_query.is_empty() {
+        true => request.url_mut().set_query(None),
+        false => request.url_mut().set_query(Some(&canonical_query)),
+    }
+
+    let canonical_request = [
+        request.method().to_string(),
+        canonical_uri,
+        canonical_query,
+        canonical_headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect::<String>(),
+        signed_headers.clone(),
+        payload_hash,
+    ]
+    .join("\n");
+
+    /* SIGNATURE */
+
+    let scope = format!(
+        "{date}/{}/{}/aws4_request",
+        aws_sig_v4.region, aws_sig_v4.service
+    );
+
+    let string_to_sign = [
+        ALGORITHM,
+        &amz_date,
+        &scope,
+        &hex(&Sha256::digest(canonical_request.as_bytes())),
+    ]
+    .join("\n");
+
+    let signing_key = [
+        date,
+        &aws_sig_v4.region,
+        &aws_sig_v4.service,
+        "aws4_request",
+    ]
+    .iter()
+    .fold(
+        format!("AWS4{}", aws_sig_v4.secret_access_key).into_bytes(),
+        |key, data| hmac_sha256(&key, data.as_bytes()),
+    );
+
+    let signature = hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+        "{ALGORITHM} Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        aws_sig_v4.access_key_id
+    );
+
+    if let Ok(authorization) = HeaderValue::from_str(&authorization) {
+        request.headers_mut().insert(AUTHORIZATION, authorization);
+    }
+}
+
+fn aws_uri_encode(input: &str) -> String {
+    utf8_percent_encode(input, AWS_URI_ENCODE_SET).to_string()
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    // HMAC accepts keys of any length
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, Request, Url};
+    use time::OffsetDateTime;
+
+    use super::sign_request;
+    use crate::request::auth::AwsSigV4;
+
+    /// get-vanilla-query-order-key-case, from the AWS Signature Version 4 test suite
+    #[test]
+    fn get_vanilla_query_order_key_case() {
+        let aws_sig_v4 = AwsSigV4 {
+            access_key_id: String::from("AKIDEXAMPLE"),
+            secret_access_key: String::from("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+            session_token: String::new(),
+            region: String::from("us-east-1"),
+            service: String::from("service"),
+        };
+
+        let mut request = Request::new(
+            Method::GET,
+            Url::parse("https://example.amazonaws.com/?Param2=value2&Param1=value1").unwrap(),
+        );
+
+        // 20150830T123600Z
+        let now = OffsetDateTime::from_unix_timestamp(1_440_938_160).unwrap();
+
+        sign_request(&mut request, &aws_sig_v4, now);
+
+        assert_eq!(request.url().query(), Some("Param1=value1&Param2=value2"));
+        assert_eq!(request.headers()["x-amz-date"], "20150830T123600Z");
+        assert_eq!(
+            request.headers()["authorization"],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=host;x-amz-date, Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"
+        );
+    }
+}
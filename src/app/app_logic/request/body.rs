@@ -5,7 +5,7 @@ use crate::utils::next_content_type;
 use reqwest::header::CONTENT_TYPE;
 use tui_textarea::TextArea;
 
-impl App<'_> {
+impl<'a> App<'a> {
     /// Reset selection if body form data is provided, either set it to none
     pub fn update_body_table_selection(&mut self) {
         let local_selected_request = self.get_selected_request_as_local();
@@ -30,7 +30,7 @@ impl App<'_> {
     }
 
     pub fn modify_request_form_data(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -48,12 +48,12 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn create_new_form_data(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -67,7 +67,7 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_body_table_selection();
         self.update_inputs();
     }
@@ -77,7 +77,7 @@ impl App<'_> {
             return;
         }
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -89,7 +89,7 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_body_table_selection();
         self.update_inputs();
     }
@@ -99,7 +99,7 @@ impl App<'_> {
             return;
         }
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -111,10 +111,31 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_inputs();
     }
 
+    pub fn is_graphql_body_selected(&self) -> bool {
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        matches!(selected_request.body, ContentType::GraphQL { .. })
+    }
+
+    /// The GraphQL variables are edited in their own text area
+    pub fn selected_body_text_area(&mut self) -> &mut TextArea<'a> {
+        match self.graphql_editor.selection {
+            1 => &mut self.graphql_editor.variables_text_area,
+            _ => &mut self.body_text_area,
+        }
+    }
+
+    pub fn refresh_graphql_variables_textarea(&mut self, text: &str) {
+        let lines: Vec<String> = text.lines().map(|line| line.to_string()).collect();
+
+        self.graphql_editor.variables_text_area = TextArea::new(lines);
+    }
+
     pub fn refresh_body_textarea(&mut self, text: &str) {
         let lines: Vec<String> = text.lines().map(|line| line.to_string()).collect();
 
@@ -122,7 +143,7 @@ impl App<'_> {
     }
 
     pub fn modify_request_body(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -142,17 +163,21 @@ impl App<'_> {
                 ContentType::Xml(_) => ContentType::Xml(body_string.clone()),
                 ContentType::Html(_) => ContentType::Html(body_string.clone()),
                 ContentType::Javascript(_) => ContentType::Javascript(body_string.clone()),
+                ContentType::GraphQL { .. } => ContentType::GraphQL {
+                    query: body_string.clone(),
+                    variables: self.graphql_editor.variables_text_area.lines().join("\n"),
+                },
             };
 
             selected_request.body = new_body;
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn modify_request_content_type(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -174,14 +199,15 @@ impl App<'_> {
                 | ContentType::Json(_)
                 | ContentType::Xml(_)
                 | ContentType::Html(_)
-                | ContentType::Javascript(_) => {
+                | ContentType::Javascript(_)
+                | ContentType::GraphQL { .. } => {
                     let content_type = &selected_request.body.to_content_type();
                     selected_request.modify_or_create_header(CONTENT_TYPE.as_str(), content_type)
                 }
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_body_table_selection();
         self.load_request_body_param_tab();
     }
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use md5::Md5;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use rand::distributions::{Alphanumeric, DistString};
+use regex::Regex;
+use reqwest::header::{HeaderValue, AUTHORIZATION, WWW_AUTHENTICATE};
+use reqwest::{Request, RequestBuilder, Response, StatusCode};
+use sha2::{Digest, Sha256};
+
+/// Everything but the attr-char of RFC 8187 is encoded, for the username* parameter
+const USERNAME_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'!')
+    .remove(b'#')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'^')
+    .remove(b'_')
+    .remove(b'`')
+    .remove(b'|')
+    .remove(b'~');
+
+lazy_static! {
+    /// e.g. `realm="api@example.org"` or `algorithm=SHA-256`
+    static ref CHALLENGE_PARAM_REGEX: Regex =
+        Regex::new(r#"(?<key>[\w-]+)\s*=\s*(?:"(?<quoted>(?:[^"\\]|\\.)*)"|(?<token>[^,\s]+))"#).unwrap();
+
+    /// e.g. `\"` inside a quoted string
+    static ref QUOTED_PAIR_REGEX: Regex = Regex::new(r"\\(?<char>.)").unwrap();
+}
+
+/// Send the request without credentials, then answer the digest challenge of the 401 response (RFC 7616).
+/// The first response is returned if there is no digest challenge to answer
+pub async fn send_with_digest_auth(
+    request: RequestBuilder,
+    username: &str,
+    password: &str,
+) -> reqwest::Result<Response> {
+    let (client, request) = request.build_split();
+    let request = request?;
+
+    // A streamed body cannot be sent twice, such requests are already rejected by prepare_request
+    let mut authorized_request = match request.try_clone() {
+        None => return client.execute(request).await,
+        Some(authorized_request) => authorized_request,
+    };
+
+    let response = client.execute(request).await?;
+
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+
+    let challenge = response
+        .headers()
+        .get_all(WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|header_value| header_value.to_str().ok())
+        .find_map(|header_value| match header_value.split_once(' ') {
+            Some((scheme, challenge)) if scheme.eq_ignore_ascii_case("digest") => {
+                Some(challenge.to_string())
+            }
+            _ => None,
+        });
+
+    let cnonce = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+
+    let authorization = match challenge.and_then(|challenge| {
+        digest_authorization(&challenge, username, password, &authorized_request, &cnonce)
+    }) {
+        None => return Ok(response),
+        Some(authorization) => authorization,
+    };
+
+    match HeaderValue::from_str(&authorization) {
+        Ok(authorization) => {
+            authorized_request
+                .headers_mut()
+                .insert(AUTHORIZATION, authorization);
+        }
+        Err(_) => return Ok(response),
+    }
+
+    client.execute(authorized_request).await
+}
+
+/// Authorization header value answering the challenge, None if the challenge is not supported
+fn digest_authorization(
+    challenge: &str,
+    username: &str,
+    password: &str,
+    request: &Request,
+    cnonce: &str,
+) -> Option<String> {
+    let params: HashMap<String, String> = CHALLENGE_PARAM_REGEX
+        .captures_iter(challenge)
+        .map(|capture| {
+            let value = match capture.name("quoted") {
+                Some(quoted) => QUOTED_PAIR_REGEX
+                    .replace_all(quoted.as_str(), "$char")
+                    .to_string(),
+                None => capture["token"].to_string(),
+            };
+
+            (capture["key"].to_lowercase(), value)
+        })
+        .collect();
+
+    let realm = params.get("realm")?;
+    let nonce = params.get("nonce")?;
+    let algorithm = params.get("algorithm").map(String::as_str).unwrap_or("MD5");
+
+    let hash: fn(&[u8]) -> String = match algorithm.to_uppercase().as_str() {
+        "MD5" | "MD5-SESS" => |data| hex(&Md5::digest(data)),
+        "SHA-256" | "SHA-256-SESS" => |data| hex(&Sha256::digest(data)),
+        _ => return None,
+    };
+
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .unwrap_or_default();
+
+    // auth is preferred, auth-int also protects the body
+    let qop = params.get("qop").and_then(|qop| {
+        let qops: Vec<&str> = qop.split(',').map(str::trim).collect();
+
+        if qops.contains(&"auth") {
+            Some("auth")
+        } else if qops.contains(&"auth-int") {
+            Some("auth-int")
+        } else {
+            None
+        }
+    });
+
+    let url = request.url();
+    let uri = match url.query() {
+        None => url.path().to_string(),
+        Some(query) => format!("{}?{query}", url.path()),
+    };
+
+    let nc = "00000001";
+
+    let mut ha1 = hash(format!("{username}:{realm}:{password}").as_bytes());
+
+    if algorithm.to_uppercase().ends_with("-SESS") {
+        ha1 = hash(format!("{ha1}:{nonce}:{cnonce}").as_bytes());
+    }
+
+    let ha2 = match qop {
+        Some("auth-int") => hash(format!("{}:{uri}:{}", request.method(), hash(body)).as_bytes()),
+        _ => hash(format!("{}:{uri}", request.method()).as_bytes()),
+    };
+
+    let response = match qop {
+        None => hash(format!("{ha1}:{nonce}:{ha2}").as_bytes()),
+        Some(qop) => hash(format!("{ha1}:{nonce}:{nc}:{cnonce}:{qop}:{ha2}").as_bytes()),
+    };
+
+    // A quoted string can only contain ASCII characters, RFC 7616 section 3.4.4
+    let username = match username.is_ascii() {
+        true => format!("username={}", quote(username)),
+        false => format!(
+            "username*=UTF-8''{}",
+            utf8_percent_encode(username, USERNAME_ENCODE_SET)
+        ),
+    };
+
+    let mut authorization = format!(
+        "Digest {username}, realm={}, nonce={}, uri={}, algorithm={algorithm}, response={}",
+        quote(realm),
+        quote(nonce),
+        quote(&uri),
+        quote(&response)
+    );
+
+    if let Some(qop) = qop {
+        authorization += &format!(", qop={qop}, nc={nc}, cnonce={}", quote(cnonce));
+    }
+
+    if let Some(opaque) = params.get("opaque") {
+        authorization += &format!(", opaque={}", quote(opaque));
+    }
+
+    Some(authorization)
+}
+
+/// Quoted string, with its quotes and backslashes escaped
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub(super) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, Request, Url};
+
+    use super::digest_authorization;
+
+    /// RFC 7616 section 3.9.1
+    const CHALLENGE: &str = r#"realm="http-auth@example.org", qop="auth, auth-int", algorithm=ALGORITHM, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#;
+    const CNONCE: &str = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+
+    fn authorization(algorithm: &str, username: &str) -> String {
+        let request = Request::new(
+            Method::GET,
+            Url::parse("http://www.example.org/dir/index.html").unwrap(),
+        );
+        let challenge = CHALLENGE.replace("ALGORITHM", algorithm);
+
+        digest_authorization(&challenge, username, "Circle of Life", &request, CNONCE).unwrap()
+    }
+
+    #[test]
+    fn rfc_7616_md5() {
+        assert_eq!(
+            authorization("MD5", "Mufasa"),
+            r#"Digest username="Mufasa", realm="http-auth@example.org", nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", uri="/dir/index.html", algorithm=MD5, response="8ca523f5e9506fed4657c9700eebdbec", qop=auth, nc=00000001, cnonce="f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#
+        );
+    }
+
+    #[test]
+    fn rfc_7616_sha_256() {
+        assert!(authorization("SHA-256", "Mufasa").contains(
+            r#"response="753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1""#
+        ));
+    }
+
+    #[test]
+    fn escaped_username() {
+        assert!(authorization("MD5", r#"Mu"fa\sa"#).starts_with(r#"Digest username="Mu\"fa\\sa""#));
+        assert!(authorization("MD5", "Mufasà").starts_with("Digest username*=UTF-8''Mufas%C3%A0,"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use tokio::task;
+
+use crate::app::app::App;
+use crate::app::app_logic::request::send::{
+    send_error_response, send_prepared_request, PrepareRequestError,
+};
+use crate::request::body::ContentType;
+use crate::request::graphql::{GraphQLSchema, INTROSPECTION_QUERY};
+use crate::request::response::{RequestResponse, ResponseContent};
+
+impl App<'_> {
+    /// Send the introspection query to the request URL, with the request headers and auth.
+    /// The schema is then used by the completion and the schema browser
+    pub fn fetch_graphql_schema(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        let mut request = {
+            let selected_request = local_selected_request.read();
+
+            // Avoid creating more than one thread
+            if selected_request.is_pending {
+                return;
+            }
+
+            if !matches!(selected_request.body, ContentType::GraphQL { .. }) {
+                return;
+            }
+
+            /* COLLECTION HEADERS, AUTH & VARIABLES */
+
+            self.apply_collection_to_request(selected_request_index[0], &selected_request)
+        };
+
+        request.body = ContentType::GraphQL {
+            query: INTROSPECTION_QUERY.to_string(),
+            variables: String::new(),
+        };
+
+        let prepared_request = match self.prepare_request(&request) {
+            Ok(prepared_request) => prepared_request,
+            Err(prepare_request_error) => {
+                local_selected_request.write().response.status_code =
+                    Some(prepare_request_error.to_string());
+                return;
+            }
+        };
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let send_time_auth = self.get_send_time_auth(&request);
+
+            let local_selected_request = self.get_selected_request_as_local();
+            let local_highlighted_body = Arc::clone(&self.syntax_highlighting.highlighted_body);
+
+            /* SEND INTROSPECTION QUERY */
+
+            let introspection_task = task::spawn(async move {
+                let response =
+                    send_prepared_request(prepared_request, send_time_auth, &request.settings)
+                        .await
+                        .unwrap_or_else(send_error_response);
+
+                let mut selected_request = local_selected_request.write();
+
+                match (&response.status_code, &response.content) {
+                    (Some(status_code), Some(ResponseContent::Body(body))) => {
+                        match GraphQLSchema::from_introspection_response(body) {
+                            Ok(schema) => selected_request.graphql_schema = Some(Arc::new(schema)),
+                            Err(error) => {
+                                selected_request.response = RequestResponse {
+                                    status_code: Some(
+                                        PrepareRequestError::GraphQLIntrospection.to_string(),
+                                    ),
+                                    content: Some(ResponseContent::Body(format!(
+                                        "{status_code}\n\t{error}"
+                                    ))),
+                                    ..Default::default()
+                                };
+                                *local_highlighted_body.write() = None;
+                            }
+                        }
+                    }
+                    // Could not be sent, e.g. connection refused
+                    _ => {
+                        selected_request.response = response;
+                        *local_highlighted_body.write() = None;
+                    }
+                }
+
+                selected_request.is_pending = false;
+                selected_request.abort_handle = None;
+            });
+
+            selected_request.is_pending = true;
+            selected_request.abort_handle = Some(Arc::new(introspection_task.abort_handle()));
+        }
+    }
+
+    /// Complete the name being typed in the GraphQL query. When several names are possible,
+    /// only their common start is written and they are displayed
+    pub fn autocomplete_graphql_query(&mut self) {
+        self.graphql_editor.completions = vec![];
+
+        // Only the query can be completed
+        if self.graphql_editor.selection != 0 {
+            return;
+        }
+
+        let schema = {
+            let local_selected_request = self.get_selected_request_as_local();
+            let selected_request = local_selected_request.read();
+
+            match (&selected_request.body, &selected_request.graphql_schema) {
+                (ContentType::GraphQL { .. }, Some(schema)) => Arc::clone(schema),
+                _ => return,
+            }
+        };
+
+        let (row, column) = self.body_text_area.cursor();
+        let lines = self.body_text_area.lines();
+
+        let mut query: String = lines[..row]
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect();
+        query.extend(lines[row].chars().take(column));
+
+        let (prefix, candidates) = schema.completions(&query);
+
+        let common_start = match candidates.first() {
+            None => return,
+            Some(first_candidate) => {
+                candidates
+                    .iter()
+                    .fold(first_candidate.clone(), |common_start, candidate| {
+                        common_start
+                            .chars()
+                            .zip(candidate.chars())
+                            .take_while(|(a, b)| a == b)
+                            .map(|(char, _)| char)
+                            .collect()
+                    })
+            }
+        };
+
+        if common_start.len() > prefix.len() {
+            self.body_text_area
+                .insert_str(&common_start[prefix.len()..]);
+        }
+
+        if candidates.len() > 1 {
+            self.graphql_editor.completions = candidates;
+        }
+    }
+}
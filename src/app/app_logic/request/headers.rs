@@ -22,7 +22,7 @@ impl App<'_> {
     }
 
     pub fn modify_request_header(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -38,12 +38,12 @@ impl App<'_> {
             };
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn create_new_header(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -55,7 +55,7 @@ impl App<'_> {
             });
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_headers_selection();
         self.update_inputs();
     }
@@ -65,7 +65,7 @@ impl App<'_> {
             return;
         }
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -75,7 +75,7 @@ impl App<'_> {
             selected_request.headers.remove(selection.0);
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_headers_selection();
         self.update_inputs();
     }
@@ -85,7 +85,7 @@ impl App<'_> {
             return;
         }
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -95,7 +95,7 @@ impl App<'_> {
             selected_request.headers[row].enabled = !selected_request.headers[row].enabled;
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_inputs();
     }
 }
@@ -0,0 +1,142 @@
+use std::fs;
+
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+
+use crate::app::app::App;
+use crate::request::auth::{Jwt, JwtAlgorithm, DEFAULT_JWT_EXPIRES_IN};
+
+impl App<'_> {
+    /// Newly generated token with the environment values, for the exports that cannot generate one
+    pub fn get_generated_jwt(&self, jwt: &Jwt) -> Option<String> {
+        let mut jwt = jwt.clone();
+
+        for value in jwt.values_mut() {
+            *value = self.replace_env_keys_by_value(value);
+        }
+
+        generate_jwt(&jwt).ok()
+    }
+}
+
+/// Build and sign a new token from the claims template, which must already be resolved
+pub fn generate_jwt(jwt: &Jwt) -> Result<String, String> {
+    let mut claims = match jwt.claims.trim() {
+        "" => Map::new(),
+        claims => match serde_json::from_str::<Map<String, Value>>(claims) {
+            Ok(claims) => claims,
+            Err(e) => return Err(format!("The claims are not a valid JSON object\n\t{e}")),
+        },
+    };
+
+    let expires_in = match jwt.expires_in.trim() {
+        "" => DEFAULT_JWT_EXPIRES_IN,
+        expires_in => match expires_in.parse::<u64>() {
+            Ok(expires_in) => expires_in,
+            Err(e) => return Err(format!("Invalid expiration \"{expires_in}\"\n\t{e}")),
+        },
+    };
+
+    let now = OffsetDateTime::now_utc().unix_timestamp() as u64;
+
+    let expiration = match now.checked_add(expires_in) {
+        Some(expiration) => expiration,
+        None => return Err(format!("Expiration \"{expires_in}\" is too large")),
+    };
+
+    claims.insert(String::from("iat"), Value::from(now));
+    claims.insert(String::from("exp"), Value::from(expiration));
+
+    /* KEY */
+
+    let (algorithm, key) = match jwt.algorithm {
+        JwtAlgorithm::HS256 => (
+            Algorithm::HS256,
+            EncodingKey::from_secret(jwt.secret.as_bytes()),
+        ),
+        JwtAlgorithm::RS256 => (
+            Algorithm::RS256,
+            EncodingKey::from_rsa_pem(&read_pem_key(&jwt.secret)?)
+                .map_err(|e| format!("Invalid RSA private key\n\t{e}"))?,
+        ),
+        JwtAlgorithm::ES256 => (
+            Algorithm::ES256,
+            EncodingKey::from_ec_pem(&read_pem_key(&jwt.secret)?)
+                // SEC1 keys can be converted with `openssl pkcs8 -topk8 -nocrypt`
+                .map_err(|e| {
+                    format!("Invalid EC private key, a PKCS#8 PEM key is expected\n\t{e}")
+                })?,
+        ),
+    };
+
+    encode(&Header::new(algorithm), &claims, &key)
+        .map_err(|e| format!("Could not sign the JWT\n\t{e}"))
+}
+
+fn read_pem_key(path: &str) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| format!("Could not read the key file \"{path}\"\n\t{e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+    use serde_json::{Map, Value};
+
+    use super::generate_jwt;
+    use crate::request::auth::{Jwt, JwtAlgorithm, DEFAULT_JWT_EXPIRES_IN};
+
+    fn decode_hs256(token: &str, secret: &str) -> Map<String, Value> {
+        let key = DecodingKey::from_secret(secret.as_bytes());
+
+        decode::<Map<String, Value>>(token, &key, &Validation::new(Algorithm::HS256))
+            .unwrap()
+            .claims
+    }
+
+    fn lifetime(claims: &Map<String, Value>) -> u64 {
+        claims["exp"].as_u64().unwrap() - claims["iat"].as_u64().unwrap()
+    }
+
+    #[test]
+    fn hs256_token() {
+        let jwt = Jwt {
+            algorithm: JwtAlgorithm::HS256,
+            secret: String::from("my-secret"),
+            claims: String::from(r#"{"sub": "1234567890", "admin": true}"#),
+            expires_in: String::from("600"),
+        };
+
+        let token = generate_jwt(&jwt).unwrap();
+
+        assert_eq!(decode_header(&token).unwrap().alg, Algorithm::HS256);
+
+        let claims = decode_hs256(&token, "my-secret");
+
+        assert_eq!(claims["sub"], "1234567890");
+        assert_eq!(claims["admin"], true);
+        assert_eq!(lifetime(&claims), 600);
+    }
+
+    #[test]
+    fn default_lifetime() {
+        let jwt = Jwt {
+            secret: String::from("my-secret"),
+            ..Default::default()
+        };
+
+        let claims = decode_hs256(&generate_jwt(&jwt).unwrap(), "my-secret");
+
+        assert_eq!(lifetime(&claims), DEFAULT_JWT_EXPIRES_IN);
+    }
+
+    #[test]
+    fn invalid_claims() {
+        let jwt = Jwt {
+            claims: String::from("[1, 2]"),
+            ..Default::default()
+        };
+
+        assert!(generate_jwt(&jwt).is_err());
+    }
+}
@@ -1,9 +1,9 @@
 use crate::app::app::App;
-use crate::request::method::next_method;
+use crate::request::method::{next_method, Method};
 
 impl App<'_> {
     pub fn modify_request_method(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -13,6 +13,28 @@ impl App<'_> {
             selected_request.method = next_method;
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
+    }
+
+    pub fn modify_request_custom_method(&mut self) {
+        let input_text = self.method_text_input.text.trim().to_string();
+
+        let method = Method::from(input_text);
+
+        // Stays in the input if the method is not a valid HTTP token
+        if method.to_reqwest().is_none() {
+            return;
+        }
+
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+            selected_request.method = method;
+        }
+
+        self.save_collection_to_file(selected_request_index[0]);
+        self.select_request_state();
     }
 }
@@ -1,11 +1,19 @@
 pub mod auth;
+pub mod aws_sig_v4;
 pub mod body;
 mod cookies;
+pub mod digest;
+pub mod graphql;
 pub mod headers;
+pub mod jwt;
 pub mod method;
+pub mod oauth1;
+pub mod oauth2;
 pub mod query_params;
-mod scripts;
+pub mod scripts;
 pub mod send;
 pub mod settings;
+pub mod tls;
 pub mod url;
 pub(super) mod utils;
+pub mod websocket;
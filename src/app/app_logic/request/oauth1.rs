@@ -0,0 +1,229 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use rand::distributions::{Alphanumeric, DistString};
+use reqwest::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Request, RequestBuilder, Response};
+use sha1::Sha1;
+use time::OffsetDateTime;
+
+use crate::request::auth::OAuth1;
+
+const SIGNATURE_METHOD: &str = "HMAC-SHA1";
+
+/// Everything but the unreserved characters is encoded, see RFC 5849 section 3.6
+const OAUTH1_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Sign the final request with OAuth 1.0a HMAC-SHA1, then send it.
+/// The credentials must already be resolved
+pub async fn send_with_oauth1(
+    request: RequestBuilder,
+    oauth1: &OAuth1,
+) -> reqwest::Result<Response> {
+    let (client, request) = request.build_split();
+    let mut request = request?;
+
+    sign_request(&mut request, oauth1);
+
+    client.execute(request).await
+}
+
+fn sign_request(request: &mut Request, oauth1: &OAuth1) {
+    let nonce = Alphanumeric.sample_string(&mut rand::thread_rng(), 32);
+    let timestamp = OffsetDateTime::now_utc().unix_timestamp().to_string();
+
+    sign_request_with(request, oauth1, nonce, timestamp);
+}
+
+/// Sign with a given nonce and timestamp, which are random and time dependent otherwise
+fn sign_request_with(request: &mut Request, oauth1: &OAuth1, nonce: String, timestamp: String) {
+    let mut oauth_params = vec![
+        ("oauth_consumer_key", oauth1.consumer_key.clone()),
+        ("oauth_nonce", nonce),
+        ("oauth_signature_method", String::from(SIGNATURE_METHOD)),
+        ("oauth_timestamp", timestamp),
+        ("oauth_version", String::from("1.0")),
+    ];
+
+    // Two-legged requests are only signed with the consumer credentials
+    if !oauth1.token.is_empty() {
+        oauth_params.push(("oauth_token", oauth1.token.clone()));
+    }
+
+    let signature_base_string = signature_base_string(request, &oauth_params);
+    let signature = signature(&signature_base_string, oauth1);
+
+    oauth_params.push(("oauth_signature", signature));
+
+    /* HEADER */
+
+    let authorization = oauth_params
+        .iter()
+        .map(|(key, value)| format!("{key}=\"{}\"", oauth1_encode(value)))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    if let Ok(authorization) = HeaderValue::from_str(&format!("OAuth {authorization}")) {
+        request.headers_mut().insert(AUTHORIZATION, authorization);
+    }
+}
+
+/// Method, base URL and normalized parameters, see RFC 5849 section 3.4.1
+fn signature_base_string(request: &Request, oauth_params: &[(&str, String)]) -> String {
+    /* PARAMETERS */
+
+    let url = request.url().clone();
+
+    let mut params: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| (oauth1_encode(&key), oauth1_encode(&value)))
+        .collect();
+
+    // Only a form body is part of the signature
+    let is_form = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/x-www-form-urlencoded"));
+
+    if is_form {
+        if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+            params.extend(
+                form_urlencoded::parse(body)
+                    .map(|(key, value)| (oauth1_encode(&key), oauth1_encode(&value))),
+            );
+        }
+    }
+
+    params.extend(
+        oauth_params
+            .iter()
+            .map(|(key, value)| (key.to_string(), oauth1_encode(value))),
+    );
+
+    params.sort();
+
+    let normalized_params = params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    /* BASE STRING */
+
+    // Scheme and host are lowercase, the default port is omitted and the query is removed
+    let base_url = match url.port() {
+        None => format!(
+            "{}://{}{}",
+            url.scheme(),
+            url.host_str().unwrap_or_default(),
+            url.path()
+        ),
+        Some(port) => format!(
+            "{}://{}:{port}{}",
+            url.scheme(),
+            url.host_str().unwrap_or_default(),
+            url.path()
+        ),
+    };
+
+    [
+        request.method().as_str(),
+        &oauth1_encode(&base_url),
+        &oauth1_encode(&normalized_params),
+    ]
+    .join("&")
+}
+
+fn signature(signature_base_string: &str, oauth1: &OAuth1) -> String {
+    let signing_key = format!(
+        "{}&{}",
+        oauth1_encode(&oauth1.consumer_secret),
+        oauth1_encode(&oauth1.token_secret)
+    );
+
+    // HMAC accepts keys of any length
+    let mut mac = Hmac::<Sha1>::new_from_slice(signing_key.as_bytes()).unwrap();
+    mac.update(signature_base_string.as_bytes());
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+fn oauth1_encode(input: &str) -> String {
+    utf8_percent_encode(input, OAUTH1_ENCODE_SET).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::AUTHORIZATION;
+    use reqwest::{Client, Request};
+
+    use super::{sign_request_with, signature, signature_base_string};
+    use crate::request::auth::OAuth1;
+
+    fn photos_oauth1() -> OAuth1 {
+        OAuth1 {
+            consumer_key: String::from("dpf43f3p2l4k3l03"),
+            consumer_secret: String::from("kd94hf93k423kf44"),
+            token: String::from("nnch734d00sl2jdk"),
+            token_secret: String::from("pfkkdhi9sl3r4s00"),
+        }
+    }
+
+    fn photos_request() -> Request {
+        Client::new()
+            .get("http://photos.example.net/photos?file=vacation.jpg&size=original")
+            .build()
+            .unwrap()
+    }
+
+    /// Example of RFC 5849 section 1.2, which is signed without oauth_version
+    #[test]
+    fn hmac_sha1_signature() {
+        let oauth1 = photos_oauth1();
+
+        let oauth_params = [
+            ("oauth_consumer_key", oauth1.consumer_key.clone()),
+            ("oauth_token", oauth1.token.clone()),
+            ("oauth_signature_method", String::from("HMAC-SHA1")),
+            ("oauth_timestamp", String::from("137131202")),
+            ("oauth_nonce", String::from("chapoH")),
+        ];
+
+        let signature_base_string = signature_base_string(&photos_request(), &oauth_params);
+
+        assert_eq!(
+            signature_base_string,
+            "GET&http%3A%2F%2Fphotos.example.net%2Fphotos&file%3Dvacation.jpg%26\
+            oauth_consumer_key%3Ddpf43f3p2l4k3l03%26oauth_nonce%3DchapoH%26\
+            oauth_signature_method%3DHMAC-SHA1%26oauth_timestamp%3D137131202%26\
+            oauth_token%3Dnnch734d00sl2jdk%26size%3Doriginal"
+        );
+        assert_eq!(
+            signature(&signature_base_string, &oauth1),
+            "MdpQcU8iPSUjWoN/UDMsK2sui9I="
+        );
+    }
+
+    /// Example of the OAuth Core 1.0 specification, appendix A.5
+    #[test]
+    fn signature_in_authorization_header() {
+        let mut request = photos_request();
+
+        sign_request_with(
+            &mut request,
+            &photos_oauth1(),
+            String::from("kllo9940pd9333jh"),
+            String::from("1191242096"),
+        );
+
+        let authorization = request.headers()[AUTHORIZATION].to_str().unwrap();
+
+        assert!(authorization.starts_with("OAuth oauth_consumer_key=\"dpf43f3p2l4k3l03\""));
+        assert!(authorization.contains("oauth_signature=\"tR3%2BTy81lMeYAr%2FFid0kMTYa%2FWM%3D\""));
+    }
+}
@@ -0,0 +1,464 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use rand::distributions::{Alphanumeric, DistString};
+use reqwest::{Client, RequestBuilder, Url};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::app::app::App;
+use crate::app::app_logic::request::digest::hex;
+use crate::app::startup::args::ARGS;
+use crate::request::auth::{OAuth2, OAuth2GrantType, DEFAULT_OAUTH2_REDIRECT_URI};
+
+/// A token is considered expired a bit before its real expiry, so it cannot expire in flight
+const EXPIRY_MARGIN: Duration = Duration::from_secs(10);
+
+/// Time left to the user to authorize in the browser
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(300);
+
+const REDIRECT_RESPONSE: &str = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nConnection: close\r\n\r\n<html><body>Authorization received, you can go back to ATAC.</body></html>";
+const NOT_FOUND_RESPONSE: &str = "HTTP/1.1 404 Not Found\r\nConnection: close\r\n\r\n";
+
+lazy_static! {
+    /// Token states, shared by every request using the same OAuth2 configuration
+    pub static ref OAUTH2_TOKENS: RwLock<HashMap<String, OAuth2TokenState>> = RwLock::new(HashMap::new());
+}
+
+#[derive(Clone, Debug)]
+pub enum OAuth2TokenState {
+    Fetching,
+    /// Waiting for the user to authorize at the given URL
+    WaitingForAuthorization(String),
+    Token(OAuth2Token),
+    Error(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct OAuth2Token {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_at: Option<Instant>,
+}
+
+impl OAuth2Token {
+    pub fn is_expired(&self) -> bool {
+        match self.expires_at {
+            None => false,
+            Some(expires_at) => Instant::now() + EXPIRY_MARGIN >= expires_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+impl App<'_> {
+    /// Copy of the OAuth2 configuration with the environment keys replaced by their values
+    pub fn resolve_oauth2(&self, oauth2: &OAuth2) -> OAuth2 {
+        let mut oauth2 = oauth2.clone();
+
+        for value in oauth2.values_mut() {
+            *value = self.replace_env_keys_by_value(value);
+        }
+
+        oauth2
+    }
+
+    /// Valid cached access token, used by the exports since they cannot fetch one
+    pub fn get_cached_oauth2_access_token(&self, oauth2: &OAuth2) -> Option<String> {
+        match get_oauth2_token_state(&self.resolve_oauth2(oauth2)) {
+            Some(OAuth2TokenState::Token(token)) if !token.is_expired() => Some(token.access_token),
+            _ => None,
+        }
+    }
+}
+
+/// A new token is fetched when one of these values changes.
+/// Hashed, so that the secrets are not kept in memory as is
+fn oauth2_cache_key(oauth2: &OAuth2) -> String {
+    let key = [
+        oauth2.grant_type.to_string(),
+        oauth2.access_token_url.clone(),
+        oauth2.authorization_url.clone(),
+        oauth2.redirect_uri.clone(),
+        oauth2.client_id.clone(),
+        oauth2.client_secret.clone(),
+        oauth2.scope.clone(),
+        oauth2.username.clone(),
+        oauth2.password.clone(),
+    ]
+    .join("\n");
+
+    hex(&Sha256::digest(key.as_bytes()))
+}
+
+pub fn get_oauth2_token_state(oauth2: &OAuth2) -> Option<OAuth2TokenState> {
+    OAUTH2_TOKENS.read().get(&oauth2_cache_key(oauth2)).cloned()
+}
+
+/// Add the bearer token to a prepared request.
+/// The token is fetched with the request HTTP client, so with its proxy, TLS and timeouts
+pub async fn apply_oauth2_token(
+    request: RequestBuilder,
+    oauth2: &OAuth2,
+) -> Result<RequestBuilder, String> {
+    let (client, request) = request.build_split();
+    let request = request.map_err(|e| format!("Could not build request\n\t{e}"))?;
+
+    let access_token = get_oauth2_access_token(&client, oauth2).await?;
+
+    Ok(RequestBuilder::from_parts(client, request).bearer_auth(access_token))
+}
+
+/// Return the cached access token, or refresh it, or fetch a new one.
+/// The OAuth2 configuration must already be resolved
+async fn get_oauth2_access_token(client: &Client, oauth2: &OAuth2) -> Result<String, String> {
+    let key = oauth2_cache_key(oauth2);
+
+    let cached_token = match OAUTH2_TOKENS.read().get(&key) {
+        Some(OAuth2TokenState::Token(token)) => Some(token.clone()),
+        _ => None,
+    };
+
+    if let Some(token) = &cached_token {
+        if !token.is_expired() {
+            return Ok(token.access_token.clone());
+        }
+    }
+
+    OAUTH2_TOKENS
+        .write()
+        .insert(key.clone(), OAuth2TokenState::Fetching);
+
+    let mut fetching_guard = FetchingGuard {
+        key: Some(key.clone()),
+        previous_token: cached_token.clone(),
+    };
+
+    let refresh_token = cached_token.and_then(|token| token.refresh_token);
+
+    // Falls back to the grant type if the refresh token has been revoked
+    let refreshed_token = match &refresh_token {
+        None => None,
+        Some(refresh_token) => {
+            let form = vec![
+                ("grant_type", String::from("refresh_token")),
+                ("refresh_token", refresh_token.clone()),
+            ];
+
+            request_token(client, oauth2, form).await.ok()
+        }
+    };
+
+    let token = match refreshed_token {
+        Some(mut token) => {
+            // The refresh token is not always renewed
+            if token.refresh_token.is_none() {
+                token.refresh_token = refresh_token;
+            }
+
+            Ok(token)
+        }
+        None => fetch_token(client, oauth2, &key).await,
+    };
+
+    fetching_guard.disarm();
+
+    match token {
+        Ok(token) => {
+            let access_token = token.access_token.clone();
+            OAUTH2_TOKENS
+                .write()
+                .insert(key, OAuth2TokenState::Token(token));
+            Ok(access_token)
+        }
+        Err(error) => {
+            OAUTH2_TOKENS
+                .write()
+                .insert(key, OAuth2TokenState::Error(error.clone()));
+            Err(error)
+        }
+    }
+}
+
+/// Restores the previous token state if the fetch is dropped before its end, e.g. when the
+/// request is cancelled, so that the token is not displayed as being fetched forever
+struct FetchingGuard {
+    /// None once the fetch ended, its result is then stored instead
+    key: Option<String>,
+    /// Kept for its refresh token, even if expired
+    previous_token: Option<OAuth2Token>,
+}
+
+impl FetchingGuard {
+    fn disarm(&mut self) {
+        self.key = None;
+    }
+}
+
+impl Drop for FetchingGuard {
+    fn drop(&mut self) {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => return,
+        };
+
+        let mut tokens = OAUTH2_TOKENS.write();
+
+        match self.previous_token.take() {
+            Some(previous_token) => tokens.insert(key, OAuth2TokenState::Token(previous_token)),
+            None => tokens.remove(&key),
+        };
+    }
+}
+
+async fn fetch_token(client: &Client, oauth2: &OAuth2, key: &str) -> Result<OAuth2Token, String> {
+    let form = match oauth2.grant_type {
+        OAuth2GrantType::ClientCredentials => {
+            vec![("grant_type", String::from("client_credentials"))]
+        }
+        OAuth2GrantType::Password => vec![
+            ("grant_type", String::from("password")),
+            ("username", oauth2.username.clone()),
+            ("password", oauth2.password.clone()),
+        ],
+        OAuth2GrantType::AuthorizationCodePkce => {
+            let redirect_uri = match oauth2.redirect_uri.is_empty() {
+                true => DEFAULT_OAUTH2_REDIRECT_URI,
+                false => &oauth2.redirect_uri,
+            };
+
+            let (code, code_verifier) = authorize_in_browser(oauth2, redirect_uri, key).await?;
+
+            vec![
+                ("grant_type", String::from("authorization_code")),
+                ("code", code),
+                ("redirect_uri", redirect_uri.to_string()),
+                ("code_verifier", code_verifier),
+            ]
+        }
+    };
+
+    request_token(client, oauth2, form).await
+}
+
+async fn request_token(
+    client: &Client,
+    oauth2: &OAuth2,
+    mut form: Vec<(&str, String)>,
+) -> Result<OAuth2Token, String> {
+    form.push(("client_id", oauth2.client_id.clone()));
+
+    if !oauth2.client_secret.is_empty() {
+        form.push(("client_secret", oauth2.client_secret.clone()));
+    }
+
+    if !oauth2.scope.is_empty() {
+        form.push(("scope", oauth2.scope.clone()));
+    }
+
+    let response = client
+        .post(&oauth2.access_token_url)
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| format!("Could not request token\n\t{e}"))?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Could not read token response\n\t{e}"))?;
+
+    if !status.is_success() {
+        return Err(match serde_json::from_str::<TokenErrorResponse>(&body) {
+            Ok(TokenErrorResponse {
+                error,
+                error_description: None,
+            }) => error,
+            Ok(TokenErrorResponse {
+                error,
+                error_description: Some(error_description),
+            }) => format!("{error}: {error_description}"),
+            Err(_) => format!("{status} {body}"),
+        });
+    }
+
+    let token_response = serde_json::from_str::<TokenResponse>(&body)
+        .map_err(|e| format!("Could not parse token response\n\t{e}"))?;
+
+    Ok(OAuth2Token {
+        access_token: token_response.access_token,
+        refresh_token: token_response.refresh_token,
+        expires_at: token_response
+            .expires_in
+            .map(|expires_in| Instant::now() + Duration::from_secs(expires_in)),
+    })
+}
+
+/// Open the authorization URL in the browser and wait for the redirect on the loopback interface.
+/// Returns the authorization code and the PKCE code verifier
+async fn authorize_in_browser(
+    oauth2: &OAuth2,
+    redirect_uri: &str,
+    key: &str,
+) -> Result<(String, String), String> {
+    let redirect_url =
+        Url::parse(redirect_uri).map_err(|e| format!("Invalid redirect URI\n\t{e}"))?;
+
+    let host = redirect_url.host_str().unwrap_or("127.0.0.1");
+    let port = redirect_url.port_or_known_default().unwrap_or(80);
+
+    let listener = TcpListener::bind((host, port))
+        .await
+        .map_err(|e| format!("Could not listen on {host}:{port}\n\t{e}"))?;
+
+    let code_verifier = Alphanumeric.sample_string(&mut rand::thread_rng(), 64);
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+    let state = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+
+    let mut authorization_url = Url::parse(&oauth2.authorization_url)
+        .map_err(|e| format!("Invalid authorization URL\n\t{e}"))?;
+
+    authorization_url
+        .query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &oauth2.client_id)
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("code_challenge", &code_challenge)
+        .append_pair("code_challenge_method", "S256")
+        .append_pair("state", &state);
+
+    if !oauth2.scope.is_empty() {
+        authorization_url
+            .query_pairs_mut()
+            .append_pair("scope", &oauth2.scope);
+    }
+
+    let authorization_url = authorization_url.to_string();
+
+    OAUTH2_TOKENS.write().insert(
+        key.to_string(),
+        OAuth2TokenState::WaitingForAuthorization(authorization_url.clone()),
+    );
+
+    // The TUI displays the URL in the auth tab
+    if ARGS.is_headless {
+        eprintln!("Authorize in your browser: {authorization_url}");
+    }
+
+    // The URL can still be opened by hand if no browser is available
+    let _ = open::that_detached(&authorization_url);
+
+    let redirect = tokio::time::timeout(
+        AUTHORIZATION_TIMEOUT,
+        wait_for_redirect(&listener, redirect_url.path()),
+    )
+    .await
+    .map_err(|_| String::from("Authorization timed out"))??;
+
+    let query: HashMap<String, String> = redirect.query_pairs().into_owned().collect();
+
+    if let Some(error) = query.get("error") {
+        return Err(format!("Authorization failed: {error}"));
+    }
+
+    if query.get("state") != Some(&state) {
+        return Err(String::from("Authorization failed: state mismatch"));
+    }
+
+    match query.get("code") {
+        None => Err(String::from("Authorization failed: no code received")),
+        Some(code) => Ok((code.clone(), code_verifier)),
+    }
+}
+
+/// Answer the browser redirect and return its URL
+async fn wait_for_redirect(listener: &TcpListener, path: &str) -> Result<Url, String> {
+    loop {
+        let (mut stream, _) = listener
+            .accept()
+            .await
+            .map_err(|e| format!("Could not receive redirect\n\t{e}"))?;
+
+        let mut buffer = [0; 8192];
+        let length = stream.read(&mut buffer).await.unwrap_or(0);
+        let request = String::from_utf8_lossy(&buffer[..length]);
+
+        // e.g. GET /callback?code=...&state=... HTTP/1.1
+        let target = request.split_whitespace().nth(1).unwrap_or_default();
+
+        match Url::parse(&format!("http://localhost{target}")) {
+            Ok(url) if url.path() == path => {
+                let _ = stream.write_all(REDIRECT_RESPONSE.as_bytes()).await;
+                return Ok(url);
+            }
+            // e.g. the favicon requested by the browser
+            _ => {
+                let _ = stream.write_all(NOT_FOUND_RESPONSE.as_bytes()).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Client;
+    use tokio::net::TcpListener;
+    use tokio::time::sleep;
+
+    use super::{get_oauth2_access_token, get_oauth2_token_state, OAuth2TokenState};
+    use crate::request::auth::OAuth2;
+
+    #[tokio::test]
+    async fn cancelled_while_fetching() {
+        // Accepts the token request but never answers it
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let oauth2 = OAuth2 {
+            access_token_url: format!("http://{}/token", listener.local_addr().unwrap()),
+            ..Default::default()
+        };
+
+        tokio::spawn(async move {
+            let _connection = listener.accept().await.unwrap();
+            sleep(Duration::from_secs(10)).await;
+        });
+
+        let fetch_oauth2 = oauth2.clone();
+        let fetch_task =
+            tokio::spawn(
+                async move { get_oauth2_access_token(&Client::new(), &fetch_oauth2).await },
+            );
+
+        sleep(Duration::from_millis(100)).await;
+
+        match get_oauth2_token_state(&oauth2) {
+            Some(OAuth2TokenState::Fetching) => {}
+            token_state => panic!("Expected Fetching, got {token_state:?}"),
+        }
+
+        fetch_task.abort();
+        assert!(fetch_task.await.unwrap_err().is_cancelled());
+
+        assert!(get_oauth2_token_state(&oauth2).is_none());
+    }
+}
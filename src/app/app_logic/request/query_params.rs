@@ -22,7 +22,7 @@ impl App<'_> {
     }
 
     pub fn modify_request_query_param(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -44,12 +44,12 @@ impl App<'_> {
             };
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn create_new_query_param(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -61,7 +61,7 @@ impl App<'_> {
             });
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_query_params_selection();
         self.update_inputs();
     }
@@ -71,7 +71,7 @@ impl App<'_> {
             return;
         }
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -81,7 +81,7 @@ impl App<'_> {
             selected_request.params.remove(selection.0);
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_query_params_selection();
         self.update_inputs();
     }
@@ -91,7 +91,7 @@ impl App<'_> {
             return;
         }
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -101,7 +101,7 @@ impl App<'_> {
             selected_request.params[row].enabled = !selected_request.params[row].enabled;
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.update_inputs();
     }
 }
@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
 use boa_engine::{Context, Source};
 use indexmap::IndexMap;
+use parking_lot::RwLock;
 use tui_textarea::TextArea;
 
 use crate::app::app::App;
+use crate::app::files::environment::save_environment_to_file;
+use crate::request::environment::Environment;
 use crate::request::request::Request;
 use crate::request::response::RequestResponse;
 
@@ -20,7 +25,7 @@ impl App<'_> {
     }
 
     pub fn modify_pre_request_script(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -35,12 +40,12 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 
     pub fn modify_post_request_script(&mut self) {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -59,9 +64,81 @@ impl App<'_> {
             }
         }
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
+
+    /// Execute the request pre-request script if there is one and save the resulting environment.
+    /// Returns the modified request (None if the script failed) and the console output
+    pub fn handle_pre_request_script(
+        &self,
+        request: &Request,
+    ) -> (Option<Request>, Option<String>) {
+        let pre_request_script = match &request.scripts.pre_request_script {
+            None => return (Some(request.clone()), None),
+            Some(pre_request_script) => pre_request_script,
+        };
+
+        let local_env = self.get_selected_env_as_local();
+        let env_values = get_env_values(&local_env);
+
+        let (result_request, env_variables, console_output) =
+            execute_pre_request_script(pre_request_script, request, env_values);
+
+        update_env_values(&local_env, env_variables);
+
+        (result_request, Some(console_output))
+    }
+}
+
+/// Execute the request post-request script if there is one and save the resulting environment.
+/// Returns the modified response and the console output
+pub fn handle_post_request_script(
+    request: &Request,
+    response: RequestResponse,
+    local_env: &Option<Arc<RwLock<Environment>>>,
+) -> (RequestResponse, Option<String>) {
+    let post_request_script = match &request.scripts.post_request_script {
+        None => return (response, None),
+        Some(post_request_script) => post_request_script,
+    };
+
+    let env_values = get_env_values(local_env);
+
+    let (result_response, env_variables, console_output) =
+        execute_post_request_script(post_request_script, &response, env_values);
+
+    update_env_values(local_env, env_variables);
+
+    let modified_response = match result_response {
+        None => {
+            let mut response = response;
+            response.status_code = Some(String::from("(CONSOLE) POST-SCRIPT ERROR"));
+            response
+        }
+        Some(result_response) => result_response,
+    };
+
+    (modified_response, Some(console_output))
+}
+
+fn get_env_values(
+    local_env: &Option<Arc<RwLock<Environment>>>,
+) -> Option<IndexMap<String, String>> {
+    local_env
+        .as_ref()
+        .map(|local_env| local_env.read().values.clone())
+}
+
+fn update_env_values(
+    local_env: &Option<Arc<RwLock<Environment>>>,
+    env_variables: Option<IndexMap<String, String>>,
+) {
+    if let (Some(local_env), Some(env_variables)) = (local_env, env_variables) {
+        let mut env = local_env.write();
+        env.values = env_variables;
+        save_environment_to_file(&env);
+    }
 }
 
 const JS_CONSOLE: &str = r#"
@@ -186,6 +263,10 @@ pub(super) fn execute_post_request_script(
                 response_result
                     .status_code
                     .clone_from(&response.status_code);
+                response_result.attempts = response.attempts;
+                response_result
+                    .http_version
+                    .clone_from(&response.http_version);
 
                 (Some(response_result), result_env_values, console_output)
             }
@@ -9,25 +9,65 @@ use ratatui::text::Line;
 use reqwest::header::{HeaderMap, CONTENT_TYPE};
 use reqwest::multipart::{Form, Part};
 use reqwest::redirect::Policy;
-use reqwest::{ClientBuilder, Proxy, Url};
+use reqwest::{ClientBuilder, Proxy, RequestBuilder, Response, Url};
+use strum::Display;
 use tokio::task;
+use tokio::time::sleep;
 
 use crate::app::app::App;
-use crate::app::app_logic::request::scripts::{
-    execute_post_request_script, execute_pre_request_script,
-};
-use crate::app::files::environment::save_environment_to_file;
+use crate::app::app_logic::request::aws_sig_v4::send_with_aws_sig_v4;
+use crate::app::app_logic::request::digest::send_with_digest_auth;
+use crate::app::app_logic::request::jwt::generate_jwt;
+use crate::app::app_logic::request::oauth1::send_with_oauth1;
+use crate::app::app_logic::request::oauth2::apply_oauth2_token;
+use crate::app::app_logic::request::scripts::handle_post_request_script;
+use crate::app::app_logic::request::websocket::{close_websocket_session, open_websocket_session};
 use crate::panic_error;
-use crate::request::auth::Auth::{BasicAuth, BearerToken, NoAuth};
+use crate::request::auth::Auth::{
+    ApiKey, AwsSigV4, BasicAuth, BearerToken, Digest, InheritFromCollection, Jwt, NoAuth,
+};
+use crate::request::auth::{ApiKeyLocation, Auth};
 use crate::request::body::ContentType;
+use crate::request::graphql::graphql_payload;
 use crate::request::request::Request;
 use crate::request::response::{ImageResponse, RequestResponse, ResponseContent};
+use crate::request::settings::{HttpVersion, RequestSettings};
+use crate::request::websocket::{is_websocket_url, WebSocketStatus};
 use crate::utils::find_file_format_in_content_type;
 use crate::utils::syntax_highlighting::highlight;
 
+#[derive(Debug, Display)]
+pub enum PrepareRequestError {
+    #[strum(to_string = "(CONSOLE) PRE-SCRIPT ERROR")]
+    PreRequestScript,
+    #[strum(to_string = "INVALID URL")]
+    InvalidUrl,
+    #[strum(to_string = "INVALID METHOD")]
+    InvalidMethod,
+    #[strum(to_string = "COULD NOT OPEN FILE")]
+    CouldNotOpenFile,
+    #[strum(to_string = "INVALID CERTIFICATE")]
+    InvalidCertificate,
+    #[strum(to_string = "OAUTH2 ERROR")]
+    OAuth2,
+    #[strum(to_string = "JWT ERROR")]
+    Jwt,
+    #[strum(to_string = "INVALID GRAPHQL VARIABLES")]
+    GraphQLVariables,
+    #[strum(to_string = "GRAPHQL INTROSPECTION ERROR")]
+    GraphQLIntrospection,
+    #[strum(to_string = "DIGEST AUTH NOT SUPPORTED WITH A FILE OR MULTIPART BODY")]
+    DigestStreamedBody,
+    #[strum(to_string = "AUTH NOT SUPPORTED BY WEBSOCKET")]
+    WebSocketAuth,
+    #[strum(to_string = "WEBSOCKET REQUESTS ARE ONLY AVAILABLE IN THE TUI")]
+    WebSocketHeadless,
+}
+
 impl App<'_> {
     pub async fn send_request(&mut self) {
-        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
             let mut selected_request = local_selected_request.write();
@@ -37,448 +77,721 @@ impl App<'_> {
                 return;
             }
 
-            let mut client_builder = ClientBuilder::new()
-                .default_headers(HeaderMap::new())
-                .referer(false);
+            // The connection is already open, write a message instead
+            if let Some(session) = &selected_request.websocket {
+                if session.is_open() {
+                    drop(selected_request);
+                    self.edit_websocket_message_state();
+                    return;
+                }
+
+                // Keep the connection until the server answers the close frame, so that its
+                // close code is displayed. Cancelling again aborts it
+                if session.status == WebSocketStatus::Closing {
+                    return;
+                }
+            }
+
+            /* COLLECTION HEADERS, AUTH & VARIABLES */
+
+            let request =
+                self.apply_collection_to_request(selected_request_index[0], &selected_request);
+
+            /* PRE-REQUEST SCRIPT */
+
+            let (modified_request, console_output) = self.handle_pre_request_script(&request);
+
+            let mut local_console_output = self.script_console.console_output.write();
+            let mut local_highlighted_console_output =
+                self.syntax_highlighting.highlighted_console_output.write();
 
-            /* REDIRECTS */
+            // Resets the data
+            *local_console_output = None;
+            *local_highlighted_console_output = vec![];
 
-            if !selected_request.settings.allow_redirects {
-                client_builder = client_builder.redirect(Policy::none());
+            if let Some(console_output) = console_output {
+                *local_highlighted_console_output =
+                    highlight_console_output(&console_output, "Pre-request");
+                *local_console_output = Some(console_output);
             }
 
-            /* STORE COOKIES */
+            // Drops the write mutex
+            drop(local_console_output);
+            drop(local_highlighted_console_output);
+
+            let modified_request = match modified_request {
+                None => {
+                    selected_request.response.status_code =
+                        Some(PrepareRequestError::PreRequestScript.to_string());
+                    return;
+                }
+                Some(modified_request) => modified_request,
+            };
+
+            let request = match self.prepare_request(&modified_request) {
+                Ok(request) => request,
+                Err(prepare_request_error) => {
+                    selected_request.response.status_code = Some(prepare_request_error.to_string());
+                    return;
+                }
+            };
 
-            let should_store_cookies = selected_request.settings.store_received_cookies;
+            let send_time_auth = self.get_send_time_auth(&modified_request);
 
-            client_builder = client_builder.cookie_store(should_store_cookies);
+            /* WEBSOCKET */
 
-            /* PROXY */
+            // A previous connection, already closed, could still write in the new log
+            if let Some(abort_handle) = selected_request.abort_handle.take() {
+                abort_handle.abort();
+            }
 
-            if selected_request.settings.use_config_proxy {
-                match &self.config.proxy {
-                    None => {}
-                    Some(proxy) => {
-                        match &proxy.http_proxy {
-                            None => {}
-                            Some(http_proxy_str) => {
-                                let proxy = match Proxy::http(http_proxy_str) {
-                                    Ok(proxy) => proxy,
-                                    Err(e) => {
-                                        panic_error(format!("Could not parse HTTP proxy\n\t{e}"))
-                                    }
-                                };
-                                client_builder = client_builder.proxy(proxy);
-                            }
-                        }
+            selected_request.websocket = None;
 
-                        match &proxy.https_proxy {
-                            None => {}
-                            Some(https_proxy_str) => {
-                                let proxy = match Proxy::https(https_proxy_str) {
-                                    Ok(proxy) => proxy,
-                                    Err(e) => {
-                                        panic_error(format!("Could not parse HTTPS proxy\n\t{e}"))
-                                    }
-                                };
-                                client_builder = client_builder.proxy(proxy);
-                            }
-                        }
+            let url = self.replace_env_keys_by_value(&modified_request.url);
+
+            if is_websocket_url(&url) {
+                let connector = match self.websocket_tls_connector(&modified_request.settings) {
+                    Ok(connector) => connector,
+                    Err(prepare_request_error) => {
+                        selected_request.response.status_code =
+                            Some(prepare_request_error.to_string());
+                        return;
                     }
-                }
+                };
+
+                let connection_task = task::spawn(open_websocket_session(
+                    request,
+                    send_time_auth,
+                    modified_request.settings.clone(),
+                    connector,
+                    self.get_websocket_proxy(&modified_request.settings, &url),
+                    self.get_selected_request_as_local(),
+                ));
+
+                selected_request.is_pending = true;
+                selected_request.abort_handle = Some(Arc::new(connection_task.abort_handle()));
+
+                return;
             }
 
-            /* COOKIES */
+            let local_selected_request = self.get_selected_request_as_local();
+            let local_env = self.get_selected_env_as_local();
+            let local_console_output = Arc::clone(&self.script_console.console_output);
+            let local_highlighted_body = Arc::clone(&self.syntax_highlighting.highlighted_body);
+            let local_highlighted_console_output =
+                Arc::clone(&self.syntax_highlighting.highlighted_console_output);
 
-            let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
-            client_builder = client_builder.cookie_provider(local_cookie_store);
+            /* SEND REQUEST */
 
-            /* PRE-REQUEST SCRIPT */
-            let mut local_console_output = self.script_console.console_output.write();
-            let mut local_highlighted_console_output =
-                self.syntax_highlighting.highlighted_console_output.write();
+            let settings = modified_request.settings;
 
-            // Resets the data
-            *local_console_output = None;
-            *local_highlighted_console_output = vec![];
+            let send_task = task::spawn(async move {
+                let response = send_prepared_request(request, send_time_auth, &settings)
+                    .await
+                    .unwrap_or_else(send_error_response);
+
+                /* SYNTAX HIGHLIGHTING */
+
+                match (
+                    &response.content,
+                    find_file_format_in_content_type(&response.headers),
+                ) {
+                    (Some(ResponseContent::Body(body)), Some(file_format)) => {
+                        *local_highlighted_body.write() = highlight(body, &file_format);
+                    }
+                    _ => *local_highlighted_body.write() = None,
+                }
+
+                /* POST-REQUEST SCRIPT */
 
-            let modified_request: Request = match &selected_request.scripts.pre_request_script {
-                None => selected_request.clone(),
-                Some(pre_request_script) => {
-                    let local_env = self.get_selected_env_as_local();
+                let mut selected_request = local_selected_request.write();
 
-                    let env_values = match &local_env {
-                        None => None,
-                        Some(local_env) => {
-                            let env = local_env.read();
-                            Some(env.values.clone())
+                let (modified_response, post_request_console_output) =
+                    handle_post_request_script(&selected_request, response, &local_env);
+
+                if let Some(post_request_console_output) = post_request_console_output {
+                    local_highlighted_console_output
+                        .write()
+                        .extend(highlight_console_output(
+                            &post_request_console_output,
+                            "Post-request",
+                        ));
+
+                    let mut console_output = local_console_output.write();
+
+                    *console_output = match console_output.as_ref() {
+                        None => Some(post_request_console_output),
+                        Some(console_output) => {
+                            Some(format!("{console_output}\n{post_request_console_output}"))
                         }
                     };
+                }
+
+                selected_request.response = modified_response;
+                selected_request.is_pending = false;
+                selected_request.abort_handle = None;
+            });
+
+            selected_request.is_pending = true;
+            selected_request.abort_handle = Some(Arc::new(send_task.abort_handle()));
+        }
+    }
 
-                    let (result_request, env_variables, console_output) =
-                        execute_pre_request_script(
-                            pre_request_script,
-                            &selected_request,
-                            env_values,
-                        );
+    pub fn cancel_request(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+        let mut selected_request = local_selected_request.write();
 
-                    match &local_env {
+        match selected_request.websocket.as_mut() {
+            Some(session) if session.is_open() => {
+                close_websocket_session(session);
+                return;
+            }
+            // The server did not answer the close frame
+            Some(session) if session.status == WebSocketStatus::Closing => {
+                if let Some(abort_handle) = selected_request.abort_handle.take() {
+                    abort_handle.abort();
+                }
+
+                if let Some(session) = selected_request.websocket.as_mut() {
+                    session.status = WebSocketStatus::Closed {
+                        code: None,
+                        reason: String::from("aborted"),
+                    };
+                }
+
+                return;
+            }
+            _ => {}
+        }
+
+        if !selected_request.is_pending {
+            return;
+        }
+
+        // Dropping the task also resets an OAuth2 token being fetched, see FetchingGuard
+        if let Some(abort_handle) = selected_request.abort_handle.take() {
+            abort_handle.abort();
+        }
+
+        selected_request.response = RequestResponse {
+            status_code: Some(String::from("CANCELLED")),
+            ..Default::default()
+        };
+        selected_request.is_pending = false;
+
+        *self.syntax_highlighting.highlighted_body.write() = None;
+    }
+
+    /// Build the HTTP client and the request to send from an already pre-scripted request,
+    /// replacing the environment keys by their values
+    pub fn prepare_request(
+        &self,
+        modified_request: &Request,
+    ) -> Result<RequestBuilder, PrepareRequestError> {
+        let mut client_builder = ClientBuilder::new()
+            .default_headers(HeaderMap::new())
+            .referer(false);
+
+        /* REDIRECTS */
+
+        if !modified_request.settings.allow_redirects {
+            client_builder = client_builder.redirect(Policy::none());
+        }
+
+        /* TIMEOUTS */
+
+        if modified_request.settings.timeout_ms > 0 {
+            client_builder = client_builder.timeout(Duration::from_millis(
+                modified_request.settings.timeout_ms as u64,
+            ));
+        }
+
+        if modified_request.settings.connect_timeout_ms > 0 {
+            client_builder = client_builder.connect_timeout(Duration::from_millis(
+                modified_request.settings.connect_timeout_ms as u64,
+            ));
+        }
+
+        /* HTTP VERSION */
+
+        match modified_request.settings.http_version {
+            HttpVersion::Auto => {}
+            HttpVersion::Http1Only => client_builder = client_builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => {
+                client_builder = client_builder.http2_prior_knowledge()
+            }
+        }
+
+        /* STORE COOKIES */
+
+        let should_store_cookies = modified_request.settings.store_received_cookies;
+
+        client_builder = client_builder.cookie_store(should_store_cookies);
+
+        /* PROXY */
+
+        if modified_request.settings.use_config_proxy {
+            match &self.config.proxy {
+                None => {}
+                Some(proxy) => {
+                    match &proxy.http_proxy {
                         None => {}
-                        Some(local_env) => match env_variables {
-                            None => {}
-                            Some(env_variables) => {
-                                let mut env = local_env.write();
-                                env.values = env_variables;
-                                save_environment_to_file(&env);
-                            }
-                        },
+                        Some(http_proxy_str) => {
+                            let proxy = match Proxy::http(http_proxy_str) {
+                                Ok(proxy) => proxy,
+                                Err(e) => panic_error(format!("Could not parse HTTP proxy\n\t{e}")),
+                            };
+                            client_builder = client_builder.proxy(proxy);
+                        }
                     }
 
-                    let mut highlighted_console_output =
-                        highlight(&console_output, "json").unwrap();
-
-                    highlighted_console_output.insert(0, Line::default());
-                    highlighted_console_output.insert(
-                        1,
-                        Line::raw("----- Pre-request script start -----")
-                            .dark_gray()
-                            .centered(),
-                    );
-                    highlighted_console_output.push(
-                        Line::raw("----- Pre-request script end -----")
-                            .dark_gray()
-                            .centered(),
-                    );
-
-                    *local_highlighted_console_output = highlighted_console_output;
-
-                    *local_console_output = Some(console_output);
-
-                    match result_request {
-                        None => {
-                            selected_request.response.status_code =
-                                Some(String::from("(CONSOLE) PRE-SCRIPT ERROR"));
-                            return;
+                    match &proxy.https_proxy {
+                        None => {}
+                        Some(https_proxy_str) => {
+                            let proxy = match Proxy::https(https_proxy_str) {
+                                Ok(proxy) => proxy,
+                                Err(e) => {
+                                    panic_error(format!("Could not parse HTTPS proxy\n\t{e}"))
+                                }
+                            };
+                            client_builder = client_builder.proxy(proxy);
                         }
-                        Some(request) => request,
                     }
                 }
-            };
+            }
+        }
 
-            // Drops the write mutex
-            drop(local_console_output);
-            drop(local_highlighted_console_output);
+        /* TLS */
 
-            /* CLIENT */
+        if modified_request.settings.accept_invalid_certs {
+            client_builder = client_builder.danger_accept_invalid_certs(true);
+        }
 
-            let client = client_builder.build().expect("Could not build HTTP client");
+        if modified_request.settings.use_config_tls {
+            if let Some(tls_settings) = &self.config.tls {
+                client_builder = self.apply_tls_settings(client_builder, tls_settings)?;
+            }
+        }
 
-            /* PARAMS */
+        if let Some(tls_settings) = &modified_request.settings.tls {
+            client_builder = self.apply_tls_settings(client_builder, tls_settings)?;
+        }
 
-            let params = self.key_value_vec_to_tuple_vec(&modified_request.params);
+        /* COOKIES */
 
-            /* URL */
+        let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
+        client_builder = client_builder.cookie_provider(local_cookie_store);
 
-            let url = self.replace_env_keys_by_value(&modified_request.url);
+        /* CLIENT */
 
-            let url = match Url::parse_with_params(&url, params) {
-                Ok(url) => url,
-                Err(_) => {
-                    selected_request.response.status_code = Some(String::from("INVALID URL"));
-                    return;
-                }
-            };
+        let client = client_builder.build().expect("Could not build HTTP client");
 
-            /* REQUEST */
+        /* PARAMS */
 
-            let mut request = client.request(modified_request.method.to_reqwest(), url);
+        let params = self.key_value_vec_to_tuple_vec(&modified_request.params);
 
-            /* CORS */
+        /* URL */
 
-            if self.config.is_cors_disabled() {
-                request = request.fetch_mode_no_cors();
-            }
+        let url = self.replace_env_keys_by_value(&modified_request.url);
 
-            /* AUTH */
+        let url = match Url::parse_with_params(&url, params) {
+            Ok(url) => url,
+            Err(_) => return Err(PrepareRequestError::InvalidUrl),
+        };
 
-            match &modified_request.auth {
-                NoAuth => {}
-                BasicAuth(username, password) => {
-                    let username = self.replace_env_keys_by_value(username);
-                    let password = self.replace_env_keys_by_value(password);
+        /* REQUEST */
 
-                    request = request.basic_auth(username, Some(password));
-                }
-                BearerToken(bearer_token) => {
-                    let bearer_token = self.replace_env_keys_by_value(bearer_token);
+        let method = match modified_request.method.to_reqwest() {
+            Some(method) => method,
+            None => return Err(PrepareRequestError::InvalidMethod),
+        };
 
-                    request = request.bearer_auth(bearer_token);
-                }
+        let mut request = client.request(method, url);
+
+        /* CORS */
+
+        if self.config.is_cors_disabled() {
+            request = request.fetch_mode_no_cors();
+        }
+
+        /* AUTH */
+
+        match &modified_request.auth {
+            // The inherited auth has already been replaced by the collection one
+            NoAuth | InheritFromCollection => {}
+            BasicAuth(username, password) => {
+                let username = self.replace_env_keys_by_value(username);
+                let password = self.replace_env_keys_by_value(password);
+
+                request = request.basic_auth(username, Some(password));
             }
+            BearerToken(bearer_token) => {
+                let bearer_token = self.replace_env_keys_by_value(bearer_token);
 
-            /* BODY */
+                request = request.bearer_auth(bearer_token);
+            }
+            ApiKey(api_key) => {
+                let key = self.replace_env_keys_by_value(&api_key.key);
+                let value = self.replace_env_keys_by_value(&api_key.value);
 
-            match &modified_request.body {
-                ContentType::NoBody => {}
-                ContentType::Multipart(form_data) => {
-                    let mut multipart = Form::new();
+                request = match api_key.location {
+                    ApiKeyLocation::Header => request.header(key, value),
+                    ApiKeyLocation::Query => request.query(&[(key, value)]),
+                };
+            }
+            // Handled right before sending the request, see get_send_time_auth
+            Digest(_, _) | AwsSigV4(_) | Jwt(_) | Auth::OAuth1(_) | Auth::OAuth2(_) => {}
+        }
 
-                    for form_data in form_data {
-                        let key = self.replace_env_keys_by_value(&form_data.data.0);
-                        let value = self.replace_env_keys_by_value(&form_data.data.1);
+        /* BODY */
 
-                        // If the value starts with !!, then it is supposed to be a file
-                        if let Some(path) = value.strip_prefix("!!") {
-                            match get_file_content_with_name(path.into()) {
-                                Ok((file_content, file_name)) => {
-                                    let part = Part::bytes(file_content).file_name(file_name);
-                                    multipart = multipart.part(key, part);
-                                }
-                                Err(_) => {
-                                    selected_request.response.status_code =
-                                        Some(String::from("COULD NOT OPEN FILE"));
-                                    return;
-                                }
+        // A streamed body cannot be sent again to answer the digest challenge
+        if matches!(modified_request.auth, Digest(_, _))
+            && matches!(
+                modified_request.body,
+                ContentType::Multipart(_) | ContentType::File(_)
+            )
+        {
+            return Err(PrepareRequestError::DigestStreamedBody);
+        }
+
+        match &modified_request.body {
+            ContentType::NoBody => {}
+            ContentType::Multipart(form_data) => {
+                let mut multipart = Form::new();
+
+                for form_data in form_data {
+                    let key = self.replace_env_keys_by_value(&form_data.data.0);
+                    let value = self.replace_env_keys_by_value(&form_data.data.1);
+
+                    // If the value starts with !!, then it is supposed to be a file
+                    if let Some(path) = value.strip_prefix("!!") {
+                        match get_file_content_with_name(path.into()) {
+                            Ok((file_content, file_name)) => {
+                                let part = Part::bytes(file_content).file_name(file_name);
+                                multipart = multipart.part(key, part);
                             }
-                        } else {
-                            multipart = multipart.text(key, value);
+                            Err(_) => return Err(PrepareRequestError::CouldNotOpenFile),
                         }
+                    } else {
+                        multipart = multipart.text(key, value);
                     }
-
-                    request = request.multipart(multipart);
                 }
-                ContentType::Form(form_data) => {
-                    let form = self.key_value_vec_to_tuple_vec(form_data);
 
-                    request = request.form(&form);
-                }
-                ContentType::File(file_path) => {
-                    let file_path_with_env_values = self.replace_env_keys_by_value(file_path);
-                    let path = PathBuf::from(file_path_with_env_values);
+                request = request.multipart(multipart);
+            }
+            ContentType::Form(form_data) => {
+                let form = self.key_value_vec_to_tuple_vec(form_data);
 
-                    match tokio::fs::File::open(path).await {
-                        Ok(file) => {
-                            request = request.body(file);
-                        }
-                        Err(_) => {
-                            selected_request.response.status_code =
-                                Some(String::from("COULD NOT OPEN FILE"));
-                            return;
-                        }
+                request = request.form(&form);
+            }
+            ContentType::File(file_path) => {
+                let file_path_with_env_values = self.replace_env_keys_by_value(file_path);
+                let path = PathBuf::from(file_path_with_env_values);
+
+                // Opened synchronously, so that no lock is held across an await while preparing
+                match File::open(path) {
+                    Ok(file) => {
+                        request = request.body(tokio::fs::File::from_std(file));
                     }
+                    Err(_) => return Err(PrepareRequestError::CouldNotOpenFile),
                 }
-                ContentType::Raw(body)
-                | ContentType::Json(body)
-                | ContentType::Xml(body)
-                | ContentType::Html(body)
-                | ContentType::Javascript(body) => {
-                    request = request.body(body.to_string());
+            }
+            ContentType::Raw(body)
+            | ContentType::Json(body)
+            | ContentType::Xml(body)
+            | ContentType::Html(body)
+            | ContentType::Javascript(body) => {
+                request = request.body(body.to_string());
+            }
+            ContentType::GraphQL { query, variables } => {
+                let query = self.replace_env_keys_by_value(query);
+                let variables = self.replace_env_keys_by_value(variables);
+
+                match graphql_payload(&query, &variables) {
+                    Ok(payload) => request = request.body(payload),
+                    Err(_) => return Err(PrepareRequestError::GraphQLVariables),
                 }
-            };
+            }
+        };
+
+        /* HEADERS */
+
+        for header in &modified_request.headers {
+            if !header.enabled {
+                continue;
+            }
+
+            let header_name = self.replace_env_keys_by_value(&header.data.0);
+            let header_value = self.replace_env_keys_by_value(&header.data.1);
+
+            request = request.header(header_name, header_value);
+        }
 
-            /* HEADERS */
+        Ok(request)
+    }
 
-            for header in &modified_request.headers {
-                if !header.enabled {
-                    continue;
+    /// Resolved auth of an already pre-scripted request, if it needs more than headers.
+    /// e.g. an OAuth2 token to fetch, a JWT to generate, a digest challenge or the request to sign
+    pub fn get_send_time_auth(&self, modified_request: &Request) -> Option<Auth> {
+        match &modified_request.auth {
+            Digest(username, password) => Some(Digest(
+                self.replace_env_keys_by_value(username),
+                self.replace_env_keys_by_value(password),
+            )),
+            AwsSigV4(aws_sig_v4) => {
+                let mut aws_sig_v4 = aws_sig_v4.clone();
+
+                for value in aws_sig_v4.values_mut() {
+                    *value = self.replace_env_keys_by_value(value);
                 }
 
-                let header_name = self.replace_env_keys_by_value(&header.data.0);
-                let header_value = self.replace_env_keys_by_value(&header.data.1);
+                Some(AwsSigV4(aws_sig_v4))
+            }
+            Jwt(jwt) => {
+                let mut jwt = jwt.clone();
+
+                for value in jwt.values_mut() {
+                    *value = self.replace_env_keys_by_value(value);
+                }
 
-                request = request.header(header_name, header_value);
+                Some(Jwt(jwt))
             }
+            Auth::OAuth1(oauth1) => {
+                let mut oauth1 = oauth1.clone();
 
-            let local_selected_request = self.get_selected_request_as_local();
-            let local_env = self.get_selected_env_as_local();
-            let local_console_output = Arc::clone(&self.script_console.console_output);
-            let local_highlighted_body = Arc::clone(&self.syntax_highlighting.highlighted_body);
-            let local_highlighted_console_output =
-                Arc::clone(&self.syntax_highlighting.highlighted_console_output);
+                for value in oauth1.values_mut() {
+                    *value = self.replace_env_keys_by_value(value);
+                }
 
-            /* SEND REQUEST */
+                Some(Auth::OAuth1(oauth1))
+            }
+            Auth::OAuth2(oauth2) => Some(Auth::OAuth2(self.resolve_oauth2(oauth2))),
+            _ => None,
+        }
+    }
+}
 
-            task::spawn(async move {
-                local_selected_request.write().is_pending = true;
+/// Send a prepared request and wait for the response, applying the auth from get_send_time_auth.
+/// The request is sent again according to the retry settings.
+/// Returns an error with its details if the auth prevented the request from being sent
+pub async fn send_prepared_request(
+    mut request: RequestBuilder,
+    send_time_auth: Option<Auth>,
+    settings: &RequestSettings,
+) -> Result<RequestResponse, (PrepareRequestError, String)> {
+    /* OAUTH2 */
+
+    if let Some(Auth::OAuth2(oauth2)) = &send_time_auth {
+        match apply_oauth2_token(request, oauth2).await {
+            Ok(authorized_request) => request = authorized_request,
+            Err(error) => return Err((PrepareRequestError::OAuth2, error)),
+        }
+    }
 
-                let request_start = Instant::now();
-                let elapsed_time: Duration;
+    /* JWT */
 
-                let mut response = match request.send().await {
-                    Ok(response) => {
-                        elapsed_time = request_start.elapsed();
+    // Generated on each send, so that iat and exp are up to date
+    if let Some(Jwt(jwt)) = &send_time_auth {
+        match generate_jwt(jwt) {
+            Ok(token) => request = request.bearer_auth(token),
+            Err(error) => return Err((PrepareRequestError::Jwt, error)),
+        }
+    }
 
-                        let status_code = response.status().to_string();
+    /* RETRIES */
 
-                        let mut is_image = false;
+    let mut attempts = 1;
+    let mut request_start;
 
-                        let headers: Vec<(String, String)> = response
-                            .headers()
-                            .clone()
-                            .iter()
-                            .map(|(header_name, header_value)| {
-                                let value = header_value.to_str().unwrap_or("").to_string();
+    let response = loop {
+        // A streamed body, e.g. a file, cannot be sent twice
+        let retry_request = match attempts < settings.max_attempts {
+            true => request.try_clone(),
+            false => None,
+        };
 
-                                if header_name == CONTENT_TYPE && value.starts_with("image/") {
-                                    is_image = true;
-                                }
+        request_start = Instant::now();
 
-                                (header_name.to_string(), value)
-                            })
-                            .collect();
-
-                        let cookies = response
-                            .cookies()
-                            .map(|cookie| format!("{}: {}", cookie.name(), cookie.value()))
-                            .collect::<Vec<String>>()
-                            .join("\n");
-
-                        let response_content = if is_image {
-                            let content = response.bytes().await.unwrap();
-                            let image = image::load_from_memory(content.as_ref());
-
-                            ResponseContent::Image(ImageResponse {
-                                data: content.to_vec(),
-                                image: image.ok(),
-                            })
-                        } else {
-                            let mut result_body = response.text().await.unwrap();
-
-                            // If a file format has been found in the content-type header
-                            if let Some(file_format) = find_file_format_in_content_type(&headers) {
-                                // If the request response content can be pretty printed
-                                if local_selected_request
-                                    .read()
-                                    .settings
-                                    .pretty_print_response_content
-                                {
-                                    // Match the file format
-                                    if file_format.as_str() == "json" {
-                                        result_body = jsonxf::pretty_print(&result_body)
-                                            .unwrap_or(result_body);
-                                    }
-                                }
+        let response = match &send_time_auth {
+            Some(Digest(username, password)) => {
+                send_with_digest_auth(request, username, password).await
+            }
+            Some(AwsSigV4(aws_sig_v4)) => send_with_aws_sig_v4(request, aws_sig_v4).await,
+            Some(Auth::OAuth1(oauth1)) => send_with_oauth1(request, oauth1).await,
+            _ => request.send().await,
+        };
+
+        let should_retry = match &response {
+            Ok(response) => settings
+                .retry_on_status_codes
+                .contains(&response.status().as_u16()),
+            Err(error) => {
+                settings.retry_on_connection_errors && (error.is_connect() || error.is_timeout())
+            }
+        };
 
-                                let highlighted_result_body = highlight(&result_body, &file_format);
-                                *local_highlighted_body.write() = highlighted_result_body;
-                            } else {
-                                *local_highlighted_body.write() = None;
-                            }
+        match retry_request {
+            Some(retry_request) if should_retry => {
+                // Exponential backoff
+                let backoff = (settings.retry_backoff_ms as u64)
+                    .saturating_mul(2_u64.saturating_pow(attempts - 1));
+                sleep(Duration::from_millis(backoff)).await;
+
+                request = retry_request;
+                attempts += 1;
+            }
+            _ => break response,
+        }
+    };
 
-                            ResponseContent::Body(result_body)
-                        };
+    let elapsed_time: Duration;
 
-                        RequestResponse {
-                            duration: None,
-                            status_code: Some(status_code),
-                            content: Some(response_content),
-                            cookies: Some(cookies),
-                            headers,
-                        }
-                    }
-                    Err(error) => {
-                        elapsed_time = request_start.elapsed();
+    let mut response = match response {
+        Ok(response) => {
+            elapsed_time = request_start.elapsed();
 
-                        let response_status_code;
+            let status_code = response.status().to_string();
+            let http_version = format!("{:?}", response.version());
 
-                        if let Some(status_code) = error.status() {
-                            response_status_code = Some(status_code.to_string());
-                        } else {
-                            response_status_code = None;
-                        }
+            let mut is_image = false;
 
-                        let result_body = ResponseContent::Body(error.to_string());
+            let headers: Vec<(String, String)> = response
+                .headers()
+                .clone()
+                .iter()
+                .map(|(header_name, header_value)| {
+                    let value = header_value.to_str().unwrap_or("").to_string();
 
-                        RequestResponse {
-                            duration: None,
-                            status_code: response_status_code,
-                            content: Some(result_body),
-                            cookies: None,
-                            headers: vec![],
-                        }
+                    if header_name == CONTENT_TYPE && value.starts_with("image/") {
+                        is_image = true;
                     }
-                };
 
-                response.duration = Some(format!("{:?}", elapsed_time));
+                    (header_name.to_string(), value)
+                })
+                .collect();
+
+            let cookies = response
+                .cookies()
+                .map(|cookie| format!("{}: {}", cookie.name(), cookie.value()))
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            match read_response_content(response, is_image, &headers, settings).await {
+                Ok(response_content) => RequestResponse {
+                    duration: None,
+                    status_code: Some(status_code),
+                    attempts,
+                    http_version: Some(http_version),
+                    content: Some(response_content),
+                    cookies: Some(cookies),
+                    headers,
+                },
+                // e.g. the timeout elapsed while the body was being received
+                Err(error) => RequestResponse {
+                    duration: None,
+                    status_code: None,
+                    attempts,
+                    http_version: None,
+                    content: Some(error_content(&error)),
+                    cookies: Some(cookies),
+                    headers,
+                },
+            }
+        }
+        Err(error) => {
+            elapsed_time = request_start.elapsed();
 
-                /* POST-REQUEST SCRIPT */
+            let response_status_code;
 
-                let mut selected_request = local_selected_request.write();
-                let mut console_output = local_console_output.write();
-
-                let modified_response: RequestResponse = match &selected_request
-                    .scripts
-                    .post_request_script
-                {
-                    None => response,
-                    Some(post_request_script) => {
-                        let env_values = match &local_env {
-                            None => None,
-                            Some(local_env) => {
-                                let env = local_env.read();
-                                Some(env.values.clone())
-                            }
-                        };
-
-                        let (result_response, env_variables, result_console_output) =
-                            execute_post_request_script(post_request_script, &response, env_values);
-
-                        match &local_env {
-                            None => {}
-                            Some(local_env) => match env_variables {
-                                None => {}
-                                Some(env_variables) => {
-                                    let mut env = local_env.write();
-                                    env.values = env_variables;
-                                    save_environment_to_file(&env);
-                                }
-                            },
-                        }
+            if let Some(status_code) = error.status() {
+                response_status_code = Some(status_code.to_string());
+            } else {
+                response_status_code = None;
+            }
 
-                        let mut highlighted_console_output =
-                            highlight(&result_console_output, "json").unwrap();
-
-                        highlighted_console_output.insert(0, Line::default());
-                        highlighted_console_output.insert(
-                            1,
-                            Line::raw("----- Post-request script start -----")
-                                .dark_gray()
-                                .centered(),
-                        );
-                        highlighted_console_output.push(
-                            Line::raw("----- Post-request script end -----")
-                                .dark_gray()
-                                .centered(),
-                        );
-
-                        let mut local_highlighted_console_output =
-                            local_highlighted_console_output.write();
-
-                        local_highlighted_console_output.extend(highlighted_console_output);
-
-                        *console_output = match console_output.as_ref() {
-                            None => Some(result_console_output),
-                            Some(console_output) => {
-                                Some(format!("{console_output}\n{result_console_output}"))
-                            }
-                        };
+            RequestResponse {
+                duration: None,
+                status_code: response_status_code,
+                attempts,
+                http_version: None,
+                content: Some(error_content(&error)),
+                cookies: None,
+                headers: vec![],
+            }
+        }
+    };
 
-                        match result_response {
-                            None => {
-                                response.status_code =
-                                    Some(String::from("(CONSOLE) POST-SCRIPT ERROR"));
-                                response
-                            }
-                            Some(result_response) => result_response,
-                        }
-                    }
-                };
+    response.duration = Some(format!("{:?}", elapsed_time));
 
-                selected_request.response = modified_response;
-                selected_request.is_pending = false;
-            });
+    Ok(response)
+}
+
+async fn read_response_content(
+    response: Response,
+    is_image: bool,
+    headers: &[(String, String)],
+    settings: &RequestSettings,
+) -> reqwest::Result<ResponseContent> {
+    if is_image {
+        let content = response.bytes().await?;
+        let image = image::load_from_memory(content.as_ref());
+
+        return Ok(ResponseContent::Image(ImageResponse {
+            data: content.to_vec(),
+            image: image.ok(),
+        }));
+    }
+
+    let mut result_body = response.text().await?;
+
+    // If a file format has been found in the content-type header
+    if let Some(file_format) = find_file_format_in_content_type(headers) {
+        // If the request response content can be pretty printed
+        if settings.pretty_print_response_content {
+            // Match the file format
+            if file_format.as_str() == "json" {
+                result_body = jsonxf::pretty_print(&result_body).unwrap_or(result_body);
+            }
         }
     }
+
+    Ok(ResponseContent::Body(result_body))
+}
+
+fn error_content(error: &reqwest::Error) -> ResponseContent {
+    match error.is_timeout() {
+        true => ResponseContent::Body(format!("{error}\n\tTimed out")),
+        false => ResponseContent::Body(error.to_string()),
+    }
+}
+
+/// Response displaying the error that prevented a prepared request from being sent
+pub fn send_error_response((error, details): (PrepareRequestError, String)) -> RequestResponse {
+    RequestResponse {
+        status_code: Some(error.to_string()),
+        content: Some(ResponseContent::Body(details)),
+        ..Default::default()
+    }
+}
+
+fn highlight_console_output(console_output: &str, script_name: &str) -> Vec<Line<'static>> {
+    let mut highlighted_console_output = highlight(console_output, "json").unwrap();
+
+    highlighted_console_output.insert(0, Line::default());
+    highlighted_console_output.insert(
+        1,
+        Line::raw(format!("----- {script_name} script start -----"))
+            .dark_gray()
+            .centered(),
+    );
+    highlighted_console_output.push(
+        Line::raw(format!("----- {script_name} script end -----"))
+            .dark_gray()
+            .centered(),
+    );
+
+    highlighted_console_output
 }
 
 pub fn get_file_content_with_name(path: PathBuf) -> std::io::Result<(Vec<u8>, String)> {
@@ -491,3 +804,52 @@ pub fn get_file_content_with_name(path: PathBuf) -> std::io::Result<(Vec<u8>, St
 
     Ok((buffer, file_name.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Client;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::time::sleep;
+
+    use super::send_prepared_request;
+    use crate::request::response::ResponseContent;
+    use crate::request::settings::RequestSettings;
+
+    #[tokio::test]
+    async fn timeout_while_receiving_the_body() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        // Sends the headers, then only a part of the announced body
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer).await;
+
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 100\r\n\r\npartial")
+                .await
+                .unwrap();
+
+            sleep(Duration::from_secs(10)).await;
+        });
+
+        let client = Client::builder()
+            .timeout(Duration::from_millis(300))
+            .build()
+            .unwrap();
+
+        let response = send_prepared_request(client.get(url), None, &RequestSettings::default())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status_code, None);
+        match &response.content {
+            Some(ResponseContent::Body(body)) => assert!(body.ends_with("Timed out")),
+            content => panic!("Expected an error body, got {content:?}"),
+        }
+    }
+}
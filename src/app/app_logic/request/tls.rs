@@ -0,0 +1,270 @@
+use std::fs;
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use p12_keystore::KeyStore;
+use reqwest::{Certificate, ClientBuilder, Identity};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use tokio_tungstenite::Connector;
+
+use crate::app::app::App;
+use crate::app::app_logic::request::send::PrepareRequestError;
+use crate::request::settings::{RequestSettings, TlsSettings};
+
+impl App<'_> {
+    /// Add the CA certificates and the client identity to the HTTP client
+    pub fn apply_tls_settings(
+        &self,
+        mut client_builder: ClientBuilder,
+        tls_settings: &TlsSettings,
+    ) -> Result<ClientBuilder, PrepareRequestError> {
+        for ca_certificate in &tls_settings.ca_certificates {
+            let path = self.replace_env_keys_by_value(ca_certificate);
+
+            for certificate in read_ca_certificates(&path)? {
+                client_builder = client_builder.add_root_certificate(certificate);
+            }
+        }
+
+        if let Some(identity) = self.read_client_identity_pem(tls_settings)? {
+            let identity = Identity::from_pem(&identity)
+                .map_err(|_| PrepareRequestError::InvalidCertificate)?;
+
+            client_builder = client_builder.identity(identity);
+        }
+
+        Ok(client_builder)
+    }
+
+    /// Same TLS settings as the HTTP client, for the WebSocket connections
+    pub fn websocket_tls_connector(
+        &self,
+        settings: &RequestSettings,
+    ) -> Result<Connector, PrepareRequestError> {
+        let tls_settings: Vec<&TlsSettings> = [
+            self.config.tls.as_ref().filter(|_| settings.use_config_tls),
+            settings.tls.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let mut root_store = RootCertStore::empty();
+
+        // Same as the default connector, the certificates that cannot be parsed are skipped
+        for certificate in rustls_native_certs::load_native_certs().unwrap_or_default() {
+            let _ = root_store.add(certificate);
+        }
+
+        let mut identity = None;
+
+        for tls_settings in tls_settings {
+            for ca_certificate in &tls_settings.ca_certificates {
+                let path = self.replace_env_keys_by_value(ca_certificate);
+
+                for certificate in read_ca_certificates_der(&path)? {
+                    root_store
+                        .add(certificate)
+                        .map_err(|_| PrepareRequestError::InvalidCertificate)?;
+                }
+            }
+
+            // The request identity replaces the config one, as with the HTTP client
+            if let Some(client_identity) = self.read_client_identity_pem(tls_settings)? {
+                identity = Some(client_identity);
+            }
+        }
+
+        let provider = Arc::new(ring::default_provider());
+
+        let config_builder = ClientConfig::builder_with_provider(Arc::clone(&provider))
+            .with_safe_default_protocol_versions()
+            .map_err(|_| PrepareRequestError::InvalidCertificate)?;
+
+        let config_builder = match settings.accept_invalid_certs {
+            true => config_builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(AcceptInvalidCertificates(provider))),
+            false => config_builder.with_root_certificates(root_store),
+        };
+
+        let config = match identity {
+            None => config_builder.with_no_client_auth(),
+            Some(identity) => {
+                let certificates = rustls_pemfile::certs(&mut identity.as_slice())
+                    .collect::<Result<Vec<CertificateDer>, _>>()
+                    .map_err(|_| PrepareRequestError::InvalidCertificate)?;
+
+                let key = rustls_pemfile::private_key(&mut identity.as_slice())
+                    .ok()
+                    .flatten()
+                    .ok_or(PrepareRequestError::InvalidCertificate)?;
+
+                config_builder
+                    .with_client_auth_cert(certificates, key)
+                    .map_err(|_| PrepareRequestError::InvalidCertificate)?
+            }
+        };
+
+        Ok(Connector::Rustls(Arc::new(config)))
+    }
+
+    fn read_client_identity_pem(
+        &self,
+        tls_settings: &TlsSettings,
+    ) -> Result<Option<Vec<u8>>, PrepareRequestError> {
+        let client_certificate = match &tls_settings.client_certificate {
+            None => return Ok(None),
+            Some(client_certificate) => client_certificate,
+        };
+
+        let identity = read_client_identity(
+            &self.replace_env_keys_by_value(client_certificate),
+            tls_settings
+                .client_key
+                .as_ref()
+                .map(|client_key| self.replace_env_keys_by_value(client_key)),
+            tls_settings
+                .client_certificate_password
+                .as_ref()
+                .map(|password| self.replace_env_keys_by_value(password)),
+        )?;
+
+        Ok(Some(identity))
+    }
+}
+
+/// Mirrors danger_accept_invalid_certs of the HTTP client, the signatures are still checked
+#[derive(Debug)]
+struct AcceptInvalidCertificates(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptInvalidCertificates {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        certificate: &CertificateDer<'_>,
+        signature: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            certificate,
+            signature,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        certificate: &CertificateDer<'_>,
+        signature: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            certificate,
+            signature,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+fn read_ca_certificates(path: &str) -> Result<Vec<Certificate>, PrepareRequestError> {
+    let content = fs::read(path).map_err(|_| PrepareRequestError::CouldNotOpenFile)?;
+
+    let certificates = match is_pem(&content) {
+        true => Certificate::from_pem_bundle(&content),
+        false => Certificate::from_der(&content).map(|certificate| vec![certificate]),
+    };
+
+    certificates.map_err(|_| PrepareRequestError::InvalidCertificate)
+}
+
+fn read_ca_certificates_der(
+    path: &str,
+) -> Result<Vec<CertificateDer<'static>>, PrepareRequestError> {
+    let content = fs::read(path).map_err(|_| PrepareRequestError::CouldNotOpenFile)?;
+
+    match is_pem(&content) {
+        true => rustls_pemfile::certs(&mut content.as_slice())
+            .collect::<Result<Vec<CertificateDer>, _>>()
+            .map_err(|_| PrepareRequestError::InvalidCertificate),
+        false => Ok(vec![CertificateDer::from(content)]),
+    }
+}
+
+/// A PEM certificate with its key, from one or two files, or a PKCS12 archive
+fn read_client_identity(
+    certificate_path: &str,
+    key_path: Option<String>,
+    password: Option<String>,
+) -> Result<Vec<u8>, PrepareRequestError> {
+    let mut content =
+        fs::read(certificate_path).map_err(|_| PrepareRequestError::CouldNotOpenFile)?;
+
+    if !is_pem(&content) {
+        content = pkcs12_to_pem(&content, &password.unwrap_or_default())?;
+    }
+
+    if let Some(key_path) = key_path {
+        let key = fs::read(key_path).map_err(|_| PrepareRequestError::CouldNotOpenFile)?;
+
+        content.push(b'\n');
+        content.extend(key);
+    }
+
+    Ok(content)
+}
+
+/// The TLS backend only reads PEM identities
+fn pkcs12_to_pem(content: &[u8], password: &str) -> Result<Vec<u8>, PrepareRequestError> {
+    let key_store = KeyStore::from_pkcs12(content, password)
+        .map_err(|_| PrepareRequestError::InvalidCertificate)?;
+
+    let (_, private_key_chain) = key_store
+        .private_key_chain()
+        .ok_or(PrepareRequestError::InvalidCertificate)?;
+
+    let mut pem = pem_block("PRIVATE KEY", private_key_chain.key());
+
+    for certificate in private_key_chain.chain() {
+        pem += &pem_block("CERTIFICATE", certificate.as_der());
+    }
+
+    Ok(pem.into_bytes())
+}
+
+fn pem_block(label: &str, der: &[u8]) -> String {
+    let base64 = STANDARD.encode(der);
+
+    let lines = base64
+        .as_bytes()
+        .chunks(64)
+        .map(|line| String::from_utf8_lossy(line).to_string())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!("-----BEGIN {label}-----\n{lines}\n-----END {label}-----\n")
+}
+
+fn is_pem(content: &[u8]) -> bool {
+    String::from_utf8_lossy(content).contains("-----BEGIN")
+}
@@ -6,7 +6,7 @@ impl App<'_> {
     pub fn modify_request_url(&mut self) {
         let input_text = self.url_text_input.text.clone();
 
-        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let selected_request_index = &self.collections_tree.selected.clone().unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
 
         {
@@ -72,7 +72,7 @@ impl App<'_> {
         // In case new params were inputted or deleted
         self.update_query_params_selection();
 
-        self.save_collection_to_file(selected_request_index.0);
+        self.save_collection_to_file(selected_request_index[0]);
         self.select_request_state();
     }
 }
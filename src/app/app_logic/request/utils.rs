@@ -3,23 +3,75 @@ use std::sync::Arc;
 use arboard::{Clipboard, ImageData};
 use image::EncodableLayout;
 use parking_lot::RwLock;
+use ratatui::text::Line;
 
 use crate::app::app::App;
 use crate::app::ui::result_tabs::RequestResultTabs;
 use crate::request::request::Request;
 use crate::request::response::ResponseContent;
+use crate::utils::syntax_highlighting::highlight;
 
 impl App<'_> {
     pub fn get_selected_request_as_local(&self) -> Arc<RwLock<Request>> {
-        let selected_request_index = &self.collections_tree.selected.unwrap();
-        self.collections[selected_request_index.0].requests[selected_request_index.1].clone()
+        let selected_request_index = self.collections_tree.selected.as_ref().unwrap();
+        self.get_request_as_local_from_indexes(selected_request_index)
     }
 
+    /// Get a request from its tree path, starting with the collection index
     pub fn get_request_as_local_from_indexes(
         &self,
-        selected_request_index: &(usize, usize),
+        selected_request_index: &[usize],
     ) -> Arc<RwLock<Request>> {
-        self.collections[selected_request_index.0].requests[selected_request_index.1].clone()
+        self.collections[selected_request_index[0]]
+            .get_request(&selected_request_index[1..])
+            .unwrap()
+    }
+
+    /// Copy the selected request as a curl command line to the clipboard, with the collection and environment values
+    pub fn copy_request_as_curl_to_clipboard(&self) {
+        let selected_request_index = self.collections_tree.selected.as_ref().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+        let selected_request = self
+            .apply_collection_to_request(selected_request_index[0], &local_selected_request.read());
+
+        let curl = self.request_to_curl(&selected_request, true);
+
+        let mut clipboard = Clipboard::new().unwrap();
+
+        clipboard
+            .set_text(curl)
+            .expect("Could not copy cURL command to clipboard");
+    }
+
+    /// Render the selected request in the code snippet popup's language, with the collection and environment values
+    pub fn update_code_snippet(&mut self) {
+        let selected_request_index = self.collections_tree.selected.as_ref().unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+        let selected_request = self
+            .apply_collection_to_request(selected_request_index[0], &local_selected_request.read());
+
+        let language = self.code_snippet_popup.language;
+        let snippet = self.request_to_code_snippet(&selected_request, language);
+
+        // Falls back to the raw lines if the language has no syntax
+        let highlighted_snippet = highlight(&snippet, language.file_extension()).unwrap_or(
+            snippet
+                .lines()
+                .map(|line| Line::from(line.to_string()))
+                .collect(),
+        );
+
+        self.code_snippet_popup.snippet = snippet;
+        self.code_snippet_popup.highlighted_snippet = highlighted_snippet;
+        self.code_snippet_popup.scroll = 0;
+    }
+
+    pub fn copy_code_snippet_to_clipboard(&self) {
+        let mut clipboard = Clipboard::new().unwrap();
+
+        clipboard
+            .set_text(&self.code_snippet_popup.snippet)
+            .expect("Could not copy code snippet to clipboard");
     }
 
     /// Copy the response's body content to the clipboard if it's present, otherwise does nothing
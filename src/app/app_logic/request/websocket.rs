@@ -0,0 +1,476 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use futures_util::{SinkExt, StreamExt};
+use parking_lot::RwLock;
+use percent_encoding::percent_decode_str;
+use reqwest::{RequestBuilder, Url};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::client::{Request as WebSocketRequest, Response};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::{Error, Message};
+use tokio_tungstenite::{
+    client_async_tls_with_config, connect_async_tls_with_config, Connector, MaybeTlsStream,
+    WebSocketStream,
+};
+
+use crate::app::app::App;
+use crate::app::app_logic::request::jwt::generate_jwt;
+use crate::app::app_logic::request::oauth2::apply_oauth2_token;
+use crate::app::app_logic::request::send::PrepareRequestError;
+use crate::panic_error;
+use crate::request::auth::Auth;
+use crate::request::request::Request;
+use crate::request::response::{RequestResponse, ResponseContent};
+use crate::request::settings::RequestSettings;
+use crate::request::websocket::{
+    WebSocketDirection, WebSocketMessage, WebSocketSession, WebSocketStatus,
+};
+
+impl App<'_> {
+    /// Send the message input as a text frame, the environment keys are replaced
+    pub fn send_websocket_message(&mut self) {
+        let text = self.replace_env_keys_by_value(&self.websocket_message_text_input.text);
+
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        let sender = match &selected_request.websocket {
+            Some(session) if session.is_open() => session.sender.as_ref(),
+            _ => None,
+        };
+
+        match sender {
+            None => {
+                drop(selected_request);
+                self.select_request_state();
+            }
+            Some(sender) => {
+                if !text.is_empty() && sender.send(Message::Text(text)).is_ok() {
+                    self.websocket_message_text_input.reset_input();
+                }
+            }
+        }
+    }
+
+    /// Config proxy matching the URL scheme, as with the HTTP client
+    pub fn get_websocket_proxy(&self, settings: &RequestSettings, url: &str) -> Option<Url> {
+        if !settings.use_config_proxy {
+            return None;
+        }
+
+        let proxy = self.config.proxy.as_ref()?;
+
+        let proxy = match url.trim_start().to_lowercase().starts_with("wss://") {
+            true => proxy.https_proxy.as_ref()?,
+            false => proxy.http_proxy.as_ref()?,
+        };
+
+        match Url::parse(proxy) {
+            Ok(proxy) => Some(proxy),
+            Err(e) => panic_error(format!("Could not parse proxy\n\t{e}")),
+        }
+    }
+}
+
+/// Perform the handshake of a prepared request, then exchange messages until the connection is
+/// closed. The request status, messages and handshake response are written in the request
+pub async fn open_websocket_session(
+    mut request: RequestBuilder,
+    send_time_auth: Option<Auth>,
+    settings: RequestSettings,
+    connector: Connector,
+    proxy: Option<Url>,
+    local_selected_request: Arc<RwLock<Request>>,
+) {
+    /* AUTH */
+
+    match send_time_auth {
+        None => {}
+        Some(Auth::OAuth2(oauth2)) => match apply_oauth2_token(request, &oauth2).await {
+            Ok(authorized_request) => request = authorized_request,
+            Err(error) => {
+                return set_websocket_error(
+                    &local_selected_request,
+                    PrepareRequestError::OAuth2,
+                    error,
+                )
+            }
+        },
+        Some(Auth::Jwt(jwt)) => match generate_jwt(&jwt) {
+            Ok(token) => request = request.bearer_auth(token),
+            Err(error) => {
+                return set_websocket_error(
+                    &local_selected_request,
+                    PrepareRequestError::Jwt,
+                    error,
+                )
+            }
+        },
+        // They sign or answer a challenge through the HTTP client
+        Some(_) => {
+            return set_websocket_error(
+                &local_selected_request,
+                PrepareRequestError::WebSocketAuth,
+                String::from("Digest, AWS signature and OAuth1 are only available for HTTP"),
+            )
+        }
+    }
+
+    /* HANDSHAKE REQUEST */
+
+    let request = match request.build() {
+        Ok(request) => request,
+        Err(error) => {
+            return set_websocket_error(
+                &local_selected_request,
+                PrepareRequestError::InvalidUrl,
+                error.to_string(),
+            )
+        }
+    };
+
+    let mut websocket_request = match request.url().as_str().into_client_request() {
+        Ok(websocket_request) => websocket_request,
+        Err(error) => {
+            return set_websocket_error(
+                &local_selected_request,
+                PrepareRequestError::InvalidUrl,
+                error.to_string(),
+            )
+        }
+    };
+
+    // The handshake headers, e.g. connection or sec-websocket-key, cannot be overridden
+    for (header_name, header_value) in request.headers() {
+        if !websocket_request.headers().contains_key(header_name) {
+            websocket_request
+                .headers_mut()
+                .insert(header_name, header_value.clone());
+        }
+    }
+
+    /* CONNECTION */
+
+    let request_start = Instant::now();
+
+    let connection = connect(websocket_request, connector, proxy);
+
+    let connection = match settings.connect_timeout_ms {
+        0 => connection.await,
+        connect_timeout_ms => {
+            let connect_timeout = Duration::from_millis(connect_timeout_ms as u64);
+
+            match timeout(connect_timeout, connection).await {
+                Ok(connection) => connection,
+                Err(_) => Err(Error::Io(std::io::ErrorKind::TimedOut.into())),
+            }
+        }
+    };
+
+    let (stream, handshake_response) = match connection {
+        Ok(connection) => connection,
+        Err(Error::Http(response)) => {
+            let body = response
+                .body()
+                .as_ref()
+                .map(|body| String::from_utf8_lossy(body).to_string());
+
+            let mut selected_request = local_selected_request.write();
+
+            selected_request.response = RequestResponse {
+                duration: Some(format!("{:?}", request_start.elapsed())),
+                status_code: Some(response.status().to_string()),
+                attempts: 1,
+                http_version: Some(format!("{:?}", response.version())),
+                content: body.map(ResponseContent::Body),
+                cookies: None,
+                headers: headers_to_vec(response.headers()),
+            };
+            selected_request.is_pending = false;
+            selected_request.abort_handle = None;
+            return;
+        }
+        Err(error) => {
+            let mut selected_request = local_selected_request.write();
+
+            selected_request.response = RequestResponse {
+                duration: Some(format!("{:?}", request_start.elapsed())),
+                content: Some(ResponseContent::Body(error.to_string())),
+                ..Default::default()
+            };
+            selected_request.is_pending = false;
+            selected_request.abort_handle = None;
+            return;
+        }
+    };
+
+    let (sender, mut receiver) = unbounded_channel::<Message>();
+
+    {
+        let mut selected_request = local_selected_request.write();
+
+        selected_request.response = RequestResponse {
+            duration: Some(format!("{:?}", request_start.elapsed())),
+            status_code: Some(handshake_response.status().to_string()),
+            attempts: 1,
+            http_version: Some(format!("{:?}", handshake_response.version())),
+            content: None,
+            cookies: None,
+            headers: headers_to_vec(handshake_response.headers()),
+        };
+        selected_request.websocket = Some(WebSocketSession {
+            status: WebSocketStatus::Open,
+            messages: vec![],
+            sender: Some(sender),
+        });
+        selected_request.is_pending = false;
+    }
+
+    /* MESSAGES */
+
+    let connection_start = Instant::now();
+    let (mut write, mut read) = stream.split();
+
+    // Becomes false once the close frame has been written
+    let mut is_writable = true;
+
+    let final_status = loop {
+        tokio::select! {
+            outgoing = receiver.recv(), if is_writable => match outgoing {
+                None => is_writable = false,
+                Some(message) => {
+                    is_writable = !message.is_close();
+
+                    let content = match &message {
+                        Message::Text(text) => Some(text.clone()),
+                        _ => None,
+                    };
+
+                    if let Err(error) = write.send(message).await {
+                        break WebSocketStatus::Error(error.to_string());
+                    }
+
+                    if let Some(content) = content {
+                        log_message(
+                            &local_selected_request,
+                            WebSocketDirection::Sent,
+                            connection_start,
+                            content,
+                        );
+                    }
+                }
+            },
+            incoming = read.next() => match incoming {
+                None => break WebSocketStatus::Closed {
+                    code: None,
+                    reason: String::from("connection dropped"),
+                },
+                Some(Err(Error::ConnectionClosed)) => break WebSocketStatus::Closed {
+                    code: None,
+                    reason: String::new(),
+                },
+                Some(Err(error)) => break WebSocketStatus::Error(error.to_string()),
+                Some(Ok(Message::Close(close_frame))) => {
+                    // The close frame answer is written by the stream, only the reading is left
+                    is_writable = false;
+
+                    let status = match close_frame {
+                        None => WebSocketStatus::Closed {
+                            code: None,
+                            reason: String::new(),
+                        },
+                        Some(close_frame) => WebSocketStatus::Closed {
+                            code: Some(u16::from(close_frame.code)),
+                            reason: close_frame.reason.to_string(),
+                        },
+                    };
+
+                    if let Some(session) = &mut local_selected_request.write().websocket {
+                        session.status = status;
+                        session.sender = None;
+                    }
+                }
+                Some(Ok(Message::Text(text))) => {
+                    let is_json = serde_json::from_str::<serde_json::Value>(&text).is_ok();
+
+                    let content = match settings.pretty_print_response_content && is_json {
+                        true => jsonxf::pretty_print(&text).unwrap_or(text),
+                        false => text,
+                    };
+
+                    log_message(
+                        &local_selected_request,
+                        WebSocketDirection::Received,
+                        connection_start,
+                        content,
+                    );
+                }
+                Some(Ok(Message::Binary(data))) => log_message(
+                    &local_selected_request,
+                    WebSocketDirection::Received,
+                    connection_start,
+                    format!("<binary, {} bytes>", data.len()),
+                ),
+                // Pings are answered by the stream
+                Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => {}
+            },
+        }
+    };
+
+    let mut selected_request = local_selected_request.write();
+
+    if let Some(session) = &mut selected_request.websocket {
+        // Keep the code received in the close frame
+        if !matches!(session.status, WebSocketStatus::Closed { .. }) {
+            session.status = final_status;
+        }
+
+        session.sender = None;
+    }
+
+    selected_request.abort_handle = None;
+}
+
+async fn connect(
+    websocket_request: WebSocketRequest,
+    connector: Connector,
+    proxy: Option<Url>,
+) -> Result<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response), Error> {
+    let proxy = match proxy {
+        None => {
+            return connect_async_tls_with_config(websocket_request, None, false, Some(connector))
+                .await
+        }
+        Some(proxy) => proxy,
+    };
+
+    let uri = websocket_request.uri();
+    let host = uri.host().unwrap_or_default().to_string();
+    let port = uri
+        .port_u16()
+        .unwrap_or(match uri.scheme_str() == Some("wss") {
+            true => 443,
+            false => 80,
+        });
+
+    let stream = connect_through_proxy(&proxy, &host, port).await?;
+
+    client_async_tls_with_config(websocket_request, stream, None, Some(connector)).await
+}
+
+/// Open a tunnel to the WebSocket server through an HTTP proxy (CONNECT method)
+async fn connect_through_proxy(proxy: &Url, host: &str, port: u16) -> Result<TcpStream, Error> {
+    let proxy_error = |message: String| Error::Io(std::io::Error::other(message));
+
+    if proxy.scheme() != "http" {
+        return Err(proxy_error(format!(
+            "Unsupported proxy scheme \"{}\", only HTTP proxies can tunnel WebSocket connections",
+            proxy.scheme()
+        )));
+    }
+
+    let proxy_host = proxy.host_str().unwrap_or_default();
+    let proxy_port = proxy.port_or_known_default().unwrap_or(80);
+
+    let mut stream = TcpStream::connect((proxy_host, proxy_port)).await?;
+
+    let mut connect_request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+
+    if !proxy.username().is_empty() {
+        let username = percent_decode_str(proxy.username()).decode_utf8_lossy();
+        let password = percent_decode_str(proxy.password().unwrap_or_default()).decode_utf8_lossy();
+        let credentials = STANDARD.encode(format!("{username}:{password}"));
+
+        connect_request += &format!("Proxy-Authorization: Basic {credentials}\r\n");
+    }
+
+    connect_request += "\r\n";
+
+    stream.write_all(connect_request.as_bytes()).await?;
+
+    // Read the proxy response headers byte by byte, the tunnel starts right after them
+    let mut response = Vec::new();
+
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() > 8192 {
+            return Err(proxy_error(String::from("Proxy response too long")));
+        }
+
+        response.push(stream.read_u8().await?);
+    }
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+
+    // e.g. HTTP/1.1 200 Connection established
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(stream),
+        _ => Err(proxy_error(format!(
+            "Proxy refused the tunnel: {status_line}"
+        ))),
+    }
+}
+
+/// Write a close frame, the connection is closed once the server answers with its own
+pub fn close_websocket_session(session: &mut WebSocketSession) {
+    if let Some(sender) = session.sender.take() {
+        let close_frame = CloseFrame {
+            code: CloseCode::Normal,
+            reason: Default::default(),
+        };
+
+        let _ = sender.send(Message::Close(Some(close_frame)));
+    }
+
+    session.status = WebSocketStatus::Closing;
+}
+
+fn log_message(
+    local_selected_request: &Arc<RwLock<Request>>,
+    direction: WebSocketDirection,
+    connection_start: Instant,
+    content: String,
+) {
+    if let Some(session) = &mut local_selected_request.write().websocket {
+        session.messages.push(WebSocketMessage {
+            direction,
+            time: format!("+{:.3}s", connection_start.elapsed().as_secs_f64()),
+            content,
+        });
+    }
+}
+
+fn set_websocket_error(
+    local_selected_request: &Arc<RwLock<Request>>,
+    prepare_request_error: PrepareRequestError,
+    error: String,
+) {
+    let mut selected_request = local_selected_request.write();
+
+    selected_request.response = RequestResponse {
+        status_code: Some(prepare_request_error.to_string()),
+        content: Some(ResponseContent::Body(error)),
+        ..Default::default()
+    };
+    selected_request.is_pending = false;
+    selected_request.abort_handle = None;
+}
+
+fn headers_to_vec(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(header_name, header_value)| {
+            let value = header_value.to_str().unwrap_or("").to_string();
+
+            (header_name.to_string(), value)
+        })
+        .collect()
+}
@@ -40,18 +40,27 @@ pub enum AppState {
     #[strum(to_string = "Creating new request")]
     CreatingNewRequest,
 
+    #[strum(to_string = "Creating new folder")]
+    CreatingNewFolder,
+
     #[strum(to_string = "Deleting collection")]
     DeletingCollection,
 
     #[strum(to_string = "Deleting request")]
     DeletingRequest,
 
+    #[strum(to_string = "Deleting folder")]
+    DeletingFolder,
+
     #[strum(to_string = "Renaming collection")]
     RenamingCollection,
 
     #[strum(to_string = "Renaming request")]
     RenamingRequest,
 
+    #[strum(to_string = "Renaming folder")]
+    RenamingFolder,
+
     /* Request */
     #[strum(to_string = "Request menu")]
     SelectedRequest,
@@ -59,6 +68,9 @@ pub enum AppState {
     #[strum(to_string = "Editing request URL")]
     EditingRequestUrl,
 
+    #[strum(to_string = "Editing request method")]
+    EditingRequestMethod,
+
     #[strum(to_string = "Editing request param")]
     EditingRequestParam,
 
@@ -71,6 +83,9 @@ pub enum AppState {
     #[strum(to_string = "Editing request auth bearer token")]
     EditingRequestAuthBearerToken,
 
+    #[strum(to_string = "Editing request auth field")]
+    EditingRequestAuthField,
+
     #[strum(to_string = "Editing request header")]
     EditingRequestHeader,
 
@@ -91,6 +106,15 @@ pub enum AppState {
 
     #[strum(to_string = "Editing request settings")]
     EditingRequestSettings,
+
+    #[strum(to_string = "Displaying code snippet")]
+    DisplayingCodeSnippet,
+
+    #[strum(to_string = "Displaying GraphQL schema")]
+    DisplayingGraphQLSchema,
+
+    #[strum(to_string = "Editing WebSocket message")]
+    EditingWebSocketMessage,
 }
 
 pub fn next_app_state(app_state: &AppState) -> AppState {
@@ -100,52 +124,68 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
         EditingCookies => ChoosingElementToCreate,
         ChoosingElementToCreate => CreatingNewCollection,
         CreatingNewCollection => CreatingNewRequest,
-        CreatingNewRequest => DeletingCollection,
+        CreatingNewRequest => CreatingNewFolder,
+        CreatingNewFolder => DeletingCollection,
         DeletingCollection => DeletingRequest,
-        DeletingRequest => RenamingCollection,
+        DeletingRequest => DeletingFolder,
+        DeletingFolder => RenamingCollection,
         RenamingCollection => RenamingRequest,
-        RenamingRequest => SelectedRequest,
+        RenamingRequest => RenamingFolder,
+        RenamingFolder => SelectedRequest,
         SelectedRequest => EditingRequestUrl,
-        EditingRequestUrl => EditingRequestParam,
+        EditingRequestUrl => EditingRequestMethod,
+        EditingRequestMethod => EditingRequestParam,
         EditingRequestParam => EditingRequestAuthUsername,
         EditingRequestAuthUsername => EditingRequestAuthPassword,
         EditingRequestAuthPassword => EditingRequestAuthBearerToken,
-        EditingRequestAuthBearerToken => EditingRequestHeader,
+        EditingRequestAuthBearerToken => EditingRequestAuthField,
+        EditingRequestAuthField => EditingRequestHeader,
         EditingRequestHeader => EditingRequestBodyTable,
         EditingRequestBodyTable => EditingRequestBodyFile,
         EditingRequestBodyFile => EditingRequestBodyString,
         EditingRequestBodyString => EditingPreRequestScript,
         EditingPreRequestScript => EditingPostRequestScript,
         EditingPostRequestScript => EditingRequestSettings,
-        EditingRequestSettings => Normal,
+        EditingRequestSettings => DisplayingCodeSnippet,
+        DisplayingCodeSnippet => DisplayingGraphQLSchema,
+        DisplayingGraphQLSchema => EditingWebSocketMessage,
+        EditingWebSocketMessage => Normal,
     }
 }
 
 pub fn previous_app_state(app_state: &AppState) -> AppState {
     match app_state {
-        Normal => EditingRequestSettings,
+        Normal => EditingWebSocketMessage,
         DisplayingCookies => Normal,
         EditingCookies => DisplayingCookies,
         ChoosingElementToCreate => EditingCookies,
         CreatingNewCollection => ChoosingElementToCreate,
         CreatingNewRequest => CreatingNewCollection,
-        DeletingCollection => CreatingNewRequest,
+        CreatingNewFolder => CreatingNewRequest,
+        DeletingCollection => CreatingNewFolder,
         DeletingRequest => DeletingCollection,
-        RenamingCollection => DeletingRequest,
+        DeletingFolder => DeletingRequest,
+        RenamingCollection => DeletingFolder,
         RenamingRequest => RenamingCollection,
-        SelectedRequest => RenamingRequest,
+        RenamingFolder => RenamingRequest,
+        SelectedRequest => RenamingFolder,
         EditingRequestUrl => SelectedRequest,
-        EditingRequestParam => EditingRequestUrl,
+        EditingRequestMethod => EditingRequestUrl,
+        EditingRequestParam => EditingRequestMethod,
         EditingRequestAuthUsername => EditingRequestParam,
         EditingRequestAuthPassword => EditingRequestAuthUsername,
         EditingRequestAuthBearerToken => EditingRequestAuthPassword,
-        EditingRequestHeader => EditingRequestAuthBearerToken,
+        EditingRequestAuthField => EditingRequestAuthBearerToken,
+        EditingRequestHeader => EditingRequestAuthField,
         EditingRequestBodyTable => EditingRequestHeader,
         EditingRequestBodyFile => EditingRequestBodyTable,
         EditingRequestBodyString => EditingRequestBodyFile,
         EditingPreRequestScript => EditingRequestBodyString,
         EditingPostRequestScript => EditingPreRequestScript,
         EditingRequestSettings => EditingPostRequestScript,
+        DisplayingCodeSnippet => EditingRequestSettings,
+        DisplayingGraphQLSchema => DisplayingCodeSnippet,
+        EditingWebSocketMessage => DisplayingGraphQLSchema,
     }
 }
 
@@ -211,12 +251,22 @@ impl AppState {
                 )),
                 MoveRequestUp(EventKeyBinding::new(
                     vec![key_bindings.main_menu.move_request_up],
-                    "Move request up",
+                    "Move element up",
                     None,
                 )),
                 MoveRequestDown(EventKeyBinding::new(
                     vec![key_bindings.main_menu.move_request_down],
-                    "Move request down",
+                    "Move element down",
+                    None,
+                )),
+                MoveElementIntoFolder(EventKeyBinding::new(
+                    vec![key_bindings.main_menu.move_element_into_folder],
+                    "Move element into the folder above",
+                    None,
+                )),
+                MoveElementOutOfFolder(EventKeyBinding::new(
+                    vec![key_bindings.main_menu.move_element_out_of_folder],
+                    "Move element out of its folder",
                     None,
                 )),
                 NextEnvironment(EventKeyBinding::new(
@@ -377,6 +427,45 @@ impl AppState {
                 )),
                 CreatingRequestCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
+            CreatingNewFolder => vec![
+                GoBackToMainMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.cancel],
+                    "Cancel",
+                    Some("Cancel"),
+                )),
+                CreateNewFolder(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.confirm],
+                    "Confirm",
+                    Some("Confirm"),
+                )),
+                CreatingFolderDeleteCharBackward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_backward],
+                    "Delete char backward",
+                    Some("Delete"),
+                )),
+                CreatingFolderDeleteCharForward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_forward],
+                    "Delete char forward",
+                    Some("Backspace"),
+                )),
+                CreatingFolderMoveCursorLeft(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.move_cursor_left],
+                    "Move cursor left",
+                    Some("Left"),
+                )),
+                CreatingFolderMoveCursorRight(EventKeyBinding::new(
+                    vec![
+                        key_bindings
+                            .generic
+                            .text_inputs
+                            .text_input
+                            .move_cursor_right,
+                    ],
+                    "Move cursor right",
+                    Some("Right"),
+                )),
+                CreatingFolderCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             DeletingCollection => vec![
                 GoBackToMainMenu(EventKeyBinding::new(
                     vec![key_bindings.generic.navigation.go_back],
@@ -421,6 +510,28 @@ impl AppState {
                     Some("Select"),
                 )),
             ],
+            DeletingFolder => vec![
+                GoBackToMainMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.go_back],
+                    "Cancel",
+                    Some("Cancel"),
+                )),
+                DeletingFolderMoveCursorLeft(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_left],
+                    "Move selection left",
+                    Some("Left"),
+                )),
+                DeletingFolderMoveCursorRight(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_right],
+                    "Move selection right",
+                    Some("Right"),
+                )),
+                DeleteFolder(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.select],
+                    "Select choice",
+                    Some("Select"),
+                )),
+            ],
             RenamingCollection => vec![
                 GoBackToMainMenu(EventKeyBinding::new(
                     vec![key_bindings.generic.text_inputs.text_input.cancel],
@@ -499,6 +610,45 @@ impl AppState {
                 )),
                 RenamingRequestCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
+            RenamingFolder => vec![
+                GoBackToMainMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.cancel],
+                    "Cancel",
+                    Some("Cancel"),
+                )),
+                RenameFolder(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.confirm],
+                    "Confirm",
+                    Some("Confirm"),
+                )),
+                RenamingFolderDeleteCharBackward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_backward],
+                    "Delete char backward",
+                    Some("Delete"),
+                )),
+                RenamingFolderDeleteCharForward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_forward],
+                    "Delete char forward",
+                    Some("Backspace"),
+                )),
+                RenamingFolderMoveCursorLeft(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.move_cursor_left],
+                    "Move cursor left",
+                    Some("Left"),
+                )),
+                RenamingFolderMoveCursorRight(EventKeyBinding::new(
+                    vec![
+                        key_bindings
+                            .generic
+                            .text_inputs
+                            .text_input
+                            .move_cursor_right,
+                    ],
+                    "Move cursor right",
+                    Some("Right"),
+                )),
+                RenamingFolderCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             SelectedRequest => {
                 // Depending on the current request view, some keys may need to be deactivated
                 let (params_events_allowed, result_events_allowed) = match request_view {
@@ -528,6 +678,11 @@ impl AppState {
                         "Change method",
                         Some("Method"),
                     )),
+                    EditCustomMethod(EventKeyBinding::new(
+                        vec![key_bindings.request_selected.custom_method],
+                        "Custom method",
+                        None,
+                    )),
                     EditSettings(EventKeyBinding::new(
                         vec![key_bindings.request_selected.request_settings],
                         "Request settings",
@@ -546,6 +701,21 @@ impl AppState {
                         "Send request",
                         Some("Send"),
                     )),
+                    CancelRequest(EventKeyBinding::new(
+                        vec![key_bindings.request_selected.cancel_request],
+                        "Cancel request",
+                        None,
+                    )),
+                    CopyRequestAsCurl(EventKeyBinding::new(
+                        vec![key_bindings.request_selected.copy_as_curl],
+                        "Copy as cURL",
+                        None,
+                    )),
+                    GenerateCodeSnippet(EventKeyBinding::new(
+                        vec![key_bindings.request_selected.generate_code_snippet],
+                        "Generate code snippet",
+                        None,
+                    )),
                     NextEnvironment(EventKeyBinding::new(
                         vec![key_bindings.main_menu.next_environment],
                         "Next environment",
@@ -729,6 +899,26 @@ impl AppState {
                                 "Toggle form element",
                                 None,
                             )),
+                            FetchGraphQLSchema(EventKeyBinding::new(
+                                vec![
+                                    key_bindings
+                                        .request_selected
+                                        .param_tabs
+                                        .fetch_graphql_schema,
+                                ],
+                                "Fetch GraphQL schema",
+                                None,
+                            )),
+                            DisplayGraphQLSchema(EventKeyBinding::new(
+                                vec![
+                                    key_bindings
+                                        .request_selected
+                                        .param_tabs
+                                        .display_graphql_schema,
+                                ],
+                                "Display GraphQL schema",
+                                None,
+                            )),
                         ],
                         RequestParamsTabs::Scripts => vec![
                             EditRequestScript(EventKeyBinding::new(
@@ -840,6 +1030,45 @@ impl AppState {
                 )),
                 EditingRequestUrlCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
+            EditingRequestMethod => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.cancel],
+                    "Cancel",
+                    Some("Cancel"),
+                )),
+                ModifyRequestMethod(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.confirm],
+                    "Confirm",
+                    Some("Confirm"),
+                )),
+                EditingRequestMethodDeleteCharBackward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_backward],
+                    "Delete char backward",
+                    Some("Delete"),
+                )),
+                EditingRequestMethodDeleteCharForward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_forward],
+                    "Delete char forward",
+                    Some("Backspace"),
+                )),
+                EditingRequestMethodMoveCursorLeft(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.move_cursor_left],
+                    "Move cursor left",
+                    Some("Left"),
+                )),
+                EditingRequestMethodMoveCursorRight(EventKeyBinding::new(
+                    vec![
+                        key_bindings
+                            .generic
+                            .text_inputs
+                            .text_input
+                            .move_cursor_right,
+                    ],
+                    "Move cursor right",
+                    Some("Right"),
+                )),
+                EditingRequestMethodCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             EditingRequestParam => vec![
                 GoBackToRequestMenu(EventKeyBinding::new(
                     vec![key_bindings.generic.text_inputs.text_input.cancel],
@@ -1008,6 +1237,45 @@ impl AppState {
                     None,
                 )),
             ],
+            EditingRequestAuthField => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.cancel],
+                    "Cancel",
+                    Some("Cancel"),
+                )),
+                ModifyRequestAuthField(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.confirm],
+                    "Confirm",
+                    Some("Confirm"),
+                )),
+                EditingRequestAuthFieldDeleteCharBackward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_backward],
+                    "Delete char backward",
+                    Some("Delete"),
+                )),
+                EditingRequestAuthFieldDeleteCharForward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_forward],
+                    "Delete char forward",
+                    Some("Backspace"),
+                )),
+                EditingRequestAuthFieldMoveCursorLeft(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.move_cursor_left],
+                    "Move cursor left",
+                    Some("Left"),
+                )),
+                EditingRequestAuthFieldMoveCursorRight(EventKeyBinding::new(
+                    vec![
+                        key_bindings
+                            .generic
+                            .text_inputs
+                            .text_input
+                            .move_cursor_right,
+                    ],
+                    "Move cursor right",
+                    Some("Right"),
+                )),
+                EditingRequestAuthFieldCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             EditingRequestHeader => vec![
                 GoBackToRequestMenu(EventKeyBinding::new(
                     vec![key_bindings.generic.text_inputs.text_input.cancel],
@@ -1127,6 +1395,11 @@ impl AppState {
             ],
             EditingRequestBodyString => match key_bindings.generic.text_inputs.text_area_mode {
                 TextAreaMode::VimEmulation => vec![
+                    EditingRequestBodyStringAutocomplete(EventKeyBinding::new(
+                        vec![key_bindings.generic.text_inputs.autocomplete],
+                        "Autocomplete GraphQL query",
+                        None,
+                    )),
                     EditingRequestBodyStringVimInput(EventKeyBinding::new(
                         vec![],
                         "Vim input",
@@ -1189,6 +1462,11 @@ impl AppState {
                         "Indent",
                         None,
                     )),
+                    EditingRequestBodyStringAutocomplete(EventKeyBinding::new(
+                        vec![key_bindings.generic.text_inputs.autocomplete],
+                        "Autocomplete GraphQL query",
+                        None,
+                    )),
                     EditingRequestBodyStringDeleteCharBackward(EventKeyBinding::new(
                         vec![text_area_key_bindings.delete_backward],
                         "Delete char backward",
@@ -1474,12 +1752,14 @@ impl AppState {
                     "Move down",
                     Some("Down"),
                 )),
-                RequestSettingsToggleSetting(EventKeyBinding::new(
-                    vec![
-                        key_bindings.generic.navigation.move_cursor_left,
-                        key_bindings.generic.navigation.move_cursor_right,
-                    ],
-                    "Toggle setting",
+                RequestSettingsPreviousValue(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_left],
+                    "Toggle setting or decrease value",
+                    None,
+                )),
+                RequestSettingsNextValue(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_right],
+                    "Toggle setting or increase value",
                     Some("Toggle"),
                 )),
                 ModifyRequestSettings(EventKeyBinding::new(
@@ -1488,6 +1768,104 @@ impl AppState {
                     Some("Confirm"),
                 )),
             ],
+            DisplayingCodeSnippet => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.go_back],
+                    "Quit",
+                    Some("Quit"),
+                )),
+                CodeSnippetPreviousLanguage(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_left],
+                    "Previous language",
+                    Some("Previous"),
+                )),
+                CodeSnippetNextLanguage(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_right],
+                    "Next language",
+                    Some("Next"),
+                )),
+                CodeSnippetScrollUp(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_up],
+                    "Scroll up",
+                    Some("Up"),
+                )),
+                CodeSnippetScrollDown(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_down],
+                    "Scroll down",
+                    Some("Down"),
+                )),
+                CopyCodeSnippet(EventKeyBinding::new(
+                    vec![key_bindings.request_selected.result_tabs.yank_response_part],
+                    "Copy snippet",
+                    Some("Copy"),
+                )),
+            ],
+            EditingWebSocketMessage => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.cancel],
+                    "Quit",
+                    Some("Quit"),
+                )),
+                SendWebSocketMessage(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.confirm],
+                    "Send message",
+                    Some("Send"),
+                )),
+                EditingWebSocketMessageDeleteCharBackward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_backward],
+                    "Delete char backward",
+                    Some("Delete"),
+                )),
+                EditingWebSocketMessageDeleteCharForward(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.delete_forward],
+                    "Delete char forward",
+                    Some("Backspace"),
+                )),
+                EditingWebSocketMessageMoveCursorLeft(EventKeyBinding::new(
+                    vec![key_bindings.generic.text_inputs.text_input.move_cursor_left],
+                    "Move cursor left",
+                    Some("Left"),
+                )),
+                EditingWebSocketMessageMoveCursorRight(EventKeyBinding::new(
+                    vec![
+                        key_bindings
+                            .generic
+                            .text_inputs
+                            .text_input
+                            .move_cursor_right,
+                    ],
+                    "Move cursor right",
+                    Some("Right"),
+                )),
+                EditingWebSocketMessageCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            DisplayingGraphQLSchema => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.go_back],
+                    "Quit",
+                    Some("Quit"),
+                )),
+                GraphQLSchemaPreviousType(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_up],
+                    "Previous type",
+                    Some("Up"),
+                )),
+                GraphQLSchemaNextType(EventKeyBinding::new(
+                    vec![key_bindings.generic.navigation.move_cursor_down],
+                    "Next type",
+                    Some("Down"),
+                )),
+                GraphQLSchemaScrollUp(EventKeyBinding::new(
+                    vec![key_bindings.request_selected.result_tabs.scroll_up],
+                    "Scroll fields up",
+                    Some("Scroll up"),
+                )),
+                GraphQLSchemaScrollDown(EventKeyBinding::new(
+                    vec![key_bindings.request_selected.result_tabs.scroll_down],
+                    "Scroll fields down",
+                    Some("Scroll down"),
+                )),
+            ],
         }
     }
 }
@@ -1535,6 +1913,7 @@ impl App<'_> {
             | ChoosingElementToCreate
             | CreatingNewCollection
             | CreatingNewRequest
+            | CreatingNewFolder
             | DisplayingCookies
             | EditingCookies => Line::from(self.state.to_string().white().on_dark_gray()),
 
@@ -1550,10 +1929,10 @@ impl App<'_> {
             }
 
             DeletingRequest | RenamingRequest => {
-                let selected_request_index = &self.collections_tree.state.selected();
-                let selected_request = &self.collections[selected_request_index[0]].requests
-                    [selected_request_index[1]]
-                    .read();
+                let selected_request_index = self.collections_tree.state.selected();
+                let local_selected_request =
+                    self.get_request_as_local_from_indexes(selected_request_index);
+                let selected_request = local_selected_request.read();
 
                 Line::from(vec![
                     Span::raw("Request > ").dark_gray(),
@@ -1562,19 +1941,37 @@ impl App<'_> {
                 ])
             }
 
+            DeletingFolder | RenamingFolder => {
+                let selected_folder_index = self.collections_tree.state.selected();
+                let selected_folder = self.collections[selected_folder_index[0]]
+                    .get_folder(&selected_folder_index[1..])
+                    .unwrap();
+
+                Line::from(vec![
+                    Span::raw("Folder > ").dark_gray(),
+                    Span::raw(format!("{} > ", selected_folder.name)).dark_gray(),
+                    Span::raw(self.state.to_string()).white().on_dark_gray(),
+                ])
+            }
+
             SelectedRequest
             | EditingRequestUrl
+            | EditingRequestMethod
             | EditingRequestParam
             | EditingRequestAuthUsername
             | EditingRequestAuthPassword
             | EditingRequestAuthBearerToken
+            | EditingRequestAuthField
             | EditingRequestHeader
             | EditingRequestBodyTable
             | EditingRequestBodyFile
             | EditingRequestBodyString
             | EditingPreRequestScript
             | EditingPostRequestScript
-            | EditingRequestSettings => {
+            | EditingRequestSettings
+            | DisplayingCodeSnippet
+            | DisplayingGraphQLSchema
+            | EditingWebSocketMessage => {
                 let local_selected_request = self.get_selected_request_as_local();
                 let selected_request = local_selected_request.read();
 
@@ -0,0 +1,115 @@
+use crate::app::app::App;
+use crate::app::startup::args::ExportType;
+
+impl App<'_> {
+    /// Export a request or a collection to the asked format and print it.
+    /// Returns false if the export failed
+    pub async fn export_from_cli(&mut self, export_type: &ExportType) -> bool {
+        match export_type {
+            ExportType::Curl {
+                collection_name,
+                request_name,
+                env,
+            } => {
+                self.select_environment_from_name(env);
+
+                let collection_index = self.find_collection_index(collection_name);
+                let local_request = self.find_request_as_local(collection_name, request_name);
+                let request =
+                    self.apply_collection_to_request(collection_index, &local_request.read());
+
+                println!("{}", self.request_to_curl(&request, env.is_some()));
+
+                true
+            }
+            ExportType::Har {
+                collection_name,
+                request_name,
+                export_path,
+                env,
+                send,
+            } => {
+                self.select_environment_from_name(env);
+
+                let collection_index = self.find_collection_index(collection_name);
+                let local_request = self.find_request_as_local(collection_name, request_name);
+                let request = local_request.read().clone();
+
+                // Sending can change the server state, so it is only done when asked
+                let response = match send {
+                    false => request.response.clone(),
+                    true => match self.send_request_and_wait(collection_index, &request).await {
+                        Ok(sent_request) => sent_request.response,
+                        Err(error) => {
+                            eprintln!("{error}");
+                            return false;
+                        }
+                    },
+                };
+
+                let collection_request =
+                    self.apply_collection_to_request(collection_index, &request);
+
+                self.export_har_entry(&collection_request, &response, export_path);
+
+                println!(
+                    "Request \"{request_name}\" exported to \"{}\"",
+                    export_path.display()
+                );
+
+                !send || response.status_code.is_some()
+            }
+            ExportType::Postman {
+                collection_name,
+                export_path,
+            } => {
+                let collection_index = self.find_collection_index(collection_name);
+
+                self.export_postman_collection(collection_index, export_path);
+
+                println!(
+                    "Collection \"{collection_name}\" exported to \"{}\"",
+                    export_path.display()
+                );
+
+                true
+            }
+            ExportType::Bruno {
+                collection_name,
+                export_path,
+                env,
+            } => {
+                self.select_environment_from_name(env);
+
+                let collection_index = self.find_collection_index(collection_name);
+
+                self.export_bruno_collection(collection_index, export_path, env.is_some());
+
+                println!(
+                    "Collection \"{collection_name}\" exported to \"{}\"",
+                    export_path.display()
+                );
+
+                true
+            }
+            ExportType::Http {
+                collection_name,
+                export_path,
+                env,
+            } => {
+                self.select_environment_from_name(env);
+
+                let collection_index = self.find_collection_index(collection_name);
+
+                self.export_http_file(collection_index, export_path, env.is_some());
+
+                println!(
+                    "Collection \"{collection_name}\" exported to \"{}\"",
+                    export_path.display()
+                );
+
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use reqwest::RequestBuilder;
+
+use crate::app::app::App;
+use crate::app::app_logic::request::scripts::handle_post_request_script;
+use crate::app::app_logic::request::send::{send_prepared_request, PrepareRequestError};
+use crate::app::startup::args::{Command, ARGS};
+use crate::panic_error;
+use crate::request::request::Request;
+use crate::request::response::RequestResponse;
+use crate::request::websocket::is_websocket_url;
+
+mod export;
+mod run;
+mod send;
+
+/// Response of a request sent from the CLI
+struct SentRequest {
+    /// As sent, the post-request script could change the environment afterward
+    url: String,
+    response: RequestResponse,
+}
+
+impl App<'_> {
+    /// Execute the headless command given in the CLI arguments.
+    /// Returns false if the command failed and the app should exit with an error code
+    pub async fn handle_headless_command(&mut self) -> bool {
+        match &ARGS.command {
+            Some(Command::Send {
+                collection_name,
+                request_name,
+                env,
+            }) => {
+                self.select_environment_from_name(env);
+                self.send_request_from_cli(collection_name, request_name)
+                    .await
+            }
+            Some(Command::Run {
+                collection_name,
+                env,
+                junit,
+                json,
+            }) => {
+                self.select_environment_from_name(env);
+                self.run_collection_from_cli(collection_name, junit, json)
+                    .await
+            }
+            Some(Command::Export { export_type }) => self.export_from_cli(export_type).await,
+            _ => true,
+        }
+    }
+
+    /// Select the environment matching the given name, keeps the default selection if none is provided
+    fn select_environment_from_name(&mut self, env_name: &Option<String>) {
+        let env_name = match env_name {
+            None => return,
+            Some(env_name) => env_name,
+        };
+
+        let env_index = self
+            .environments
+            .iter()
+            .position(|environment| &environment.read().name == env_name);
+
+        match env_index {
+            None => panic_error(format!("Environment \"{env_name}\" not found")),
+            Some(env_index) => self.selected_environment = env_index,
+        }
+    }
+
+    fn find_collection_index(&self, collection_name: &str) -> usize {
+        match self
+            .collections
+            .iter()
+            .position(|collection| collection.name == collection_name)
+        {
+            None => panic_error(format!("Collection \"{collection_name}\" not found")),
+            Some(collection_index) => collection_index,
+        }
+    }
+
+    fn find_request_as_local(
+        &self,
+        collection_name: &str,
+        request_name: &str,
+    ) -> Arc<RwLock<Request>> {
+        let collection = &self.collections[self.find_collection_index(collection_name)];
+
+        let request = collection
+            .all_requests()
+            .into_iter()
+            .find(|request| request.read().name == request_name);
+
+        match request {
+            None => panic_error(format!(
+                "Request \"{request_name}\" not found in collection \"{collection_name}\""
+            )),
+            Some(request) => request,
+        }
+    }
+
+    /// Run the whole send pipeline (collection values and scripts included) and wait for the response.
+    /// Scripts console outputs are printed to stderr in order to keep stdout clean.
+    /// Returns the error to print if the request could not be sent
+    async fn send_request_and_wait(
+        &self,
+        collection_index: usize,
+        request: &Request,
+    ) -> Result<SentRequest, String> {
+        let collection_request = self.apply_collection_to_request(collection_index, request);
+
+        let (modified_request, console_output) =
+            self.handle_pre_request_script(&collection_request);
+
+        if let Some(console_output) = console_output {
+            eprint!("{console_output}");
+        }
+
+        let modified_request = match modified_request {
+            None => return Err(PrepareRequestError::PreRequestScript.to_string()),
+            Some(modified_request) => modified_request,
+        };
+
+        // A session needs the message input and log
+        if is_websocket_url(&self.replace_env_keys_by_value(&modified_request.url)) {
+            return Err(PrepareRequestError::WebSocketHeadless.to_string());
+        }
+
+        let prepared_request = self
+            .prepare_request(&modified_request)
+            .map_err(|prepare_request_error| prepare_request_error.to_string())?;
+        let send_time_auth = self.get_send_time_auth(&modified_request);
+
+        // Final URL, e.g. with the query params and the collection variables
+        let (client, prepared_request) = prepared_request.build_split();
+        let prepared_request = prepared_request.map_err(|error| error.to_string())?;
+        let url = prepared_request.url().to_string();
+        let prepared_request = RequestBuilder::from_parts(client, prepared_request);
+
+        let response = match send_prepared_request(
+            prepared_request,
+            send_time_auth,
+            &request.settings,
+        )
+        .await
+        {
+            Ok(response) => response,
+            Err((error, details)) => return Err(format!("{error}\n\t{details}")),
+        };
+
+        let local_env = self.get_selected_env_as_local();
+        let (modified_response, console_output) =
+            handle_post_request_script(request, response, &local_env);
+
+        if let Some(console_output) = console_output {
+            eprint!("{console_output}");
+        }
+
+        Ok(SentRequest {
+            url,
+            response: modified_response,
+        })
+    }
+}
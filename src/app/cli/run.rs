@@ -0,0 +1,246 @@
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::app::app::App;
+use crate::panic_error;
+use crate::request::response::{RequestResponse, ResponseContent};
+
+#[derive(Serialize)]
+struct RunReport {
+    collection: String,
+    environment: Option<String>,
+    passed: usize,
+    failed: usize,
+    /// In seconds
+    duration: f64,
+    results: Vec<RequestResult>,
+}
+
+#[derive(Serialize)]
+struct RequestResult {
+    name: String,
+    method: String,
+    url: String,
+    status_code: Option<String>,
+    /// In seconds
+    duration: f64,
+    error: Option<String>,
+}
+
+impl App<'_> {
+    /// Send every request of a collection in order, print a summary and write the asked reports.
+    /// Returns false if at least one request failed
+    pub async fn run_collection_from_cli(
+        &self,
+        collection_name: &str,
+        junit_path: &Option<PathBuf>,
+        json_path: &Option<PathBuf>,
+    ) -> bool {
+        let collection_index = self.find_collection_index(collection_name);
+        let collection = &self.collections[collection_index];
+
+        let run_start = Instant::now();
+        let mut results: Vec<RequestResult> = vec![];
+
+        for local_request in &collection.all_requests() {
+            // Cloning the request prevents from holding the lock while waiting for the response
+            let request = local_request.read().clone();
+
+            let request_start = Instant::now();
+            let response = self.send_request_and_wait(collection_index, &request).await;
+            let duration = request_start.elapsed().as_secs_f64();
+
+            let (url, status_code, error) = match &response {
+                Ok(sent_request) => (
+                    sent_request.url.clone(),
+                    sent_request.response.status_code.clone(),
+                    find_response_error(&sent_request.response),
+                ),
+                // Not sent, the URL is resolved as it would have been
+                Err(error) => {
+                    let collection_request =
+                        self.apply_collection_to_request(collection_index, &request);
+
+                    (
+                        self.replace_env_keys_by_value(&collection_request.url),
+                        None,
+                        Some(error.clone()),
+                    )
+                }
+            };
+
+            results.push(RequestResult {
+                name: request.name.clone(),
+                method: request.method.to_string(),
+                url,
+                status_code,
+                duration,
+                error,
+            });
+        }
+
+        let failed = results
+            .iter()
+            .filter(|result| result.error.is_some())
+            .count();
+
+        let report = RunReport {
+            collection: collection.name.clone(),
+            environment: self
+                .get_selected_env_as_local()
+                .map(|local_env| local_env.read().name.clone()),
+            passed: results.len() - failed,
+            failed,
+            duration: run_start.elapsed().as_secs_f64(),
+            results,
+        };
+
+        print_summary(&report);
+
+        if let Some(junit_path) = junit_path {
+            write_report(junit_path, report_to_junit(&report));
+        }
+
+        if let Some(json_path) = json_path {
+            let json_report = match serde_json::to_string_pretty(&report) {
+                Ok(json_report) => json_report,
+                Err(e) => panic_error(format!("Could not serialize JSON report\n\t{e}")),
+            };
+
+            write_report(json_path, json_report);
+        }
+
+        report.failed == 0
+    }
+}
+
+/// A request is considered as failed if no response was received, if its post-request script
+/// failed or if the server answered with a client or server error
+fn find_response_error(response: &RequestResponse) -> Option<String> {
+    let status_code = match &response.status_code {
+        None => {
+            return match &response.content {
+                Some(ResponseContent::Body(error)) => Some(error.clone()),
+                _ => Some(String::from("NO STATUS CODE")),
+            }
+        }
+        Some(status_code) => status_code,
+    };
+
+    let status_number = status_code
+        .split_whitespace()
+        .next()
+        .and_then(|status_number| status_number.parse::<u16>().ok());
+
+    match status_number {
+        None => Some(status_code.clone()),
+        Some(status_number) if status_number >= 400 => Some(status_code.clone()),
+        Some(_) => None,
+    }
+}
+
+fn print_summary(report: &RunReport) {
+    let name_width = report
+        .results
+        .iter()
+        .map(|result| result.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    println!(
+        "{:name_width$}  {:7}  {:30}  {:>10}  RESULT",
+        "NAME", "METHOD", "STATUS", "DURATION"
+    );
+
+    for result in &report.results {
+        let status_code = result.status_code.as_deref().unwrap_or("-");
+        let duration = format!("{:.0}ms", result.duration * 1000.0);
+
+        let outcome = match &result.error {
+            None => String::from("PASSED"),
+            Some(error) => format!("FAILED ({})", error.lines().next().unwrap_or_default()),
+        };
+
+        println!(
+            "{:name_width$}  {:7}  {:30}  {:>10}  {}",
+            result.name, result.method, status_code, duration, outcome
+        );
+    }
+
+    println!();
+    println!(
+        "{} passed, {} failed in {:.2}s",
+        report.passed, report.failed, report.duration
+    );
+}
+
+fn report_to_junit(report: &RunReport) -> String {
+    let collection_name = escape_xml(&report.collection);
+    let tests = report.results.len();
+
+    let mut junit = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    writeln!(
+        junit,
+        r#"<testsuites name="ATAC" tests="{tests}" failures="{}" time="{:.3}">"#,
+        report.failed, report.duration
+    )
+    .unwrap();
+    writeln!(
+        junit,
+        r#"  <testsuite name="{collection_name}" tests="{tests}" failures="{}" time="{:.3}">"#,
+        report.failed, report.duration
+    )
+    .unwrap();
+
+    for result in &report.results {
+        let test_case = format!(
+            r#"    <testcase name="{}" classname="{collection_name}" time="{:.3}""#,
+            escape_xml(&format!("{} {}", result.method, result.name)),
+            result.duration
+        );
+
+        match &result.error {
+            None => writeln!(junit, "{test_case}/>").unwrap(),
+            Some(error) => {
+                writeln!(junit, "{test_case}>").unwrap();
+                writeln!(
+                    junit,
+                    r#"      <failure message="{}">{}</failure>"#,
+                    escape_xml(error.lines().next().unwrap_or_default()),
+                    escape_xml(&format!("{}\n{error}", result.url))
+                )
+                .unwrap();
+                writeln!(junit, "    </testcase>").unwrap();
+            }
+        }
+    }
+
+    writeln!(junit, "  </testsuite>").unwrap();
+    writeln!(junit, "</testsuites>").unwrap();
+
+    junit
+}
+
+fn escape_xml(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn write_report(path: &PathBuf, content: String) {
+    if let Err(e) = fs::write(path, content) {
+        panic_error(format!(
+            "Could not write report \"{}\"\n\t{e}",
+            path.display()
+        ));
+    }
+}
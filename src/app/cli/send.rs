@@ -0,0 +1,59 @@
+use std::io::{stdout, Write};
+
+use crate::app::app::App;
+use crate::request::response::{RequestResponse, ResponseContent};
+
+impl App<'_> {
+    /// Send a request from a collection and print its response.
+    /// Returns false if the request could not be sent or no response was received
+    pub async fn send_request_from_cli(&self, collection_name: &str, request_name: &str) -> bool {
+        let collection_index = self.find_collection_index(collection_name);
+        let local_request = self.find_request_as_local(collection_name, request_name);
+        let request = local_request.read().clone();
+
+        let response = match self.send_request_and_wait(collection_index, &request).await {
+            Ok(sent_request) => sent_request.response,
+            Err(error) => {
+                eprintln!("{error}");
+                return false;
+            }
+        };
+
+        print_response(&response);
+
+        // None if no response was received, e.g. connection refused
+        response.status_code.is_some()
+    }
+}
+
+fn print_response(response: &RequestResponse) {
+    let mut stdout = stdout();
+
+    match &response.status_code {
+        None => println!("NO STATUS CODE"),
+        Some(status_code) => println!("{status_code}"),
+    }
+
+    // Printed to stderr in order to keep stdout clean
+    if response.attempts > 1 {
+        eprintln!("{} attempts", response.attempts);
+    }
+
+    for (header, value) in &response.headers {
+        println!("{header}: {value}");
+    }
+
+    println!();
+
+    match &response.content {
+        None => {}
+        Some(ResponseContent::Body(body)) => println!("{body}"),
+        Some(ResponseContent::Image(image_response)) => {
+            stdout
+                .write_all(&image_response.data)
+                .expect("Could not write image to stdout");
+        }
+    }
+
+    stdout.flush().expect("Could not flush stdout");
+}
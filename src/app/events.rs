@@ -29,6 +29,8 @@ pub enum AppEvent {
 
     MoveRequestUp(EventKeyBinding),
     MoveRequestDown(EventKeyBinding),
+    MoveElementIntoFolder(EventKeyBinding),
+    MoveElementOutOfFolder(EventKeyBinding),
 
     NextEnvironment(EventKeyBinding),
     DisplayCookies(EventKeyBinding),
@@ -63,6 +65,13 @@ pub enum AppEvent {
     CreatingRequestSelectCollectionDown(EventKeyBinding),
     CreatingRequestCharInput(EventKeyBinding),
 
+    CreateNewFolder(EventKeyBinding),
+    CreatingFolderDeleteCharBackward(EventKeyBinding),
+    CreatingFolderDeleteCharForward(EventKeyBinding),
+    CreatingFolderMoveCursorLeft(EventKeyBinding),
+    CreatingFolderMoveCursorRight(EventKeyBinding),
+    CreatingFolderCharInput(EventKeyBinding),
+
     DeletingCollectionMoveCursorLeft(EventKeyBinding),
     DeletingCollectionMoveCursorRight(EventKeyBinding),
     DeleteCollection(EventKeyBinding),
@@ -71,6 +80,10 @@ pub enum AppEvent {
     DeletingRequestMoveCursorRight(EventKeyBinding),
     DeleteRequest(EventKeyBinding),
 
+    DeletingFolderMoveCursorLeft(EventKeyBinding),
+    DeletingFolderMoveCursorRight(EventKeyBinding),
+    DeleteFolder(EventKeyBinding),
+
     RenameCollection(EventKeyBinding),
     RenamingCollectionDeleteCharBackward(EventKeyBinding),
     RenamingCollectionDeleteCharForward(EventKeyBinding),
@@ -85,17 +98,29 @@ pub enum AppEvent {
     RenamingRequestMoveCursorRight(EventKeyBinding),
     RenamingRequestCharInput(EventKeyBinding),
 
+    RenameFolder(EventKeyBinding),
+    RenamingFolderDeleteCharBackward(EventKeyBinding),
+    RenamingFolderDeleteCharForward(EventKeyBinding),
+    RenamingFolderMoveCursorLeft(EventKeyBinding),
+    RenamingFolderMoveCursorRight(EventKeyBinding),
+    RenamingFolderCharInput(EventKeyBinding),
+
     /* Request */
     GoBackToRequestMenu(EventKeyBinding),
 
     EditUrl(EventKeyBinding),
     EditMethod(EventKeyBinding),
+    EditCustomMethod(EventKeyBinding),
 
     EditSettings(EventKeyBinding),
 
     NextView(EventKeyBinding),
 
     SendRequest(EventKeyBinding),
+    CancelRequest(EventKeyBinding),
+
+    CopyRequestAsCurl(EventKeyBinding),
+    GenerateCodeSnippet(EventKeyBinding),
 
     /* Param tabs */
     NextParamTab(EventKeyBinding),
@@ -132,6 +157,8 @@ pub enum AppEvent {
     CreateRequestBodyTableElement(EventKeyBinding),
     DeleteRequestBodyTableElement(EventKeyBinding),
     ToggleRequestBodyTableElement(EventKeyBinding),
+    FetchGraphQLSchema(EventKeyBinding),
+    DisplayGraphQLSchema(EventKeyBinding),
 
     EditRequestScript(EventKeyBinding),
     // Move up or down
@@ -156,6 +183,13 @@ pub enum AppEvent {
     EditingRequestUrlMoveCursorRight(EventKeyBinding),
     EditingRequestUrlCharInput(EventKeyBinding),
 
+    ModifyRequestMethod(EventKeyBinding),
+    EditingRequestMethodDeleteCharBackward(EventKeyBinding),
+    EditingRequestMethodDeleteCharForward(EventKeyBinding),
+    EditingRequestMethodMoveCursorLeft(EventKeyBinding),
+    EditingRequestMethodMoveCursorRight(EventKeyBinding),
+    EditingRequestMethodCharInput(EventKeyBinding),
+
     ModifyRequestQueryParam(EventKeyBinding),
     EditingRequestQueryParamDeleteCharBackward(EventKeyBinding),
     EditingRequestQueryParamDeleteCharForward(EventKeyBinding),
@@ -185,6 +219,13 @@ pub enum AppEvent {
     EditingRequestAuthBearerTokenMoveCursorRight(EventKeyBinding),
     EditingRequestAuthBearerTokenCharInput(EventKeyBinding),
 
+    ModifyRequestAuthField(EventKeyBinding),
+    EditingRequestAuthFieldDeleteCharBackward(EventKeyBinding),
+    EditingRequestAuthFieldDeleteCharForward(EventKeyBinding),
+    EditingRequestAuthFieldMoveCursorLeft(EventKeyBinding),
+    EditingRequestAuthFieldMoveCursorRight(EventKeyBinding),
+    EditingRequestAuthFieldCharInput(EventKeyBinding),
+
     /* Headers */
     ModifyRequestHeader(EventKeyBinding),
     EditingRequestHeaderDeleteCharBackward(EventKeyBinding),
@@ -208,6 +249,7 @@ pub enum AppEvent {
     EditingRequestBodyFileMoveCursorRight(EventKeyBinding),
     EditingRequestBodyFileCharInput(EventKeyBinding),
 
+    EditingRequestBodyStringAutocomplete(EventKeyBinding),
     EditingRequestBodyStringVimInput(EventKeyBinding),
 
     EditingRequestBodyStringSaveAndQuit(EventKeyBinding),
@@ -269,9 +311,31 @@ pub enum AppEvent {
     /* Settings */
     RequestSettingsMoveUp(EventKeyBinding),
     RequestSettingsMoveDown(EventKeyBinding),
-    RequestSettingsToggleSetting(EventKeyBinding),
+    RequestSettingsPreviousValue(EventKeyBinding),
+    RequestSettingsNextValue(EventKeyBinding),
     ModifyRequestSettings(EventKeyBinding),
 
+    /* Code snippet */
+    CodeSnippetPreviousLanguage(EventKeyBinding),
+    CodeSnippetNextLanguage(EventKeyBinding),
+    CodeSnippetScrollUp(EventKeyBinding),
+    CodeSnippetScrollDown(EventKeyBinding),
+    CopyCodeSnippet(EventKeyBinding),
+
+    /* WebSocket */
+    SendWebSocketMessage(EventKeyBinding),
+    EditingWebSocketMessageDeleteCharBackward(EventKeyBinding),
+    EditingWebSocketMessageDeleteCharForward(EventKeyBinding),
+    EditingWebSocketMessageMoveCursorLeft(EventKeyBinding),
+    EditingWebSocketMessageMoveCursorRight(EventKeyBinding),
+    EditingWebSocketMessageCharInput(EventKeyBinding),
+
+    /* GraphQL schema */
+    GraphQLSchemaPreviousType(EventKeyBinding),
+    GraphQLSchemaNextType(EventKeyBinding),
+    GraphQLSchemaScrollUp(EventKeyBinding),
+    GraphQLSchemaScrollDown(EventKeyBinding),
+
     /* Others */
     Documentation(EventKeyBinding),
 }
@@ -369,8 +433,10 @@ impl App<'_> {
                 DeleteElement(_) => self.delete_element(),
                 RenameElement(_) => self.rename_element(),
 
-                MoveRequestUp(_) => self.move_request_up(),
-                MoveRequestDown(_) => self.move_request_down(),
+                MoveRequestUp(_) => self.move_element_up(),
+                MoveRequestDown(_) => self.move_element_down(),
+                MoveElementIntoFolder(_) => self.move_element_into_folder(),
+                MoveElementOutOfFolder(_) => self.move_element_out_of_folder(),
 
                 NextEnvironment(_) => self.next_environment(),
                 DisplayCookies(_) => self.display_cookies_state(),
@@ -436,6 +502,20 @@ impl App<'_> {
                         self.new_request_popup.text_input.enter_char(char)
                     }
                 }
+                CreateNewFolder(_) => self.new_folder(),
+                CreatingFolderDeleteCharBackward(_) => self.new_folder_input.delete_char_forward(),
+                CreatingFolderDeleteCharForward(_) => self.new_folder_input.delete_char_backward(),
+                CreatingFolderMoveCursorLeft(_) => self.new_folder_input.move_cursor_left(),
+                CreatingFolderMoveCursorRight(_) => self.new_folder_input.move_cursor_right(),
+                CreatingFolderCharInput(_) => {
+                    if let KeyCombination {
+                        codes: One(KeyCode::Char(char)),
+                        ..
+                    } = key
+                    {
+                        self.new_folder_input.enter_char(char)
+                    }
+                }
 
                 DeletingCollectionMoveCursorLeft(_) => self.delete_collection_popup.change_state(),
                 DeletingCollectionMoveCursorRight(_) => self.delete_collection_popup.change_state(),
@@ -455,6 +535,15 @@ impl App<'_> {
                         self.normal_state()
                     }
                 }
+                DeletingFolderMoveCursorLeft(_) => self.delete_folder_popup.change_state(),
+                DeletingFolderMoveCursorRight(_) => self.delete_folder_popup.change_state(),
+                DeleteFolder(_) => {
+                    if self.delete_folder_popup.state {
+                        self.delete_folder()
+                    } else {
+                        self.normal_state()
+                    }
+                }
 
                 RenameCollection(_) => self.rename_collection(),
                 RenamingCollectionDeleteCharBackward(_) => {
@@ -496,16 +585,39 @@ impl App<'_> {
                         self.rename_request_input.enter_char(char)
                     }
                 }
+                RenameFolder(_) => self.rename_folder(),
+                RenamingFolderDeleteCharBackward(_) => {
+                    self.rename_folder_input.delete_char_forward()
+                }
+                RenamingFolderDeleteCharForward(_) => {
+                    self.rename_folder_input.delete_char_backward()
+                }
+                RenamingFolderMoveCursorLeft(_) => self.rename_folder_input.move_cursor_left(),
+                RenamingFolderMoveCursorRight(_) => self.rename_folder_input.move_cursor_right(),
+                RenamingFolderCharInput(_) => {
+                    if let KeyCombination {
+                        codes: One(KeyCode::Char(char)),
+                        ..
+                    } = key
+                    {
+                        self.rename_folder_input.enter_char(char)
+                    }
+                }
 
                 /* Selected Request */
                 GoBackToRequestMenu(_) => self.select_request_state(),
 
                 EditUrl(_) => self.edit_request_url_state(),
                 EditMethod(_) => self.modify_request_method(),
+                EditCustomMethod(_) => self.edit_request_method_state(),
                 EditSettings(_) => self.edit_request_settings_state(),
 
                 NextView(_) => self.next_request_view(),
                 SendRequest(_) => self.send_request().await,
+                CancelRequest(_) => self.cancel_request(),
+
+                CopyRequestAsCurl(_) => self.copy_request_as_curl_to_clipboard(),
+                GenerateCodeSnippet(_) => self.display_code_snippet_state(),
 
                 /* Param tabs */
                 NextParamTab(_) => self.next_request_param_tab(),
@@ -564,6 +676,11 @@ impl App<'_> {
                         self.edit_request_body_file_or_string_state()
                     }
                 }
+                RequestBodyTableMoveUp(_) | RequestBodyTableMoveDown(_)
+                    if self.is_graphql_body_selected() =>
+                {
+                    self.graphql_editor.change_selection()
+                }
                 RequestBodyTableMoveUp(_) => self.body_form_table.up(),
                 RequestBodyTableMoveDown(_) => self.body_form_table.down(),
                 RequestBodyTableMoveLeft(_) | RequestBodyTableMoveRight(_) => {
@@ -572,6 +689,8 @@ impl App<'_> {
                 CreateRequestBodyTableElement(_) => self.create_new_form_data(),
                 DeleteRequestBodyTableElement(_) => self.delete_form_data(),
                 ToggleRequestBodyTableElement(_) => self.toggle_form_data(),
+                FetchGraphQLSchema(_) => self.fetch_graphql_schema(),
+                DisplayGraphQLSchema(_) => self.display_graphql_schema_state(),
 
                 /* Scripts */
                 EditRequestScript(_) => self.edit_request_script_state(),
@@ -604,6 +723,27 @@ impl App<'_> {
                     }
                 }
 
+                ModifyRequestMethod(_) => self.modify_request_custom_method(),
+                EditingRequestMethodDeleteCharBackward(_) => {
+                    self.method_text_input.delete_char_forward()
+                }
+                EditingRequestMethodDeleteCharForward(_) => {
+                    self.method_text_input.delete_char_backward()
+                }
+                EditingRequestMethodMoveCursorLeft(_) => self.method_text_input.move_cursor_left(),
+                EditingRequestMethodMoveCursorRight(_) => {
+                    self.method_text_input.move_cursor_right()
+                }
+                EditingRequestMethodCharInput(_) => {
+                    if let KeyCombination {
+                        codes: One(KeyCode::Char(char)),
+                        ..
+                    } = key
+                    {
+                        self.method_text_input.enter_char(char)
+                    }
+                }
+
                 ModifyRequestQueryParam(_) => self.modify_request_query_param(),
                 EditingRequestQueryParamDeleteCharBackward(_) => self
                     .query_params_table
@@ -701,6 +841,28 @@ impl App<'_> {
                         self.auth_bearer_token_text_input.enter_char(char)
                     }
                 }
+                ModifyRequestAuthField(_) => self.modify_request_auth_field(),
+                EditingRequestAuthFieldDeleteCharBackward(_) => {
+                    self.auth_field_text_input.delete_char_forward()
+                }
+                EditingRequestAuthFieldDeleteCharForward(_) => {
+                    self.auth_field_text_input.delete_char_backward()
+                }
+                EditingRequestAuthFieldMoveCursorLeft(_) => {
+                    self.auth_field_text_input.move_cursor_left()
+                }
+                EditingRequestAuthFieldMoveCursorRight(_) => {
+                    self.auth_field_text_input.move_cursor_right()
+                }
+                EditingRequestAuthFieldCharInput(_) => {
+                    if let KeyCombination {
+                        codes: One(KeyCode::Char(char)),
+                        ..
+                    } = key
+                    {
+                        self.auth_field_text_input.enter_char(char)
+                    }
+                }
 
                 /* Header */
                 ModifyRequestHeader(_) => self.modify_request_header(),
@@ -776,73 +938,84 @@ impl App<'_> {
                         self.body_file_text_input.enter_char(char)
                     }
                 }
-                EditingRequestBodyStringVimInput(_) => match self
-                    .body_text_area_vim_emulation
-                    .transition(key, &mut self.body_text_area)
-                {
-                    VimTransition::Mode(mode) if self.body_text_area_vim_emulation.mode != mode => {
-                        self.body_text_area.set_block(mode.block());
-                        self.body_text_area.set_cursor_style(mode.cursor_style());
-                        self.body_text_area_vim_emulation = Vim::new(mode);
-                    }
-                    VimTransition::Nop | VimTransition::Mode(_) => {
-                        self.body_text_area_vim_emulation = self.body_text_area_vim_emulation;
-                    }
-                    VimTransition::Pending(input) => {
-                        self.body_text_area_vim_emulation =
-                            self.body_text_area_vim_emulation.with_pending(input);
+                EditingRequestBodyStringAutocomplete(_) => self.autocomplete_graphql_query(),
+                EditingRequestBodyStringVimInput(_) => {
+                    let text_area = match self.graphql_editor.selection {
+                        1 => &mut self.graphql_editor.variables_text_area,
+                        _ => &mut self.body_text_area,
+                    };
+
+                    match self.body_text_area_vim_emulation.transition(key, text_area) {
+                        VimTransition::Mode(mode)
+                            if self.body_text_area_vim_emulation.mode != mode =>
+                        {
+                            text_area.set_block(mode.block());
+                            text_area.set_cursor_style(mode.cursor_style());
+                            self.body_text_area_vim_emulation = Vim::new(mode);
+                        }
+                        VimTransition::Nop | VimTransition::Mode(_) => {
+                            self.body_text_area_vim_emulation = self.body_text_area_vim_emulation;
+                        }
+                        VimTransition::Pending(input) => {
+                            self.body_text_area_vim_emulation =
+                                self.body_text_area_vim_emulation.with_pending(input);
+                        }
+                        VimTransition::Quit => self.select_request_state(),
+                        VimTransition::SaveAndQuit => self.modify_request_body(),
                     }
-                    VimTransition::Quit => self.select_request_state(),
-                    VimTransition::SaveAndQuit => self.modify_request_body(),
-                },
+                }
 
                 EditingRequestBodyStringSaveAndQuit(_) => self.modify_request_body(),
-                EditingRequestBodyStringCopy(_) => self.body_text_area.copy(),
+                EditingRequestBodyStringCopy(_) => self.selected_body_text_area().copy(),
                 EditingRequestBodyStringPaste(_) => {
-                    self.body_text_area.paste();
+                    self.selected_body_text_area().paste();
                 }
                 EditingRequestBodyStringUndo(_) => {
-                    self.body_text_area.undo();
+                    self.selected_body_text_area().undo();
                 }
                 EditingRequestBodyStringRedo(_) => {
-                    self.body_text_area.redo();
+                    self.selected_body_text_area().redo();
+                }
+                EditingRequestBodyStringNewLine(_) => {
+                    self.selected_body_text_area().insert_newline()
                 }
-                EditingRequestBodyStringNewLine(_) => self.body_text_area.insert_newline(),
                 EditingRequestBodyStringIndent(_) => {
-                    self.body_text_area.set_hard_tab_indent(true);
-                    self.body_text_area.insert_tab();
+                    self.selected_body_text_area().set_hard_tab_indent(true);
+                    self.selected_body_text_area().insert_tab();
                 }
                 EditingRequestBodyStringDeleteCharBackward(_) => {
-                    self.body_text_area.delete_next_char();
+                    self.selected_body_text_area().delete_next_char();
                 }
                 EditingRequestBodyStringDeleteCharForward(_) => {
-                    self.body_text_area.delete_char();
-                }
-                EditingRequestBodyStringSkipWordLeft(_) => {
-                    self.body_text_area.move_cursor(CursorMove::WordBack)
-                }
-                EditingRequestBodyStringSkipWordRight(_) => {
-                    self.body_text_area.move_cursor(CursorMove::WordForward)
+                    self.selected_body_text_area().delete_char();
                 }
+                EditingRequestBodyStringSkipWordLeft(_) => self
+                    .selected_body_text_area()
+                    .move_cursor(CursorMove::WordBack),
+                EditingRequestBodyStringSkipWordRight(_) => self
+                    .selected_body_text_area()
+                    .move_cursor(CursorMove::WordForward),
                 EditingRequestBodyStringMoveCursorUp(_) => {
-                    self.body_text_area.move_cursor(CursorMove::Up)
-                }
-                EditingRequestBodyStringMoveCursorDown(_) => {
-                    self.body_text_area.move_cursor(CursorMove::Bottom)
+                    self.selected_body_text_area().move_cursor(CursorMove::Up)
                 }
+                EditingRequestBodyStringMoveCursorDown(_) => self
+                    .selected_body_text_area()
+                    .move_cursor(CursorMove::Bottom),
                 EditingRequestBodyStringMoveCursorLeft(_) => {
-                    self.body_text_area.move_cursor(CursorMove::Back)
-                }
-                EditingRequestBodyStringMoveCursorRight(_) => {
-                    self.body_text_area.move_cursor(CursorMove::Forward)
+                    self.selected_body_text_area().move_cursor(CursorMove::Back)
                 }
+                EditingRequestBodyStringMoveCursorRight(_) => self
+                    .selected_body_text_area()
+                    .move_cursor(CursorMove::Forward),
                 EditingRequestBodyStringCharInput(_) => {
+                    self.graphql_editor.completions.clear();
+
                     if let KeyCombination {
                         codes: One(KeyCode::Char(char)),
                         ..
                     } = key
                     {
-                        self.body_text_area.insert_char(char)
+                        self.selected_body_text_area().insert_char(char)
                     }
                 }
 
@@ -1024,9 +1197,53 @@ impl App<'_> {
                 /* Settings */
                 RequestSettingsMoveUp(_) => self.request_settings_popup.previous(),
                 RequestSettingsMoveDown(_) => self.request_settings_popup.next(),
-                RequestSettingsToggleSetting(_) => self.request_settings_popup.toggle_setting(),
+                RequestSettingsPreviousValue(_) => self.request_settings_popup.previous_value(),
+                RequestSettingsNextValue(_) => self.request_settings_popup.next_value(),
                 ModifyRequestSettings(_) => self.modify_request_settings(),
 
+                /* Code snippet */
+                CodeSnippetPreviousLanguage(_) => {
+                    self.code_snippet_popup.previous_language();
+                    self.update_code_snippet();
+                }
+                CodeSnippetNextLanguage(_) => {
+                    self.code_snippet_popup.next_language();
+                    self.update_code_snippet();
+                }
+                CodeSnippetScrollUp(_) => self.code_snippet_popup.scroll_up(),
+                CodeSnippetScrollDown(_) => self.code_snippet_popup.scroll_down(),
+                CopyCodeSnippet(_) => self.copy_code_snippet_to_clipboard(),
+
+                /* WebSocket */
+                SendWebSocketMessage(_) => self.send_websocket_message(),
+                EditingWebSocketMessageDeleteCharBackward(_) => {
+                    self.websocket_message_text_input.delete_char_forward()
+                }
+                EditingWebSocketMessageDeleteCharForward(_) => {
+                    self.websocket_message_text_input.delete_char_backward()
+                }
+                EditingWebSocketMessageMoveCursorLeft(_) => {
+                    self.websocket_message_text_input.move_cursor_left()
+                }
+                EditingWebSocketMessageMoveCursorRight(_) => {
+                    self.websocket_message_text_input.move_cursor_right()
+                }
+                EditingWebSocketMessageCharInput(_) => {
+                    if let KeyCombination {
+                        codes: One(KeyCode::Char(char)),
+                        ..
+                    } = key
+                    {
+                        self.websocket_message_text_input.enter_char(char)
+                    }
+                }
+
+                /* GraphQL schema */
+                GraphQLSchemaPreviousType(_) => self.graphql_schema_popup.previous_type(),
+                GraphQLSchemaNextType(_) => self.graphql_schema_popup.next_type(),
+                GraphQLSchemaScrollUp(_) => self.graphql_schema_popup.scroll_up(),
+                GraphQLSchemaScrollDown(_) => self.graphql_schema_popup.scroll_down(),
+
                 /* Others */
                 Documentation(_) => {}
             },
@@ -1050,6 +1267,8 @@ impl AppEvent {
             | RenameElement(event_key_bindings)
             | MoveRequestUp(event_key_bindings)
             | MoveRequestDown(event_key_bindings)
+            | MoveElementIntoFolder(event_key_bindings)
+            | MoveElementOutOfFolder(event_key_bindings)
             | NextEnvironment(event_key_bindings)
             | DisplayCookies(event_key_bindings)
             | GoBackToMainMenu(event_key_bindings)
@@ -1075,12 +1294,21 @@ impl AppEvent {
             | CreatingRequestSelectCollectionUp(event_key_bindings)
             | CreatingRequestSelectCollectionDown(event_key_bindings)
             | CreatingRequestCharInput(event_key_bindings)
+            | CreateNewFolder(event_key_bindings)
+            | CreatingFolderDeleteCharBackward(event_key_bindings)
+            | CreatingFolderDeleteCharForward(event_key_bindings)
+            | CreatingFolderMoveCursorLeft(event_key_bindings)
+            | CreatingFolderMoveCursorRight(event_key_bindings)
+            | CreatingFolderCharInput(event_key_bindings)
             | DeletingCollectionMoveCursorLeft(event_key_bindings)
             | DeletingCollectionMoveCursorRight(event_key_bindings)
             | DeleteCollection(event_key_bindings)
             | DeletingRequestMoveCursorLeft(event_key_bindings)
             | DeletingRequestMoveCursorRight(event_key_bindings)
             | DeleteRequest(event_key_bindings)
+            | DeletingFolderMoveCursorLeft(event_key_bindings)
+            | DeletingFolderMoveCursorRight(event_key_bindings)
+            | DeleteFolder(event_key_bindings)
             | RenameCollection(event_key_bindings)
             | RenamingCollectionDeleteCharBackward(event_key_bindings)
             | RenamingCollectionDeleteCharForward(event_key_bindings)
@@ -1093,12 +1321,22 @@ impl AppEvent {
             | RenamingRequestMoveCursorLeft(event_key_bindings)
             | RenamingRequestMoveCursorRight(event_key_bindings)
             | RenamingRequestCharInput(event_key_bindings)
+            | RenameFolder(event_key_bindings)
+            | RenamingFolderDeleteCharBackward(event_key_bindings)
+            | RenamingFolderDeleteCharForward(event_key_bindings)
+            | RenamingFolderMoveCursorLeft(event_key_bindings)
+            | RenamingFolderMoveCursorRight(event_key_bindings)
+            | RenamingFolderCharInput(event_key_bindings)
             | GoBackToRequestMenu(event_key_bindings)
             | EditUrl(event_key_bindings)
             | EditMethod(event_key_bindings)
+            | EditCustomMethod(event_key_bindings)
             | EditSettings(event_key_bindings)
             | NextView(event_key_bindings)
             | SendRequest(event_key_bindings)
+            | CancelRequest(event_key_bindings)
+            | CopyRequestAsCurl(event_key_bindings)
+            | GenerateCodeSnippet(event_key_bindings)
             | NextParamTab(event_key_bindings)
             | ModifyRequestAuthMethod(event_key_bindings)
             | ModifyRequestBodyContentType(event_key_bindings)
@@ -1129,6 +1367,8 @@ impl AppEvent {
             | CreateRequestBodyTableElement(event_key_bindings)
             | DeleteRequestBodyTableElement(event_key_bindings)
             | ToggleRequestBodyTableElement(event_key_bindings)
+            | FetchGraphQLSchema(event_key_bindings)
+            | DisplayGraphQLSchema(event_key_bindings)
             | EditRequestScript(event_key_bindings)
             | RequestScriptMove(event_key_bindings)
             | NextResultTab(event_key_bindings)
@@ -1143,6 +1383,12 @@ impl AppEvent {
             | EditingRequestUrlMoveCursorLeft(event_key_bindings)
             | EditingRequestUrlMoveCursorRight(event_key_bindings)
             | EditingRequestUrlCharInput(event_key_bindings)
+            | ModifyRequestMethod(event_key_bindings)
+            | EditingRequestMethodDeleteCharBackward(event_key_bindings)
+            | EditingRequestMethodDeleteCharForward(event_key_bindings)
+            | EditingRequestMethodMoveCursorLeft(event_key_bindings)
+            | EditingRequestMethodMoveCursorRight(event_key_bindings)
+            | EditingRequestMethodCharInput(event_key_bindings)
             | ModifyRequestQueryParam(event_key_bindings)
             | EditingRequestQueryParamDeleteCharBackward(event_key_bindings)
             | EditingRequestQueryParamDeleteCharForward(event_key_bindings)
@@ -1167,6 +1413,12 @@ impl AppEvent {
             | EditingRequestAuthBearerTokenMoveCursorLeft(event_key_bindings)
             | EditingRequestAuthBearerTokenMoveCursorRight(event_key_bindings)
             | EditingRequestAuthBearerTokenCharInput(event_key_bindings)
+            | ModifyRequestAuthField(event_key_bindings)
+            | EditingRequestAuthFieldDeleteCharBackward(event_key_bindings)
+            | EditingRequestAuthFieldDeleteCharForward(event_key_bindings)
+            | EditingRequestAuthFieldMoveCursorLeft(event_key_bindings)
+            | EditingRequestAuthFieldMoveCursorRight(event_key_bindings)
+            | EditingRequestAuthFieldCharInput(event_key_bindings)
             | ModifyRequestHeader(event_key_bindings)
             | EditingRequestHeaderDeleteCharBackward(event_key_bindings)
             | EditingRequestHeaderDeleteCharForward(event_key_bindings)
@@ -1185,6 +1437,7 @@ impl AppEvent {
             | EditingRequestBodyFileMoveCursorLeft(event_key_bindings)
             | EditingRequestBodyFileMoveCursorRight(event_key_bindings)
             | EditingRequestBodyFileCharInput(event_key_bindings)
+            | EditingRequestBodyStringAutocomplete(event_key_bindings)
             | EditingRequestBodyStringVimInput(event_key_bindings)
             | EditingRequestBodyStringCopy(event_key_bindings)
             | EditingRequestBodyStringPaste(event_key_bindings)
@@ -1238,8 +1491,24 @@ impl AppEvent {
             | EditingPostRequestScriptCharInput(event_key_bindings)
             | RequestSettingsMoveUp(event_key_bindings)
             | RequestSettingsMoveDown(event_key_bindings)
-            | RequestSettingsToggleSetting(event_key_bindings)
+            | RequestSettingsPreviousValue(event_key_bindings)
+            | RequestSettingsNextValue(event_key_bindings)
             | ModifyRequestSettings(event_key_bindings)
+            | CodeSnippetPreviousLanguage(event_key_bindings)
+            | CodeSnippetNextLanguage(event_key_bindings)
+            | CodeSnippetScrollUp(event_key_bindings)
+            | CodeSnippetScrollDown(event_key_bindings)
+            | CopyCodeSnippet(event_key_bindings)
+            | SendWebSocketMessage(event_key_bindings)
+            | EditingWebSocketMessageDeleteCharBackward(event_key_bindings)
+            | EditingWebSocketMessageDeleteCharForward(event_key_bindings)
+            | EditingWebSocketMessageMoveCursorLeft(event_key_bindings)
+            | EditingWebSocketMessageMoveCursorRight(event_key_bindings)
+            | EditingWebSocketMessageCharInput(event_key_bindings)
+            | GraphQLSchemaPreviousType(event_key_bindings)
+            | GraphQLSchemaNextType(event_key_bindings)
+            | GraphQLSchemaScrollUp(event_key_bindings)
+            | GraphQLSchemaScrollDown(event_key_bindings)
             | Documentation(event_key_bindings) => event_key_bindings,
         }
     }
@@ -45,7 +45,9 @@ impl App<'_> {
 
         self.collections.push(collection);
 
-        println!("Collection file parsed!");
+        if !ARGS.is_headless {
+            println!("Collection file parsed!");
+        }
     }
 
     /// Save app collection in the collection file through a temporary file
@@ -1,6 +1,8 @@
 use crate::app::app::App;
+use crate::app::startup::args::ARGS;
 use crate::panic_error;
 use crate::request::collection::CollectionFileFormat;
+use crate::request::settings::TlsSettings;
 use serde::{Deserialize, Serialize};
 use std::fs::OpenOptions;
 use std::io::Read;
@@ -21,6 +23,9 @@ pub struct Config {
     pub preferred_collection_file_format: Option<CollectionFileFormat>,
 
     pub proxy: Option<Proxy>,
+
+    #[serde(default)]
+    pub tls: Option<TlsSettings>,
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -71,6 +76,8 @@ impl App<'_> {
 
         self.config = config;
 
-        println!("Config file parsed!");
+        if !ARGS.is_headless {
+            println!("Config file parsed!");
+        }
     }
 }
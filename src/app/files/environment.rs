@@ -38,7 +38,9 @@ impl App<'_> {
 
         self.environments.push(Arc::new(RwLock::new(environment)));
 
-        println!("environment file parsed!");
+        if !ARGS.is_headless {
+            println!("environment file parsed!");
+        }
     }
 }
 
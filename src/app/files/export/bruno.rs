@@ -0,0 +1,423 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::app::app::App;
+use crate::panic_error;
+use crate::request::auth::{ApiKeyLocation, Auth, OAuth2GrantType};
+use crate::request::body::ContentType;
+use crate::request::collection::Collection;
+use crate::request::request::{KeyValue, Request, DEFAULT_HEADERS};
+
+impl App<'_> {
+    /// Write a collection as a Bruno collection directory, one .bru file per request.
+    /// The selected environment can be written in the environments directory
+    pub fn export_bruno_collection(
+        &self,
+        collection_index: usize,
+        path: &Path,
+        export_environment: bool,
+    ) {
+        let collection = &self.collections[collection_index];
+
+        create_directory(path);
+
+        let bruno_json = json!({
+            "version": "1",
+            "name": collection.name,
+            "type": "collection",
+            "ignore": ["node_modules", ".git"]
+        });
+
+        write_file(
+            &path.join("bruno.json"),
+            serde_json::to_string_pretty(&bruno_json).unwrap_or_default(),
+        );
+
+        /* COLLECTION */
+
+        if let Some(collection_bru) = collection_to_bru(collection) {
+            write_file(&path.join("collection.bru"), collection_bru);
+        }
+
+        /* REQUESTS */
+
+        let mut file_names: HashSet<String> = HashSet::new();
+
+        for (index, request) in collection.all_requests().iter().enumerate() {
+            let request = request.read();
+
+            // Bruno uses the file name as a fallback name, it must be unique and valid
+            let mut file_name = request
+                .name
+                .replace(['/', '\\', ':', '*', '?', '"', '<', '>', '|'], "-");
+
+            if !file_names.insert(file_name.clone()) {
+                file_name = format!("{file_name} {}", index + 1);
+                file_names.insert(file_name.clone());
+            }
+
+            write_file(
+                &path.join(format!("{file_name}.bru")),
+                request_to_bru(&request, index + 1),
+            );
+        }
+
+        /* ENVIRONMENT */
+
+        if !export_environment {
+            return;
+        }
+
+        if let Some(local_env) = self.get_selected_env_as_local() {
+            let env = local_env.read();
+
+            let environments_path = path.join("environments");
+            create_directory(&environments_path);
+
+            let vars: Vec<String> = env
+                .values
+                .iter()
+                .map(|(key, value)| format!("{key}: {value}"))
+                .collect();
+
+            write_file(
+                &environments_path.join(format!("{}.bru", env.name)),
+                format!("{}\n", bru_block("vars", &vars)),
+            );
+        }
+    }
+}
+
+/// The collection headers, auth and variables, if there are some
+fn collection_to_bru(collection: &Collection) -> Option<String> {
+    let mut blocks: Vec<String> = vec![];
+
+    if !collection.headers.is_empty() {
+        blocks.push(bru_block(
+            "headers",
+            &key_values_to_lines(&collection.headers),
+        ));
+    }
+
+    if !collection.auth.is_no_auth() {
+        blocks.push(bru_block(
+            "auth",
+            &[format!("mode: {}", auth_to_bru_mode(&collection.auth))],
+        ));
+        blocks.extend(auth_to_bru_block(&collection.auth));
+    }
+
+    if !collection.variables.is_empty() {
+        let vars: Vec<String> = collection
+            .variables
+            .iter()
+            .map(|(key, value)| format!("{key}: {value}"))
+            .collect();
+
+        blocks.push(bru_block("vars:pre-request", &vars));
+    }
+
+    match blocks.is_empty() {
+        true => None,
+        false => Some(format!("{}\n", blocks.join("\n\n"))),
+    }
+}
+
+fn request_to_bru(request: &Request, sequence: usize) -> String {
+    let mut blocks: Vec<String> = vec![];
+
+    let auth_mode = auth_to_bru_mode(&request.auth);
+
+    let body_mode = match request.body {
+        ContentType::NoBody => "none",
+        ContentType::File(_) => "file",
+        ContentType::Multipart(_) => "multipartForm",
+        ContentType::Form(_) => "formUrlEncoded",
+        ContentType::Json(_) => "json",
+        ContentType::Xml(_) => "xml",
+        ContentType::Raw(_) | ContentType::Html(_) | ContentType::Javascript(_) => "text",
+        ContentType::GraphQL { .. } => "graphql",
+    };
+
+    let request_type = match request.body {
+        ContentType::GraphQL { .. } => "graphql",
+        _ => "http",
+    };
+
+    /* META */
+
+    blocks.push(bru_block(
+        "meta",
+        &[
+            format!("name: {}", request.name),
+            format!("type: {request_type}"),
+            format!("seq: {sequence}"),
+        ],
+    ));
+
+    /* METHOD & URL */
+
+    // Bruno expects the query params both in the URL and in their own block
+    let enabled_params: Vec<String> = request
+        .params
+        .iter()
+        .filter(|param| param.enabled)
+        .map(|param| format!("{}={}", param.data.0, param.data.1))
+        .collect();
+
+    let url = match enabled_params.is_empty() {
+        true => request.url.clone(),
+        false => format!("{}?{}", request.url, enabled_params.join("&")),
+    };
+
+    blocks.push(bru_block(
+        &request.method.to_string().to_lowercase(),
+        &[
+            format!("url: {url}"),
+            format!("body: {body_mode}"),
+            format!("auth: {auth_mode}"),
+        ],
+    ));
+
+    /* QUERY PARAMS */
+
+    if !request.params.is_empty() {
+        blocks.push(bru_block(
+            "params:query",
+            &key_values_to_lines(&request.params),
+        ));
+    }
+
+    /* HEADERS */
+
+    // Default headers are skipped, they are added back by the importer and Bruno sets its own
+    let headers: Vec<KeyValue> = request
+        .headers
+        .iter()
+        .filter(|header| {
+            !DEFAULT_HEADERS
+                .iter()
+                .any(|default_header| default_header.data == header.data)
+        })
+        .cloned()
+        .collect();
+
+    if !headers.is_empty() {
+        blocks.push(bru_block("headers", &key_values_to_lines(&headers)));
+    }
+
+    /* AUTH */
+
+    blocks.extend(auth_to_bru_block(&request.auth));
+
+    /* BODY */
+
+    match &request.body {
+        ContentType::NoBody => {}
+        ContentType::File(file_path) => blocks.push(bru_block(
+            "body:file",
+            &[format!("file: @file({file_path})")],
+        )),
+        ContentType::Multipart(form_data) => {
+            // If the value starts with !!, then it is supposed to be a file
+            let form_data: Vec<KeyValue> = form_data
+                .iter()
+                .map(|form_data| {
+                    let mut form_data = form_data.clone();
+
+                    if let Some(file_path) = form_data.data.1.strip_prefix("!!") {
+                        form_data.data.1 = format!("@file({file_path})");
+                    }
+
+                    form_data
+                })
+                .collect();
+
+            blocks.push(bru_block(
+                "body:multipart-form",
+                &key_values_to_lines(&form_data),
+            ))
+        }
+        ContentType::Form(form_data) => blocks.push(bru_block(
+            "body:form-urlencoded",
+            &key_values_to_lines(form_data),
+        )),
+        ContentType::Raw(body)
+        | ContentType::Json(body)
+        | ContentType::Xml(body)
+        | ContentType::Html(body)
+        | ContentType::Javascript(body) => {
+            let lines: Vec<String> = body.lines().map(str::to_string).collect();
+            blocks.push(bru_block(&format!("body:{body_mode}"), &lines));
+        }
+        ContentType::GraphQL { query, variables } => {
+            let lines: Vec<String> = query.lines().map(str::to_string).collect();
+            blocks.push(bru_block("body:graphql", &lines));
+
+            if !variables.trim().is_empty() {
+                let lines: Vec<String> = variables.lines().map(str::to_string).collect();
+                blocks.push(bru_block("body:graphql:vars", &lines));
+            }
+        }
+    }
+
+    /* SCRIPTS */
+
+    if let Some(pre_request_script) = &request.scripts.pre_request_script {
+        let lines: Vec<String> = pre_request_script.lines().map(str::to_string).collect();
+        blocks.push(bru_block("script:pre-request", &lines));
+    }
+
+    if let Some(post_request_script) = &request.scripts.post_request_script {
+        let lines: Vec<String> = post_request_script.lines().map(str::to_string).collect();
+        blocks.push(bru_block("script:post-response", &lines));
+    }
+
+    let mut bru_file = blocks.join("\n\n");
+    bru_file.push('\n');
+
+    bru_file
+}
+
+fn auth_to_bru_mode(auth: &Auth) -> &'static str {
+    match auth {
+        Auth::NoAuth => "none",
+        Auth::BasicAuth(_, _) => "basic",
+        Auth::BearerToken(_) => "bearer",
+        Auth::Digest(_, _) => "digest",
+        Auth::ApiKey(_) => "apikey",
+        Auth::AwsSigV4(_) => "awsv4",
+        // Not supported by Bruno
+        Auth::Jwt(_) | Auth::OAuth1(_) => "none",
+        Auth::OAuth2(_) => "oauth2",
+        Auth::InheritFromCollection => "inherit",
+    }
+}
+
+fn auth_to_bru_block(auth: &Auth) -> Option<String> {
+    match auth {
+        Auth::NoAuth | Auth::InheritFromCollection | Auth::Jwt(_) | Auth::OAuth1(_) => None,
+        Auth::BasicAuth(username, password) => Some(bru_block(
+            "auth:basic",
+            &[
+                format!("username: {username}"),
+                format!("password: {password}"),
+            ],
+        )),
+        Auth::BearerToken(bearer_token) => Some(bru_block(
+            "auth:bearer",
+            &[format!("token: {bearer_token}")],
+        )),
+        Auth::Digest(username, password) => Some(bru_block(
+            "auth:digest",
+            &[
+                format!("username: {username}"),
+                format!("password: {password}"),
+            ],
+        )),
+        Auth::ApiKey(api_key) => {
+            let placement = match api_key.location {
+                ApiKeyLocation::Header => "header",
+                ApiKeyLocation::Query => "queryparams",
+            };
+
+            Some(bru_block(
+                "auth:apikey",
+                &[
+                    format!("key: {}", api_key.key),
+                    format!("value: {}", api_key.value),
+                    format!("placement: {placement}"),
+                ],
+            ))
+        }
+        Auth::AwsSigV4(aws_sig_v4) => Some(bru_block(
+            "auth:awsv4",
+            &[
+                format!("accessKeyId: {}", aws_sig_v4.access_key_id),
+                format!("secretAccessKey: {}", aws_sig_v4.secret_access_key),
+                format!("sessionToken: {}", aws_sig_v4.session_token),
+                format!("service: {}", aws_sig_v4.service),
+                format!("region: {}", aws_sig_v4.region),
+                String::from("profileName: "),
+            ],
+        )),
+        Auth::OAuth2(oauth2) => {
+            let mut lines = vec![];
+
+            match oauth2.grant_type {
+                OAuth2GrantType::ClientCredentials => {
+                    lines.push(String::from("grant_type: client_credentials"));
+                }
+                OAuth2GrantType::Password => {
+                    lines.push(String::from("grant_type: password"));
+                    lines.push(format!("username: {}", oauth2.username));
+                    lines.push(format!("password: {}", oauth2.password));
+                }
+                OAuth2GrantType::AuthorizationCodePkce => {
+                    lines.push(String::from("grant_type: authorization_code"));
+                    lines.push(format!("callback_url: {}", oauth2.redirect_uri));
+                    lines.push(format!("authorization_url: {}", oauth2.authorization_url));
+                }
+            }
+
+            lines.push(format!("access_token_url: {}", oauth2.access_token_url));
+            lines.push(format!("client_id: {}", oauth2.client_id));
+            lines.push(format!("client_secret: {}", oauth2.client_secret));
+            lines.push(format!("scope: {}", oauth2.scope));
+
+            if oauth2.grant_type == OAuth2GrantType::AuthorizationCodePkce {
+                lines.push(String::from("pkce: true"));
+            }
+
+            Some(bru_block("auth:oauth2", &lines))
+        }
+    }
+}
+
+/// Content lines are indented by two spaces, a disabled key starts with ~
+fn key_values_to_lines(key_values: &[KeyValue]) -> Vec<String> {
+    key_values
+        .iter()
+        .map(|key_value| {
+            let disabled = match key_value.enabled {
+                true => "",
+                false => "~",
+            };
+
+            format!("{disabled}{}: {}", key_value.data.0, key_value.data.1)
+        })
+        .collect()
+}
+
+fn bru_block(name: &str, lines: &[String]) -> String {
+    let content: Vec<String> = lines
+        .iter()
+        .map(|line| match line.is_empty() {
+            true => String::new(),
+            false => format!("  {line}"),
+        })
+        .collect();
+
+    format!("{name} {{\n{}\n}}", content.join("\n"))
+}
+
+fn create_directory(path: &Path) {
+    if let Err(e) = fs::create_dir_all(path) {
+        panic_error(format!(
+            "Could not create directory \"{}\"\n\t{e}",
+            path.display()
+        ));
+    }
+}
+
+fn write_file(path: &Path, content: String) {
+    if let Err(e) = fs::write(path, content) {
+        panic_error(format!(
+            "Could not write Bruno file \"{}\"\n\t{e}",
+            path.display()
+        ));
+    }
+}
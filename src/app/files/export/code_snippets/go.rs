@@ -0,0 +1,185 @@
+use std::collections::BTreeSet;
+
+use crate::app::files::export::code_snippets::{
+    file_name, quote, MultipartValue, SnippetBody, SnippetRequest,
+};
+use crate::request::auth::Auth;
+
+pub(super) fn to_snippet(request: &SnippetRequest) -> String {
+    let mut imports: BTreeSet<&str> = BTreeSet::from(["fmt", "io", "net/http"]);
+    let mut body_lines: Vec<String> = vec![];
+
+    /* BODY */
+
+    let body_reader = match &request.body {
+        SnippetBody::NoBody => "nil",
+        SnippetBody::Text(body) => {
+            imports.insert("strings");
+            body_lines.push(format!("\tbody := strings.NewReader({})", go_string(body)));
+            "body"
+        }
+        SnippetBody::Form(form_data) => {
+            imports.insert("net/url");
+            imports.insert("strings");
+            body_lines.push(String::from("\tform := url.Values{}"));
+
+            for (key, value) in form_data {
+                body_lines.push(format!(
+                    "\tform.Add({}, {})",
+                    go_string(key),
+                    go_string(value)
+                ));
+            }
+
+            body_lines.push(String::from("\tbody := strings.NewReader(form.Encode())"));
+            "body"
+        }
+        SnippetBody::Multipart(form_data) => {
+            imports.insert("bytes");
+            imports.insert("mime/multipart");
+            body_lines.push(String::from("\tbody := &bytes.Buffer{}"));
+            body_lines.push(String::from("\twriter := multipart.NewWriter(body)"));
+
+            for (key, value) in form_data {
+                match value {
+                    MultipartValue::Text(value) => body_lines.push(format!(
+                        "\twriter.WriteField({}, {})",
+                        go_string(key),
+                        go_string(value)
+                    )),
+                    MultipartValue::File(file_path) => {
+                        imports.insert("os");
+                        body_lines.extend([
+                            String::from("\t{"),
+                            format!("\t\tcontent, err := os.ReadFile({})", go_string(file_path)),
+                            String::from("\t\tif err != nil {"),
+                            String::from("\t\t\tpanic(err)"),
+                            String::from("\t\t}"),
+                            format!(
+                                "\t\tpart, _ := writer.CreateFormFile({}, {})",
+                                go_string(key),
+                                go_string(&file_name(file_path))
+                            ),
+                            String::from("\t\tpart.Write(content)"),
+                            String::from("\t}"),
+                        ]);
+                    }
+                }
+            }
+
+            body_lines.push(String::from("\twriter.Close()"));
+            "body"
+        }
+        SnippetBody::File(file_path) => {
+            imports.insert("os");
+            body_lines.extend([
+                format!("\tbody, err := os.Open({})", go_string(file_path)),
+                String::from("\tif err != nil {"),
+                String::from("\t\tpanic(err)"),
+                String::from("\t}"),
+                String::from("\tdefer body.Close()"),
+            ]);
+            "body"
+        }
+    };
+
+    /* IMPORTS */
+
+    let mut lines: Vec<String> = vec![String::from("package main"), String::new()];
+
+    lines.push(String::from("import ("));
+
+    for import in &imports {
+        lines.push(format!("\t\"{import}\""));
+    }
+
+    lines.extend([
+        String::from(")"),
+        String::new(),
+        String::from("func main() {"),
+    ]);
+
+    lines.extend(body_lines);
+
+    /* REQUEST */
+
+    lines.extend([
+        format!(
+            "\treq, err := http.NewRequest({}, {}, {body_reader})",
+            go_string(&request.method),
+            go_string(&request.url)
+        ),
+        String::from("\tif err != nil {"),
+        String::from("\t\tpanic(err)"),
+        String::from("\t}"),
+    ]);
+
+    /* HEADERS */
+
+    for (header, value) in &request.headers {
+        lines.push(format!(
+            "\treq.Header.Add({}, {})",
+            go_string(header),
+            go_string(value)
+        ));
+    }
+
+    if let SnippetBody::Multipart(_) = request.body {
+        lines.push(String::from(
+            "\treq.Header.Set(\"Content-Type\", writer.FormDataContentType())",
+        ));
+    }
+
+    /* AUTH */
+
+    match &request.auth {
+        // Digest needs a challenge/response round trip, which the standard library does not do
+        Auth::NoAuth
+        | Auth::InheritFromCollection
+        | Auth::Digest(_, _)
+        | Auth::ApiKey(_)
+        | Auth::AwsSigV4(_)
+        | Auth::Jwt(_)
+        | Auth::OAuth1(_)
+        | Auth::OAuth2(_) => {}
+        Auth::BasicAuth(username, password) => lines.push(format!(
+            "\treq.SetBasicAuth({}, {})",
+            go_string(username),
+            go_string(password)
+        )),
+        Auth::BearerToken(bearer_token) => lines.push(format!(
+            "\treq.Header.Add(\"Authorization\", {})",
+            go_string(&format!("Bearer {bearer_token}"))
+        )),
+    }
+
+    /* RESPONSE */
+
+    lines.extend([
+        String::new(),
+        String::from("\tres, err := http.DefaultClient.Do(req)"),
+        String::from("\tif err != nil {"),
+        String::from("\t\tpanic(err)"),
+        String::from("\t}"),
+        String::from("\tdefer res.Body.Close()"),
+        String::new(),
+        String::from("\tresBody, err := io.ReadAll(res.Body)"),
+        String::from("\tif err != nil {"),
+        String::from("\t\tpanic(err)"),
+        String::from("\t}"),
+        String::new(),
+        String::from("\tfmt.Println(res.Status)"),
+        String::from("\tfmt.Println(string(resBody))"),
+        String::from("}"),
+    ]);
+
+    lines.join("\n")
+}
+
+/// Raw string literals are used when the string contains quotes, backslashes or new lines
+fn go_string(input: &str) -> String {
+    match input.contains(['"', '\\', '\n']) && !input.contains(['`', '\r']) {
+        true => format!("`{input}`"),
+        false => quote(input),
+    }
+}
@@ -0,0 +1,81 @@
+use crate::app::files::export::code_snippets::{MultipartValue, SnippetBody, SnippetRequest};
+use crate::app::files::export::shell_quote;
+use crate::request::auth::Auth;
+
+pub(super) fn to_snippet(request: &SnippetRequest) -> String {
+    let mut parts: Vec<String> = vec![];
+    let mut suffix = String::new();
+
+    /* OPTIONS */
+
+    match &request.auth {
+        Auth::NoAuth
+        | Auth::InheritFromCollection
+        | Auth::ApiKey(_)
+        | Auth::AwsSigV4(_)
+        | Auth::Jwt(_)
+        | Auth::OAuth1(_)
+        | Auth::OAuth2(_) => {}
+        Auth::BasicAuth(username, password) => parts.push(format!(
+            "--auth {}",
+            shell_quote(&format!("{username}:{password}"))
+        )),
+        Auth::BearerToken(bearer_token) => parts.push(format!(
+            "--auth-type bearer --auth {}",
+            shell_quote(bearer_token)
+        )),
+        Auth::Digest(username, password) => parts.push(format!(
+            "--auth-type digest --auth {}",
+            shell_quote(&format!("{username}:{password}"))
+        )),
+    }
+
+    match &request.body {
+        SnippetBody::NoBody => {}
+        SnippetBody::Text(body) => parts.push(format!("--raw {}", shell_quote(body))),
+        SnippetBody::Form(_) => parts.push(String::from("--form")),
+        SnippetBody::Multipart(_) => parts.push(String::from("--multipart")),
+        SnippetBody::File(file_path) => suffix = format!(" < {}", shell_quote(file_path)),
+    }
+
+    /* METHOD & URL */
+
+    parts.push(request.method.clone());
+    parts.push(shell_quote(&request.url));
+
+    let mut lines: Vec<String> = vec![format!("http {}", parts.join(" "))];
+
+    /* HEADERS */
+
+    for (header, value) in &request.headers {
+        lines.push(shell_quote(&format!("{header}:{value}")));
+    }
+
+    /* BODY ITEMS */
+
+    match &request.body {
+        SnippetBody::Form(form_data) => {
+            for (key, value) in form_data {
+                lines.push(shell_quote(&format!("{key}={value}")));
+            }
+        }
+        SnippetBody::Multipart(form_data) => {
+            for (key, value) in form_data {
+                match value {
+                    MultipartValue::Text(value) => {
+                        lines.push(shell_quote(&format!("{key}={value}")))
+                    }
+                    MultipartValue::File(file_path) => {
+                        lines.push(shell_quote(&format!("{key}@{file_path}")))
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let mut snippet = lines.join(" \\\n  ");
+    snippet.push_str(&suffix);
+
+    snippet
+}
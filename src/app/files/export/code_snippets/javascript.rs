@@ -0,0 +1,127 @@
+use crate::app::files::export::code_snippets::{
+    basic_auth_header_value, file_name, quote, MultipartValue, SnippetBody, SnippetRequest,
+};
+use crate::request::auth::Auth;
+
+pub(super) fn to_snippet(request: &SnippetRequest) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    let uses_files = match &request.body {
+        SnippetBody::File(_) => true,
+        SnippetBody::Multipart(form_data) => form_data
+            .iter()
+            .any(|(_, value)| matches!(value, MultipartValue::File(_))),
+        _ => false,
+    };
+
+    if uses_files {
+        lines.push(String::from("import { readFileSync } from \"node:fs\";"));
+        lines.push(String::new());
+    }
+
+    lines.push(format!("const url = {};", quote(&request.url)));
+
+    /* HEADERS */
+
+    let mut headers = request.headers.clone();
+
+    match &request.auth {
+        // Digest needs a challenge/response round trip, which the standard library does not do
+        Auth::NoAuth
+        | Auth::InheritFromCollection
+        | Auth::Digest(_, _)
+        | Auth::ApiKey(_)
+        | Auth::AwsSigV4(_)
+        | Auth::Jwt(_)
+        | Auth::OAuth1(_)
+        | Auth::OAuth2(_) => {}
+        Auth::BasicAuth(username, password) => headers.push((
+            String::from("Authorization"),
+            basic_auth_header_value(username, password),
+        )),
+        Auth::BearerToken(bearer_token) => headers.push((
+            String::from("Authorization"),
+            format!("Bearer {bearer_token}"),
+        )),
+    }
+
+    /* BODY */
+
+    let has_body = match &request.body {
+        SnippetBody::NoBody => false,
+        SnippetBody::Text(body) => {
+            lines.push(format!("const body = {};", javascript_string(body)));
+            true
+        }
+        SnippetBody::Form(form_data) => {
+            lines.push(String::from("const body = new URLSearchParams(["));
+
+            for (key, value) in form_data {
+                lines.push(format!("  [{}, {}],", quote(key), quote(value)));
+            }
+
+            lines.push(String::from("]);"));
+            true
+        }
+        SnippetBody::Multipart(form_data) => {
+            lines.push(String::from("const body = new FormData();"));
+
+            for (key, value) in form_data {
+                match value {
+                    MultipartValue::Text(value) => {
+                        lines.push(format!("body.append({}, {});", quote(key), quote(value)))
+                    }
+                    MultipartValue::File(file_path) => lines.push(format!(
+                        "body.append({}, new Blob([readFileSync({})]), {});",
+                        quote(key),
+                        quote(file_path),
+                        quote(&file_name(file_path))
+                    )),
+                }
+            }
+
+            true
+        }
+        SnippetBody::File(file_path) => {
+            lines.push(format!("const body = readFileSync({});", quote(file_path)));
+            true
+        }
+    };
+
+    /* REQUEST */
+
+    lines.push(String::new());
+    lines.push(String::from("const response = await fetch(url, {"));
+    lines.push(format!("  method: {},", quote(&request.method)));
+
+    if !headers.is_empty() {
+        lines.push(String::from("  headers: {"));
+
+        for (header, value) in &headers {
+            lines.push(format!("    {}: {},", quote(header), quote(value)));
+        }
+
+        lines.push(String::from("  },"));
+    }
+
+    if has_body {
+        lines.push(String::from("  body,"));
+    }
+
+    lines.extend([
+        String::from("});"),
+        String::new(),
+        String::from("console.log(response.status);"),
+        String::from("console.log(await response.text());"),
+    ]);
+
+    lines.join("\n")
+}
+
+/// Multiline strings are written as template literals when possible
+fn javascript_string(input: &str) -> String {
+    match input.contains('\n') && !input.contains(['`', '\\']) && !input.contains("${") {
+        true => format!("`{input}`"),
+        false => quote(input),
+    }
+}
@@ -0,0 +1,195 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::header::CONTENT_TYPE;
+use strum::{Display, EnumIter, FromRepr};
+
+use crate::app::app::App;
+use crate::app::files::export::url_with_params;
+use crate::request::auth::{ApiKeyLocation, Auth};
+use crate::request::body::ContentType;
+use crate::request::graphql::graphql_export_payload;
+use crate::request::request::{KeyValue, Request};
+
+mod go;
+mod httpie;
+mod javascript;
+mod python;
+mod rust;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Display, FromRepr, EnumIter)]
+pub enum CodeLanguage {
+    #[default]
+    #[strum(to_string = "Rust (reqwest)")]
+    RustReqwest,
+    #[strum(to_string = "Python (requests)")]
+    PythonRequests,
+    #[strum(to_string = "JavaScript (fetch)")]
+    JavascriptFetch,
+    #[strum(to_string = "Go (net/http)")]
+    GoNetHttp,
+    #[strum(to_string = "HTTPie")]
+    Httpie,
+}
+
+impl CodeLanguage {
+    /// Extension used to find the syntax to highlight the snippet with
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            CodeLanguage::RustReqwest => "rs",
+            CodeLanguage::PythonRequests => "py",
+            CodeLanguage::JavascriptFetch => "js",
+            CodeLanguage::GoNetHttp => "go",
+            CodeLanguage::Httpie => "sh",
+        }
+    }
+}
+
+/// Request with the environment values and without the disabled elements
+struct SnippetRequest {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    auth: Auth,
+    body: SnippetBody,
+}
+
+enum SnippetBody {
+    NoBody,
+    Text(String),
+    Form(Vec<(String, String)>),
+    Multipart(Vec<(String, MultipartValue)>),
+    File(String),
+}
+
+enum MultipartValue {
+    Text(String),
+    File(String),
+}
+
+impl App<'_> {
+    /// Render a request as a code snippet of the given language, with the environment values
+    pub fn request_to_code_snippet(&self, request: &Request, language: CodeLanguage) -> String {
+        let snippet_request = self.resolve_snippet_request(request);
+
+        match language {
+            CodeLanguage::RustReqwest => rust::to_snippet(&snippet_request),
+            CodeLanguage::PythonRequests => python::to_snippet(&snippet_request),
+            CodeLanguage::JavascriptFetch => javascript::to_snippet(&snippet_request),
+            CodeLanguage::GoNetHttp => go::to_snippet(&snippet_request),
+            CodeLanguage::Httpie => httpie::to_snippet(&snippet_request),
+        }
+    }
+
+    fn resolve_snippet_request(&self, request: &Request) -> SnippetRequest {
+        let mut params = self.key_value_vec_to_tuple_vec(&request.params);
+        let mut headers = self.key_value_vec_to_tuple_vec(&request.headers);
+
+        let auth = match &request.auth {
+            Auth::NoAuth | Auth::InheritFromCollection => Auth::NoAuth,
+            Auth::BasicAuth(username, password) => Auth::BasicAuth(
+                self.replace_env_keys_by_value(username),
+                self.replace_env_keys_by_value(password),
+            ),
+            Auth::BearerToken(bearer_token) => {
+                Auth::BearerToken(self.replace_env_keys_by_value(bearer_token))
+            }
+            Auth::Digest(username, password) => Auth::Digest(
+                self.replace_env_keys_by_value(username),
+                self.replace_env_keys_by_value(password),
+            ),
+            // Written as an ordinary header or query param
+            Auth::ApiKey(api_key) => {
+                let key_value = (
+                    self.replace_env_keys_by_value(&api_key.key),
+                    self.replace_env_keys_by_value(&api_key.value),
+                );
+
+                match api_key.location {
+                    ApiKeyLocation::Header => headers.push(key_value),
+                    ApiKeyLocation::Query => params.push(key_value),
+                }
+
+                Auth::NoAuth
+            }
+            // The signature depends on the time the request is sent
+            Auth::AwsSigV4(_) | Auth::OAuth1(_) => Auth::NoAuth,
+            // A token valid from now, the snippets do not generate one
+            Auth::Jwt(jwt) => match self.get_generated_jwt(jwt) {
+                None => Auth::NoAuth,
+                Some(token) => Auth::BearerToken(token),
+            },
+            // Only a cached token can be written, the snippets do not fetch one
+            Auth::OAuth2(oauth2) => match self.get_cached_oauth2_access_token(oauth2) {
+                None => Auth::NoAuth,
+                Some(access_token) => Auth::BearerToken(access_token),
+            },
+        };
+
+        let url = url_with_params(self.replace_env_keys_by_value(&request.url), &params);
+
+        let body = match &request.body {
+            ContentType::NoBody => SnippetBody::NoBody,
+            ContentType::File(file_path) => {
+                SnippetBody::File(self.replace_env_keys_by_value(file_path))
+            }
+            ContentType::Form(form_data) => {
+                SnippetBody::Form(self.key_value_vec_to_tuple_vec(form_data))
+            }
+            ContentType::Multipart(form_data) => {
+                // The multipart content type contains the boundary, it is computed by the HTTP clients
+                headers.retain(|(header, _)| !header.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+
+                SnippetBody::Multipart(self.multipart_to_snippet_values(form_data))
+            }
+            ContentType::Raw(body)
+            | ContentType::Json(body)
+            | ContentType::Xml(body)
+            | ContentType::Html(body)
+            | ContentType::Javascript(body) => {
+                SnippetBody::Text(self.replace_env_keys_by_value(body))
+            }
+            ContentType::GraphQL { query, variables } => SnippetBody::Text(graphql_export_payload(
+                &self.replace_env_keys_by_value(query),
+                &self.replace_env_keys_by_value(variables),
+            )),
+        };
+
+        SnippetRequest {
+            method: request.method.to_string(),
+            url,
+            headers,
+            auth,
+            body,
+        }
+    }
+
+    fn multipart_to_snippet_values(&self, form_data: &[KeyValue]) -> Vec<(String, MultipartValue)> {
+        self.key_value_vec_to_tuple_vec(form_data)
+            .into_iter()
+            // If the value starts with !!, then it is supposed to be a file
+            .map(|(key, value)| match value.strip_prefix("!!") {
+                Some(file_path) => (key, MultipartValue::File(file_path.to_string())),
+                None => (key, MultipartValue::Text(value)),
+            })
+            .collect()
+    }
+}
+
+fn basic_auth_header_value(username: &str, password: &str) -> String {
+    format!(
+        "Basic {}",
+        STANDARD.encode(format!("{username}:{password}"))
+    )
+}
+
+/// Double quoted string literal, JSON escaping is valid in Python, JavaScript and Go
+fn quote(input: &str) -> String {
+    serde_json::to_string(input).unwrap()
+}
+
+fn file_name(file_path: &str) -> String {
+    std::path::Path::new(file_path)
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or(file_path.to_string())
+}
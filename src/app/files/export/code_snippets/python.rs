@@ -0,0 +1,129 @@
+use crate::app::files::export::code_snippets::{
+    file_name, quote, MultipartValue, SnippetBody, SnippetRequest,
+};
+use crate::request::auth::Auth;
+
+pub(super) fn to_snippet(request: &SnippetRequest) -> String {
+    let mut lines: Vec<String> = vec![
+        String::from("import requests"),
+        String::new(),
+        format!("url = {}", quote(&request.url)),
+    ];
+
+    let mut arguments: Vec<&str> = vec![];
+
+    /* HEADERS */
+
+    let mut headers = request.headers.clone();
+
+    if let Auth::BearerToken(bearer_token) = &request.auth {
+        headers.push((
+            String::from("Authorization"),
+            format!("Bearer {bearer_token}"),
+        ));
+    }
+
+    if !headers.is_empty() {
+        lines.push(String::from("headers = {"));
+
+        for (header, value) in &headers {
+            lines.push(format!("    {}: {},", quote(header), quote(value)));
+        }
+
+        lines.push(String::from("}"));
+        arguments.push("headers=headers");
+    }
+
+    /* AUTH */
+
+    match &request.auth {
+        Auth::BasicAuth(username, password) => {
+            lines.push(format!("auth = ({}, {})", quote(username), quote(password)));
+            arguments.push("auth=auth");
+        }
+        Auth::Digest(username, password) => {
+            lines.insert(1, String::from("from requests.auth import HTTPDigestAuth"));
+            lines.push(format!(
+                "auth = HTTPDigestAuth({}, {})",
+                quote(username),
+                quote(password)
+            ));
+            arguments.push("auth=auth");
+        }
+        _ => {}
+    }
+
+    /* BODY */
+
+    match &request.body {
+        SnippetBody::NoBody => {}
+        SnippetBody::Text(body) => {
+            lines.push(format!("data = {}", python_string(body)));
+            arguments.push("data=data");
+        }
+        SnippetBody::Form(form_data) => {
+            lines.push(String::from("data = ["));
+
+            for (key, value) in form_data {
+                lines.push(format!("    ({}, {}),", quote(key), quote(value)));
+            }
+
+            lines.push(String::from("]"));
+            arguments.push("data=data");
+        }
+        SnippetBody::Multipart(form_data) => {
+            lines.push(String::from("files = ["));
+
+            for (key, value) in form_data {
+                // Text fields have no file name
+                let value = match value {
+                    MultipartValue::Text(value) => format!("(None, {})", quote(value)),
+                    MultipartValue::File(file_path) => format!(
+                        "({}, open({}, \"rb\"))",
+                        quote(&file_name(file_path)),
+                        quote(file_path)
+                    ),
+                };
+
+                lines.push(format!("    ({}, {}),", quote(key), value));
+            }
+
+            lines.push(String::from("]"));
+            arguments.push("files=files");
+        }
+        SnippetBody::File(file_path) => {
+            lines.push(format!("data = open({}, \"rb\")", quote(file_path)));
+            arguments.push("data=data");
+        }
+    }
+
+    /* REQUEST */
+
+    let mut request_arguments = vec![quote(&request.method), String::from("url")];
+    request_arguments.extend(arguments.iter().map(|argument| argument.to_string()));
+
+    lines.extend([
+        String::new(),
+        format!(
+            "response = requests.request({})",
+            request_arguments.join(", ")
+        ),
+        String::new(),
+        String::from("print(response.status_code)"),
+        String::from("print(response.text)"),
+    ]);
+
+    lines.join("\n")
+}
+
+/// Multiline strings are written between triple quotes when possible
+fn python_string(input: &str) -> String {
+    match input.contains('\n')
+        && !input.contains('\\')
+        && !input.contains("\"\"\"")
+        && !input.ends_with('"')
+    {
+        true => format!("\"\"\"{input}\"\"\""),
+        false => quote(input),
+    }
+}
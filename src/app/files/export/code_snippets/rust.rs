@@ -0,0 +1,143 @@
+use crate::app::files::export::code_snippets::{
+    file_name, MultipartValue, SnippetBody, SnippetRequest,
+};
+use crate::request::auth::Auth;
+
+pub(super) fn to_snippet(request: &SnippetRequest) -> String {
+    let mut lines: Vec<String> = vec![
+        String::from("#[tokio::main]"),
+        String::from("async fn main() -> Result<(), Box<dyn std::error::Error>> {"),
+        String::from("    let client = reqwest::Client::new();"),
+        String::new(),
+    ];
+
+    /* BODY */
+
+    if let SnippetBody::Multipart(form_data) = &request.body {
+        lines.push(String::from(
+            "    let form = reqwest::multipart::Form::new()",
+        ));
+
+        for (key, value) in form_data {
+            match value {
+                MultipartValue::Text(value) => lines.push(format!(
+                    "        .text({}, {})",
+                    rust_string(key),
+                    rust_string(value)
+                )),
+                MultipartValue::File(file_path) => lines.push(format!(
+                    "        .part({}, reqwest::multipart::Part::bytes(std::fs::read({})?).file_name({}))",
+                    rust_string(key),
+                    rust_string(file_path),
+                    rust_string(&file_name(file_path))
+                )),
+            }
+        }
+
+        lines.last_mut().unwrap().push(';');
+        lines.push(String::new());
+    }
+
+    /* METHOD & URL */
+
+    let method = request.method.to_lowercase();
+
+    lines.push(String::from("    let response = client"));
+
+    match method.as_str() {
+        "get" | "post" | "put" | "patch" | "delete" | "head" => {
+            lines.push(format!("        .{method}({})", rust_string(&request.url)))
+        }
+        "options" => lines.push(format!(
+            "        .request(reqwest::Method::OPTIONS, {})",
+            rust_string(&request.url)
+        )),
+        _ => lines.push(format!(
+            "        .request(reqwest::Method::from_bytes({}.as_bytes())?, {})",
+            rust_string(&request.method),
+            rust_string(&request.url)
+        )),
+    }
+
+    /* HEADERS */
+
+    for (header, value) in &request.headers {
+        lines.push(format!(
+            "        .header({}, {})",
+            rust_string(header),
+            rust_string(value)
+        ));
+    }
+
+    /* AUTH */
+
+    match &request.auth {
+        // Digest needs a challenge/response round trip, which the standard library does not do
+        Auth::NoAuth
+        | Auth::InheritFromCollection
+        | Auth::Digest(_, _)
+        | Auth::ApiKey(_)
+        | Auth::AwsSigV4(_)
+        | Auth::Jwt(_)
+        | Auth::OAuth1(_)
+        | Auth::OAuth2(_) => {}
+        Auth::BasicAuth(username, password) => lines.push(format!(
+            "        .basic_auth({}, Some({}))",
+            rust_string(username),
+            rust_string(password)
+        )),
+        Auth::BearerToken(bearer_token) => lines.push(format!(
+            "        .bearer_auth({})",
+            rust_string(bearer_token)
+        )),
+    }
+
+    match &request.body {
+        SnippetBody::NoBody => {}
+        SnippetBody::Text(body) => lines.push(format!("        .body({})", rust_string(body))),
+        SnippetBody::Form(form_data) => {
+            let pairs: Vec<String> = form_data
+                .iter()
+                .map(|(key, value)| format!("({}, {})", rust_string(key), rust_string(value)))
+                .collect();
+
+            lines.push(format!("        .form(&[{}])", pairs.join(", ")));
+        }
+        SnippetBody::Multipart(_) => lines.push(String::from("        .multipart(form)")),
+        SnippetBody::File(file_path) => lines.push(format!(
+            "        .body(std::fs::read({})?)",
+            rust_string(file_path)
+        )),
+    }
+
+    lines.push(String::from("        .send()"));
+    lines.push(String::from("        .await?;"));
+
+    /* RESPONSE */
+
+    lines.extend([
+        String::new(),
+        String::from("    println!(\"{}\", response.status());"),
+        String::from("    println!(\"{}\", response.text().await?);"),
+        String::new(),
+        String::from("    Ok(())"),
+        String::from("}"),
+    ]);
+
+    lines.join("\n")
+}
+
+/// Raw string literals are used as soon as the string contains quotes, backslashes or new lines
+fn rust_string(input: &str) -> String {
+    if !input.contains(['"', '\\', '\n']) {
+        return format!("\"{input}\"");
+    }
+
+    let mut hashes = String::from("#");
+
+    while input.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+
+    format!("r{hashes}\"{input}\"{hashes}")
+}
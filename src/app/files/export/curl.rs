@@ -0,0 +1,206 @@
+use reqwest::header::CONTENT_TYPE;
+
+use crate::app::app::App;
+use crate::app::files::export::{shell_quote, url_with_params};
+use crate::request::auth::{ApiKeyLocation, Auth};
+use crate::request::body::ContentType;
+use crate::request::graphql::graphql_export_payload;
+use crate::request::method::Method;
+use crate::request::request::Request;
+
+impl App<'_> {
+    /// Render a request as a curl command line.
+    /// If `resolve_env` is true, the environment keys are replaced by the values of the selected environment
+    pub fn request_to_curl(&self, request: &Request, resolve_env: bool) -> String {
+        let resolve = |input: &String| match resolve_env {
+            true => self.replace_env_keys_by_value(input),
+            false => input.clone(),
+        };
+
+        let mut command = String::from("curl");
+
+        /* METHOD */
+
+        match request.method {
+            // curl switches to POST as soon as a body is given
+            Method::GET if matches!(request.body, ContentType::NoBody) => {}
+            Method::HEAD if matches!(request.body, ContentType::NoBody) => command += " --head",
+            _ => command += &format!(" -X {}", request.method),
+        }
+
+        /* URL */
+
+        let mut params: Vec<(String, String)> = request
+            .params
+            .iter()
+            .filter(|param| param.enabled)
+            .map(|param| (resolve(&param.data.0), resolve(&param.data.1)))
+            .collect();
+
+        if let Auth::ApiKey(api_key) = &request.auth {
+            if api_key.location == ApiKeyLocation::Query {
+                params.push((resolve(&api_key.key), resolve(&api_key.value)));
+            }
+        }
+
+        let url = url_with_params(resolve(&request.url), &params);
+
+        command += &format!(" {}", shell_quote(&url));
+
+        // Every other option is written on its own line
+        let mut args: Vec<String> = vec![command];
+
+        /* AUTH */
+
+        match &request.auth {
+            Auth::NoAuth | Auth::InheritFromCollection => {}
+            Auth::BasicAuth(username, password) => args.push(format!(
+                "-u {}",
+                shell_quote(&format!("{}:{}", resolve(username), resolve(password)))
+            )),
+            Auth::BearerToken(bearer_token) => args.push(format!(
+                "-H {}",
+                shell_quote(&format!("Authorization: Bearer {}", resolve(bearer_token)))
+            )),
+            Auth::Digest(username, password) => args.push(format!(
+                "--digest -u {}",
+                shell_quote(&format!("{}:{}", resolve(username), resolve(password)))
+            )),
+            Auth::ApiKey(api_key) => match api_key.location {
+                ApiKeyLocation::Header => args.push(format!(
+                    "-H {}",
+                    shell_quote(&format!(
+                        "{}: {}",
+                        resolve(&api_key.key),
+                        resolve(&api_key.value)
+                    ))
+                )),
+                // Already added to the URL
+                ApiKeyLocation::Query => {}
+            },
+            // curl signs the request itself
+            Auth::AwsSigV4(aws_sig_v4) => {
+                args.push(format!(
+                    "--aws-sigv4 {}",
+                    shell_quote(&format!(
+                        "aws:amz:{}:{}",
+                        resolve(&aws_sig_v4.region),
+                        resolve(&aws_sig_v4.service)
+                    ))
+                ));
+                args.push(format!(
+                    "-u {}",
+                    shell_quote(&format!(
+                        "{}:{}",
+                        resolve(&aws_sig_v4.access_key_id),
+                        resolve(&aws_sig_v4.secret_access_key)
+                    ))
+                ));
+
+                if !aws_sig_v4.session_token.is_empty() {
+                    args.push(format!(
+                        "-H {}",
+                        shell_quote(&format!(
+                            "x-amz-security-token: {}",
+                            resolve(&aws_sig_v4.session_token)
+                        ))
+                    ));
+                }
+            }
+            // Not supported by curl, the signature depends on the time the request is sent
+            Auth::OAuth1(_) => {}
+            // A token valid from now, curl does not generate one
+            Auth::Jwt(jwt) => match self.get_generated_jwt(jwt) {
+                Some(token) if resolve_env => args.push(format!(
+                    "-H {}",
+                    shell_quote(&format!("Authorization: Bearer {token}"))
+                )),
+                _ => {}
+            },
+            // Only a cached token can be written, curl does not fetch one
+            Auth::OAuth2(oauth2) => match self.get_cached_oauth2_access_token(oauth2) {
+                Some(access_token) if resolve_env => args.push(format!(
+                    "-H {}",
+                    shell_quote(&format!("Authorization: Bearer {access_token}"))
+                )),
+                _ => {}
+            },
+        }
+
+        /* HEADERS */
+
+        for header in &request.headers {
+            if !header.enabled {
+                continue;
+            }
+
+            // curl computes the multipart content type itself, as it contains the boundary
+            if matches!(request.body, ContentType::Multipart(_))
+                && header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str())
+            {
+                continue;
+            }
+
+            args.push(format!(
+                "-H {}",
+                shell_quote(&format!(
+                    "{}: {}",
+                    resolve(&header.data.0),
+                    resolve(&header.data.1)
+                ))
+            ));
+        }
+
+        /* BODY */
+
+        match &request.body {
+            ContentType::NoBody => {}
+            ContentType::Multipart(form_data) => {
+                for form_data in form_data.iter().filter(|form_data| form_data.enabled) {
+                    let key = resolve(&form_data.data.0);
+                    let value = resolve(&form_data.data.1);
+
+                    // If the value starts with !!, then it is supposed to be a file
+                    match value.strip_prefix("!!") {
+                        Some(path) => {
+                            args.push(format!("-F {}", shell_quote(&format!("{key}=@{path}"))))
+                        }
+                        None => args.push(format!(
+                            "--form-string {}",
+                            shell_quote(&format!("{key}={value}"))
+                        )),
+                    }
+                }
+            }
+            ContentType::Form(form_data) => {
+                for form_data in form_data.iter().filter(|form_data| form_data.enabled) {
+                    args.push(format!(
+                        "--data-urlencode {}",
+                        shell_quote(&format!(
+                            "{}={}",
+                            resolve(&form_data.data.0),
+                            resolve(&form_data.data.1)
+                        ))
+                    ));
+                }
+            }
+            ContentType::File(file_path) => args.push(format!(
+                "--data-binary {}",
+                shell_quote(&format!("@{}", resolve(file_path)))
+            )),
+            ContentType::Raw(body)
+            | ContentType::Json(body)
+            | ContentType::Xml(body)
+            | ContentType::Html(body)
+            | ContentType::Javascript(body) => {
+                args.push(format!("--data-raw {}", shell_quote(&resolve(body))))
+            }
+            ContentType::GraphQL { query, variables } => {
+                let payload = graphql_export_payload(&resolve(query), &resolve(variables));
+                args.push(format!("--data-raw {}", shell_quote(&payload)))
+            }
+        }
+
+        args.join(" \\\n  ")
+    }
+}
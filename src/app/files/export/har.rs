@@ -0,0 +1,288 @@
+use std::fs;
+use std::path::PathBuf;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Url;
+use serde_json::{json, Value};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::app::app::App;
+use crate::panic_error;
+use crate::request::auth::{ApiKeyLocation, Auth};
+use crate::request::body::ContentType;
+use crate::request::graphql::graphql_export_payload;
+use crate::request::request::Request;
+use crate::request::response::{RequestResponse, ResponseContent};
+
+impl App<'_> {
+    /// Write a HAR 1.2 file containing a single entry made of the request, with the environment values,
+    /// and its response
+    pub fn export_har_entry(
+        &self,
+        request: &Request,
+        response: &RequestResponse,
+        path_buf: &PathBuf,
+    ) {
+        let time = response
+            .duration
+            .as_deref()
+            .map(duration_to_milliseconds)
+            .unwrap_or(0.0);
+
+        let started_date_time = OffsetDateTime::now_utc()
+            .format(&Rfc3339)
+            .unwrap_or_default();
+
+        let har = json!({
+            "log": {
+                "version": "1.2",
+                "creator": {
+                    "name": "ATAC",
+                    "version": env!("CARGO_PKG_VERSION")
+                },
+                "entries": [
+                    {
+                        "startedDateTime": started_date_time,
+                        "time": time,
+                        "request": self.request_to_har(request),
+                        "response": response_to_har(response),
+                        "cache": {},
+                        "timings": {
+                            "send": 0,
+                            "wait": time,
+                            "receive": 0
+                        }
+                    }
+                ]
+            }
+        });
+
+        let har = match serde_json::to_string_pretty(&har) {
+            Ok(har) => har,
+            Err(e) => panic_error(format!("Could not serialize HAR file\n\t{e}")),
+        };
+
+        if let Err(e) = fs::write(path_buf, har) {
+            panic_error(format!(
+                "Could not write HAR file \"{}\"\n\t{e}",
+                path_buf.display()
+            ));
+        }
+    }
+
+    fn request_to_har(&self, request: &Request) -> Value {
+        /* QUERY PARAMS */
+
+        let mut params = self.key_value_vec_to_tuple_vec(&request.params);
+
+        if let Auth::ApiKey(api_key) = &request.auth {
+            if api_key.location == ApiKeyLocation::Query {
+                params.push((
+                    self.replace_env_keys_by_value(&api_key.key),
+                    self.replace_env_keys_by_value(&api_key.value),
+                ));
+            }
+        }
+
+        let url = self.replace_env_keys_by_value(&request.url);
+        let url = match Url::parse_with_params(&url, &params) {
+            Ok(url) => url.to_string(),
+            Err(_) => url,
+        };
+
+        /* HEADERS */
+
+        let mut headers: Vec<Value> = self
+            .key_value_vec_to_tuple_vec(&request.headers)
+            .into_iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect();
+
+        /* AUTH */
+
+        let authorization = match &request.auth {
+            Auth::NoAuth | Auth::InheritFromCollection => None,
+            Auth::BasicAuth(username, password) => {
+                let credentials = format!(
+                    "{}:{}",
+                    self.replace_env_keys_by_value(username),
+                    self.replace_env_keys_by_value(password)
+                );
+
+                Some(format!("Basic {}", STANDARD.encode(credentials)))
+            }
+            Auth::BearerToken(bearer_token) => Some(format!(
+                "Bearer {}",
+                self.replace_env_keys_by_value(bearer_token)
+            )),
+            // The header depends on the challenge of the server, or on the sending time
+            Auth::Digest(_, _) | Auth::AwsSigV4(_) | Auth::OAuth1(_) => None,
+            Auth::ApiKey(api_key) => {
+                if api_key.location == ApiKeyLocation::Header {
+                    headers.push(json!({
+                        "name": self.replace_env_keys_by_value(&api_key.key),
+                        "value": self.replace_env_keys_by_value(&api_key.value)
+                    }));
+                }
+
+                None
+            }
+            Auth::Jwt(jwt) => self
+                .get_generated_jwt(jwt)
+                .map(|token| format!("Bearer {token}")),
+            Auth::OAuth2(oauth2) => self
+                .get_cached_oauth2_access_token(oauth2)
+                .map(|access_token| format!("Bearer {access_token}")),
+        };
+
+        if let Some(authorization) = authorization {
+            headers.push(json!({ "name": "authorization", "value": authorization }));
+        }
+
+        let mut har_request = json!({
+            "method": request.method.to_string(),
+            "url": url,
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": headers,
+            "queryString": params
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect::<Vec<Value>>(),
+            "headersSize": -1,
+            "bodySize": -1
+        });
+
+        /* BODY */
+
+        let mime_type = request.body.to_content_type();
+
+        let post_data = match &request.body {
+            ContentType::NoBody => None,
+            ContentType::Multipart(form_data) => Some(json!({
+                "mimeType": mime_type,
+                "params": self
+                    .key_value_vec_to_tuple_vec(form_data)
+                    .into_iter()
+                    .map(|(name, value)| match value.strip_prefix("!!") {
+                        // If the value starts with !!, then it is supposed to be a file
+                        Some(file_path) => json!({ "name": name, "fileName": file_path }),
+                        None => json!({ "name": name, "value": value }),
+                    })
+                    .collect::<Vec<Value>>()
+            })),
+            ContentType::Form(form_data) => {
+                let form_data = self.key_value_vec_to_tuple_vec(form_data);
+
+                let text = Url::parse_with_params("http://localhost", &form_data)
+                    .ok()
+                    .and_then(|url| url.query().map(str::to_string))
+                    .unwrap_or_default();
+
+                Some(json!({
+                    "mimeType": mime_type,
+                    "params": form_data
+                        .iter()
+                        .map(|(name, value)| json!({ "name": name, "value": value }))
+                        .collect::<Vec<Value>>(),
+                    "text": text
+                }))
+            }
+            // The file content is not embedded
+            ContentType::File(file_path) => Some(json!({
+                "mimeType": mime_type,
+                "text": "",
+                "comment": format!("File: {}", self.replace_env_keys_by_value(file_path))
+            })),
+            ContentType::Raw(body)
+            | ContentType::Json(body)
+            | ContentType::Xml(body)
+            | ContentType::Html(body)
+            | ContentType::Javascript(body) => Some(json!({
+                "mimeType": mime_type,
+                "text": self.replace_env_keys_by_value(body)
+            })),
+            ContentType::GraphQL { query, variables } => Some(json!({
+                "mimeType": mime_type,
+                "text": graphql_export_payload(
+                    &self.replace_env_keys_by_value(query),
+                    &self.replace_env_keys_by_value(variables)
+                )
+            })),
+        };
+
+        if let Some(post_data) = post_data {
+            har_request["postData"] = post_data;
+        }
+
+        har_request
+    }
+}
+
+fn response_to_har(response: &RequestResponse) -> Value {
+    // e.g. "200 OK", a response without status code is recorded as a failed one
+    let (status, status_text) = match &response.status_code {
+        None => (0, ""),
+        Some(status_code) => match status_code.split_once(' ') {
+            Some((status, status_text)) => (status.parse::<u16>().unwrap_or(0), status_text),
+            None => (status_code.parse::<u16>().unwrap_or(0), ""),
+        },
+    };
+
+    let mime_type = response
+        .headers
+        .iter()
+        .find(|(header, _)| header.as_str() == CONTENT_TYPE.as_str())
+        .map(|(_, value)| value.as_str())
+        .unwrap_or_default();
+
+    let content = match &response.content {
+        None => json!({ "size": 0, "mimeType": mime_type }),
+        Some(ResponseContent::Body(body)) => json!({
+            "size": body.len(),
+            "mimeType": mime_type,
+            "text": body
+        }),
+        Some(ResponseContent::Image(image)) => json!({
+            "size": image.data.len(),
+            "mimeType": mime_type,
+            "text": STANDARD.encode(&image.data),
+            "encoding": "base64"
+        }),
+    };
+
+    json!({
+        "status": status,
+        "statusText": status_text,
+        "httpVersion": response.http_version.as_deref().unwrap_or_default(),
+        "cookies": [],
+        "headers": response
+            .headers
+            .iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect::<Vec<Value>>(),
+        "content": content,
+        "redirectURL": "",
+        "headersSize": -1,
+        "bodySize": -1
+    })
+}
+
+/// The response duration is stored as a debug formatted Duration, e.g. "12.345ms" or "1.2s"
+fn duration_to_milliseconds(duration: &str) -> f64 {
+    let units = [("ms", 1.0), ("µs", 0.001), ("ns", 0.000_001), ("s", 1000.0)];
+
+    for (unit, factor) in units {
+        if let Some(value) = duration.strip_suffix(unit) {
+            return value
+                .parse::<f64>()
+                .map(|value| value * factor)
+                .unwrap_or(0.0);
+        }
+    }
+
+    0.0
+}
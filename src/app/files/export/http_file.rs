@@ -0,0 +1,218 @@
+use std::fs;
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use reqwest::header::CONTENT_TYPE;
+
+use crate::app::app::App;
+use crate::panic_error;
+use crate::request::auth::{ApiKeyLocation, Auth};
+use crate::request::body::ContentType;
+use crate::request::graphql::graphql_export_payload;
+use crate::request::request::Request;
+
+const MULTIPART_BOUNDARY: &str = "----ATACFormBoundary";
+
+impl App<'_> {
+    /// Write a collection as a `.http` file (VS Code REST Client / JetBrains HTTP client format).
+    /// Keys are kept as `{{key}}`, the collection variables and optionally the selected environment values are declared as file variables
+    pub fn export_http_file(
+        &self,
+        collection_index: usize,
+        path_buf: &PathBuf,
+        declare_env_values: bool,
+    ) {
+        let collection = &self.collections[collection_index];
+
+        let mut blocks: Vec<String> = vec![];
+
+        /* VARIABLES */
+
+        let mut variables: IndexMap<String, String> = collection.variables.clone();
+
+        // The environment values take precedence over the collection variables
+        if declare_env_values {
+            if let Some(local_env) = self.get_selected_env_as_local() {
+                let env = local_env.read();
+
+                variables.extend(env.values.clone());
+            }
+        }
+
+        if !variables.is_empty() {
+            let variables: Vec<String> = variables
+                .iter()
+                .map(|(key, value)| format!("@{key} = {value}"))
+                .collect();
+
+            blocks.push(variables.join("\n"));
+        }
+
+        /* REQUESTS */
+
+        // The format has no collection level headers and auth, they are written in every request
+        for request in &collection.all_requests() {
+            let mut request = request.read().clone();
+
+            collection.apply_headers(&mut request);
+            request.auth = collection.resolve_auth(&request.auth).clone();
+
+            blocks.push(request_to_http(&request));
+        }
+
+        let mut http_file = blocks.join("\n\n");
+        http_file.push('\n');
+
+        if let Err(e) = fs::write(path_buf, http_file) {
+            panic_error(format!(
+                "Could not write HTTP file \"{}\"\n\t{e}",
+                path_buf.display()
+            ));
+        }
+    }
+}
+
+/// Disabled params, headers and form values are not exported, the format has no equivalent
+fn request_to_http(request: &Request) -> String {
+    let mut lines: Vec<String> = vec![format!("### {}", request.name)];
+
+    /* REQUEST LINE */
+
+    let mut params: Vec<String> = request
+        .params
+        .iter()
+        .filter(|param| param.enabled)
+        .map(|param| format!("{}={}", param.data.0, param.data.1))
+        .collect();
+
+    if let Auth::ApiKey(api_key) = &request.auth {
+        if api_key.location == ApiKeyLocation::Query {
+            params.push(format!("{}={}", api_key.key, api_key.value));
+        }
+    }
+
+    let url = match (params.is_empty(), request.url.contains('?')) {
+        (true, _) => request.url.clone(),
+        (false, false) => format!("{}?{}", request.url, params.join("&")),
+        (false, true) => format!("{}&{}", request.url, params.join("&")),
+    };
+
+    lines.push(format!("{} {url} HTTP/1.1", request.method));
+
+    /* HEADERS */
+
+    let is_multipart = matches!(request.body, ContentType::Multipart(_));
+
+    for header in &request.headers {
+        if !header.enabled {
+            continue;
+        }
+
+        // The multipart content type needs the boundary used in the body
+        if is_multipart && header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()) {
+            continue;
+        }
+
+        lines.push(format!("{}: {}", header.data.0, header.data.1));
+    }
+
+    if is_multipart {
+        lines.push(format!(
+            "{}: multipart/form-data; boundary={MULTIPART_BOUNDARY}",
+            CONTENT_TYPE.as_str()
+        ));
+    }
+
+    /* AUTH */
+
+    match &request.auth {
+        Auth::NoAuth | Auth::InheritFromCollection => {}
+        Auth::BasicAuth(username, password) => {
+            lines.push(format!("Authorization: Basic {username}:{password}"))
+        }
+        Auth::BearerToken(bearer_token) => {
+            lines.push(format!("Authorization: Bearer {bearer_token}"))
+        }
+        Auth::Digest(username, password) => {
+            lines.push(format!("Authorization: Digest {username} {password}"))
+        }
+        Auth::ApiKey(api_key) => {
+            if api_key.location == ApiKeyLocation::Header {
+                lines.push(format!("{}: {}", api_key.key, api_key.value))
+            }
+        }
+        // Not supported by the format
+        Auth::AwsSigV4(_) | Auth::Jwt(_) | Auth::OAuth1(_) | Auth::OAuth2(_) => {}
+    }
+
+    /* BODY */
+
+    let body = match &request.body {
+        ContentType::NoBody => None,
+        ContentType::File(file_path) => Some(format!("< {file_path}")),
+        ContentType::Form(form_data) => Some(
+            form_data
+                .iter()
+                .filter(|form_data| form_data.enabled)
+                .map(|form_data| format!("{}={}", form_data.data.0, form_data.data.1))
+                .collect::<Vec<String>>()
+                .join("&"),
+        ),
+        ContentType::Multipart(form_data) => {
+            let mut parts: Vec<String> = vec![];
+
+            for form_data in form_data.iter().filter(|form_data| form_data.enabled) {
+                let (key, value) = &form_data.data;
+
+                // If the value starts with !!, then it is supposed to be a file
+                let part = match value.strip_prefix("!!") {
+                    Some(file_path) => {
+                        let file_name = PathBuf::from(file_path)
+                            .file_name()
+                            .map(|file_name| file_name.to_string_lossy().to_string())
+                            .unwrap_or_default();
+
+                        format!(
+                            "--{MULTIPART_BOUNDARY}\nContent-Disposition: form-data; name=\"{key}\"; filename=\"{file_name}\"\n\n< {file_path}"
+                        )
+                    }
+                    None => format!(
+                        "--{MULTIPART_BOUNDARY}\nContent-Disposition: form-data; name=\"{key}\"\n\n{value}"
+                    ),
+                };
+
+                parts.push(part);
+            }
+
+            parts.push(format!("--{MULTIPART_BOUNDARY}--"));
+
+            Some(parts.join("\n"))
+        }
+        ContentType::Raw(body)
+        | ContentType::Json(body)
+        | ContentType::Xml(body)
+        | ContentType::Html(body)
+        | ContentType::Javascript(body) => Some(body.clone()),
+        ContentType::GraphQL { query, variables } => Some(graphql_export_payload(query, variables)),
+    };
+
+    if let Some(body) = body {
+        // Text bodies need a content type to be recognized when imported back
+        let has_content_type = request.headers.iter().any(|header| {
+            header.enabled && header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str())
+        });
+
+        if !is_multipart && !has_content_type && !matches!(request.body, ContentType::Raw(_)) {
+            lines.push(format!(
+                "{}: {}",
+                CONTENT_TYPE.as_str(),
+                request.body.to_content_type()
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(body);
+    }
+
+    lines.join("\n")
+}
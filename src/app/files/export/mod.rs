@@ -0,0 +1,34 @@
+use reqwest::Url;
+
+pub(super) mod bruno;
+pub mod code_snippets;
+pub(super) mod curl;
+pub(super) mod har;
+pub(super) mod http_file;
+pub(super) mod postman;
+
+/// Append the query params to the URL, encoded if the URL can be parsed
+fn url_with_params(url: String, params: &[(String, String)]) -> String {
+    match Url::parse_with_params(&url, params) {
+        Ok(url) if !params.is_empty() => url.to_string(),
+        // Unresolved environment keys may prevent the URL from being parsed, the params are then appended as is
+        _ => {
+            let query = params
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<String>>()
+                .join("&");
+
+            match (query.is_empty(), url.contains('?')) {
+                (true, _) => url,
+                (false, true) => format!("{url}&{query}"),
+                (false, false) => format!("{url}?{query}"),
+            }
+        }
+    }
+}
+
+/// Quote a string so that it is passed as a single argument by POSIX shells
+fn shell_quote(input: &str) -> String {
+    format!("'{}'", input.replace('\'', r"'\''"))
+}
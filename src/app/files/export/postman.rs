@@ -0,0 +1,615 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use parse_postman_collection::v2_1_0::{
+    Auth as PostmanAuth, AuthAttribute, AuthType, Body, BodyClass, Event, File, FormParameter,
+    FormParameterSrcUnion, Header, HeaderUnion, Host, Information, Items, Language, Mode, Options,
+    PathElement, PostmanCollection_v2_1_0, ProtocolProfileBehavior, QueryParam, Raw, RequestClass,
+    RequestUnion, Script, Url, UrlClass, UrlEncodedParameter, UrlPath, Variable,
+};
+use serde_json::Value;
+
+use crate::app::app::App;
+use crate::panic_error;
+use crate::request::auth::{ApiKeyLocation, Auth, OAuth2GrantType};
+use crate::request::body::ContentType;
+use crate::request::collection::{Collection, Folder};
+use crate::request::graphql::graphql_export_payload;
+use crate::request::request::{KeyValue, Request, DEFAULT_HEADERS};
+
+const POSTMAN_SCHEMA: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+impl App<'_> {
+    pub fn export_postman_collection(&self, collection_index: usize, path_buf: &PathBuf) {
+        let postman_collection = collection_to_postman(&self.collections[collection_index]);
+
+        let mut postman_collection = match serde_json::to_value(postman_collection) {
+            Ok(postman_collection) => postman_collection,
+            Err(e) => panic_error(format!("Could not serialize Postman collection\n\t{e}")),
+        };
+
+        // Unset optional fields would otherwise be serialized as null, which Postman does not always accept
+        remove_null_values(&mut postman_collection);
+
+        let postman_collection = match serde_json::to_string_pretty(&postman_collection) {
+            Ok(postman_collection) => postman_collection,
+            Err(e) => panic_error(format!("Could not serialize Postman collection\n\t{e}")),
+        };
+
+        if let Err(e) = fs::write(path_buf, postman_collection) {
+            panic_error(format!(
+                "Could not write Postman collection \"{}\"\n\t{e}",
+                path_buf.display()
+            ));
+        }
+    }
+}
+
+fn collection_to_postman(collection: &Collection) -> PostmanCollection_v2_1_0 {
+    if let Auth::Jwt(_) = collection.auth {
+        eprintln!("JWT auth is not supported by Postman, the collection is exported without auth");
+    }
+
+    PostmanCollection_v2_1_0 {
+        info: Information {
+            name: collection.name.clone(),
+            schema: String::from(POSTMAN_SCHEMA),
+            ..Default::default()
+        },
+        item: children_to_items(collection, &collection.folders, &collection.requests),
+        auth: match collection.auth.is_no_auth() {
+            true => None,
+            false => Some(auth_to_postman(&collection.auth)),
+        },
+        variable: variables_to_postman(&collection.variables),
+        ..Default::default()
+    }
+}
+
+/// Folders are written as items containing other items, before the requests.
+/// Postman has no collection headers, they are written in every request
+fn children_to_items(
+    collection: &Collection,
+    folders: &[Folder],
+    requests: &[Arc<RwLock<Request>>],
+) -> Vec<Items> {
+    let mut items: Vec<Items> = folders
+        .iter()
+        .map(|folder| folder_to_item(collection, folder))
+        .collect();
+
+    items.extend(requests.iter().map(|request| {
+        let mut request = request.read().clone();
+        collection.apply_headers(&mut request);
+
+        // The API key auth is missing from the Postman collection format library
+        if let Auth::ApiKey(api_key) = collection.resolve_auth(&request.auth).clone() {
+            let key_value = KeyValue {
+                enabled: true,
+                data: (api_key.key, api_key.value),
+            };
+
+            match api_key.location {
+                ApiKeyLocation::Header => request.headers.push(key_value),
+                ApiKeyLocation::Query => request.params.push(key_value),
+            }
+
+            request.auth = Auth::NoAuth;
+        }
+
+        if let Auth::Jwt(_) = request.auth {
+            eprintln!(
+                "JWT auth is not supported by Postman, \"{}\" is exported without auth",
+                request.name
+            );
+        }
+
+        request_to_item(&request)
+    }));
+
+    items
+}
+
+fn folder_to_item(collection: &Collection, folder: &Folder) -> Items {
+    Items {
+        description: None,
+        event: None,
+        id: None,
+        name: Some(folder.name.clone()),
+        protocol_profile_behavior: None,
+        request: None,
+        response: None,
+        variable: None,
+        auth: None,
+        item: Some(children_to_items(
+            collection,
+            &folder.folders,
+            &folder.requests,
+        )),
+    }
+}
+
+fn request_to_item(request: &Request) -> Items {
+    /* SCRIPTS */
+
+    let mut events: Vec<Event> = vec![];
+
+    if let Some(pre_request_script) = &request.scripts.pre_request_script {
+        events.push(script_to_event("prerequest", pre_request_script));
+    }
+
+    if let Some(post_request_script) = &request.scripts.post_request_script {
+        events.push(script_to_event("test", post_request_script));
+    }
+
+    /* SETTINGS */
+
+    let protocol_profile_behavior = ProtocolProfileBehavior {
+        disable_body_pruning: None,
+        follow_redirects: Some(request.settings.allow_redirects),
+        disable_cookies: Some(!request.settings.store_received_cookies),
+    };
+
+    /* HEADERS */
+
+    // Default headers are skipped whatever their value (e.g. an older ATAC user agent),
+    // they are added back by the importer and Postman sets its own
+    let headers: Vec<Header> = request
+        .headers
+        .iter()
+        .filter(|header| {
+            !DEFAULT_HEADERS
+                .iter()
+                .any(|default_header| default_header.data.0.eq_ignore_ascii_case(&header.data.0))
+        })
+        .map(|header| Header {
+            description: None,
+            disabled: Some(!header.enabled),
+            key: header.data.0.clone(),
+            value: header.data.1.clone(),
+        })
+        .collect();
+
+    let request_class = RequestClass {
+        // Postman requests without auth inherit it from their parent
+        auth: match request.auth {
+            Auth::InheritFromCollection => None,
+            _ => Some(auth_to_postman(&request.auth)),
+        },
+        body: body_to_postman(&request.body),
+        certificate: None,
+        description: None,
+        header: Some(HeaderUnion::HeaderArray(headers)),
+        method: Some(request.method.to_string()),
+        proxy: None,
+        url: Some(Url::UrlClass(url_to_postman(&request.url, &request.params))),
+    };
+
+    Items {
+        description: None,
+        event: match events.is_empty() {
+            true => None,
+            false => Some(events),
+        },
+        id: None,
+        name: Some(request.name.clone()),
+        protocol_profile_behavior: Some(protocol_profile_behavior),
+        request: Some(RequestUnion::RequestClass(request_class)),
+        response: Some(vec![]),
+        variable: None,
+        auth: None,
+        item: None,
+    }
+}
+
+fn script_to_event(listen: &str, script: &str) -> Event {
+    Event {
+        disabled: None,
+        id: None,
+        listen: String::from(listen),
+        script: Some(Script {
+            exec: Some(Host::StringArray(
+                script.lines().map(|line| line.to_string()).collect(),
+            )),
+            id: None,
+            name: None,
+            src: None,
+            script_type: Some(String::from("text/javascript")),
+        }),
+    }
+}
+
+/// Postman expects the URL to be split into its parts, the raw URL contains the enabled query params
+fn url_to_postman(url: &str, params: &[KeyValue]) -> UrlClass {
+    // Query params written directly in the URL are moved with the others
+    let (url, params) = match url.split_once('?') {
+        None => (url, params.to_vec()),
+        Some((url, url_query)) => {
+            let url_params = url_query
+                .split('&')
+                .filter(|param| !param.is_empty())
+                .map(|param| {
+                    let (key, value) = param.split_once('=').unwrap_or((param, ""));
+
+                    KeyValue {
+                        enabled: true,
+                        data: (key.to_string(), value.to_string()),
+                    }
+                });
+
+            (url, url_params.chain(params.iter().cloned()).collect())
+        }
+    };
+
+    let (protocol, url_without_protocol) = match url.split_once("://") {
+        Some((protocol, rest)) => (Some(protocol.to_string()), rest),
+        None => (None, url),
+    };
+
+    let (host, path) = match url_without_protocol.split_once('/') {
+        Some((host, path)) => (host, Some(path)),
+        None => (url_without_protocol, None),
+    };
+
+    // The port separator must not be confused with a colon inside an environment key
+    let (host, port) = match host.rsplit_once(':') {
+        Some((host_without_port, port))
+            if host_without_port.matches("{{").count()
+                == host_without_port.matches("}}").count() =>
+        {
+            (host_without_port, Some(port.to_string()))
+        }
+        _ => (host, None),
+    };
+
+    let enabled_params: Vec<String> = params
+        .iter()
+        .filter(|param| param.enabled)
+        .map(|param| format!("{}={}", param.data.0, param.data.1))
+        .collect();
+
+    let raw = match enabled_params.is_empty() {
+        true => url.to_string(),
+        false => format!("{url}?{}", enabled_params.join("&")),
+    };
+
+    UrlClass {
+        hash: None,
+        host: Some(Host::StringArray(
+            host.split('.').map(|part| part.to_string()).collect(),
+        )),
+        path: path.map(|path| {
+            UrlPath::UnionArray(
+                path.split('/')
+                    .map(|part| PathElement::String(part.to_string()))
+                    .collect(),
+            )
+        }),
+        port,
+        protocol,
+        query: Some(
+            params
+                .iter()
+                .map(|param| QueryParam {
+                    description: None,
+                    disabled: Some(!param.enabled),
+                    key: Some(param.data.0.clone()),
+                    value: Some(param.data.1.clone()),
+                })
+                .collect(),
+        ),
+        raw: Some(raw),
+        variable: None,
+    }
+}
+
+fn auth_to_postman(auth: &Auth) -> PostmanAuth {
+    let mut postman_auth = PostmanAuth {
+        awsv4: None,
+        basic: None,
+        bearer: None,
+        digest: None,
+        hawk: None,
+        noauth: None,
+        ntlm: None,
+        oauth1: None,
+        oauth2: None,
+        auth_type: AuthType::Noauth,
+    };
+
+    match auth {
+        Auth::NoAuth | Auth::InheritFromCollection => {}
+        Auth::BasicAuth(username, password) => {
+            postman_auth.auth_type = AuthType::Basic;
+            postman_auth.basic = Some(vec![
+                auth_attribute("username", username),
+                auth_attribute("password", password),
+            ]);
+        }
+        Auth::BearerToken(bearer_token) => {
+            postman_auth.auth_type = AuthType::Bearer;
+            postman_auth.bearer = Some(vec![auth_attribute("token", bearer_token)]);
+        }
+        Auth::Digest(username, password) => {
+            postman_auth.auth_type = AuthType::Digest;
+            postman_auth.digest = Some(vec![
+                auth_attribute("username", username),
+                auth_attribute("password", password),
+            ]);
+        }
+        // Written as a header or a query param of the requests instead, see children_to_items
+        Auth::ApiKey(_) => {}
+        // Not supported by the collection format, exported as no auth with a warning
+        Auth::Jwt(_) => {}
+        Auth::OAuth1(oauth1) => {
+            postman_auth.auth_type = AuthType::Oauth1;
+            postman_auth.oauth1 = Some(vec![
+                auth_attribute("consumerKey", &oauth1.consumer_key),
+                auth_attribute("consumerSecret", &oauth1.consumer_secret),
+                auth_attribute("token", &oauth1.token),
+                auth_attribute("tokenSecret", &oauth1.token_secret),
+                auth_attribute("signatureMethod", "HMAC-SHA1"),
+                auth_attribute("version", "1.0"),
+                AuthAttribute {
+                    key: String::from("addParamsToHeader"),
+                    auth_type: Some(String::from("boolean")),
+                    value: Some(Value::Bool(true)),
+                },
+            ]);
+        }
+        Auth::AwsSigV4(aws_sig_v4) => {
+            postman_auth.auth_type = AuthType::Awsv4;
+            postman_auth.awsv4 = Some(vec![
+                auth_attribute("accessKey", &aws_sig_v4.access_key_id),
+                auth_attribute("secretKey", &aws_sig_v4.secret_access_key),
+                auth_attribute("sessionToken", &aws_sig_v4.session_token),
+                auth_attribute("region", &aws_sig_v4.region),
+                auth_attribute("service", &aws_sig_v4.service),
+            ]);
+        }
+        Auth::OAuth2(oauth2) => {
+            let grant_type = match oauth2.grant_type {
+                OAuth2GrantType::ClientCredentials => "client_credentials",
+                OAuth2GrantType::Password => "password_credentials",
+                OAuth2GrantType::AuthorizationCodePkce => "authorization_code_with_pkce",
+            };
+
+            postman_auth.auth_type = AuthType::Oauth2;
+            postman_auth.oauth2 = Some(vec![
+                auth_attribute("grant_type", grant_type),
+                auth_attribute("accessTokenUrl", &oauth2.access_token_url),
+                auth_attribute("authUrl", &oauth2.authorization_url),
+                auth_attribute("redirect_uri", &oauth2.redirect_uri),
+                auth_attribute("clientId", &oauth2.client_id),
+                auth_attribute("clientSecret", &oauth2.client_secret),
+                auth_attribute("scope", &oauth2.scope),
+                auth_attribute("username", &oauth2.username),
+                auth_attribute("password", &oauth2.password),
+                auth_attribute("challengeAlgorithm", "S256"),
+            ]);
+        }
+    }
+
+    postman_auth
+}
+
+fn variables_to_postman(variables: &IndexMap<String, String>) -> Option<Vec<Variable>> {
+    if variables.is_empty() {
+        return None;
+    }
+
+    let variables = variables
+        .iter()
+        .map(|(key, value)| Variable {
+            description: None,
+            disabled: None,
+            id: None,
+            key: Some(key.clone()),
+            name: None,
+            system: None,
+            variable_type: None,
+            value: Some(Value::String(value.clone())),
+        })
+        .collect();
+
+    Some(variables)
+}
+
+fn auth_attribute(key: &str, value: &str) -> AuthAttribute {
+    AuthAttribute {
+        key: String::from(key),
+        auth_type: Some(String::from("string")),
+        value: Some(Value::String(value.to_string())),
+    }
+}
+
+fn body_to_postman(content_type: &ContentType) -> Option<Body> {
+    let mut body_class = BodyClass {
+        disabled: None,
+        file: None,
+        formdata: None,
+        options: None,
+        mode: None,
+        raw: None,
+        urlencoded: None,
+    };
+
+    match content_type {
+        ContentType::NoBody => return None,
+        ContentType::File(file_path) => {
+            body_class.mode = Some(Mode::File);
+            body_class.file = Some(File {
+                content: None,
+                src: Some(file_path.clone()),
+            });
+        }
+        ContentType::Multipart(form_data) => {
+            body_class.mode = Some(Mode::Formdata);
+            body_class.formdata = Some(
+                form_data
+                    .iter()
+                    .map(|form_data| {
+                        let (key, value) = &form_data.data;
+
+                        // If the value starts with !!, then it is supposed to be a file
+                        let (form_parameter_type, value, src) = match value.strip_prefix("!!") {
+                            Some(file_path) => (
+                                "file",
+                                None,
+                                Some(FormParameterSrcUnion::File(file_path.to_string())),
+                            ),
+                            None => ("text", Some(value.clone()), None),
+                        };
+
+                        FormParameter {
+                            content_type: None,
+                            description: None,
+                            disabled: Some(!form_data.enabled),
+                            key: key.clone(),
+                            form_parameter_type: Some(String::from(form_parameter_type)),
+                            value,
+                            src,
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        ContentType::Form(form_data) => {
+            body_class.mode = Some(Mode::Urlencoded);
+            body_class.urlencoded = Some(
+                form_data
+                    .iter()
+                    .map(|form_data| UrlEncodedParameter {
+                        description: None,
+                        disabled: Some(!form_data.enabled),
+                        key: form_data.data.0.clone(),
+                        value: Some(form_data.data.1.clone()),
+                    })
+                    .collect(),
+            );
+        }
+        ContentType::Raw(body)
+        | ContentType::Json(body)
+        | ContentType::Xml(body)
+        | ContentType::Html(body)
+        | ContentType::Javascript(body) => {
+            let language = match content_type {
+                ContentType::Json(_) => Language::Json,
+                ContentType::Xml(_) => Language::Xml,
+                ContentType::Html(_) => Language::Html,
+                ContentType::Javascript(_) => Language::Javascript,
+                _ => Language::Text,
+            };
+
+            body_class.mode = Some(Mode::Raw);
+            body_class.raw = Some(body.clone());
+            body_class.options = Some(Options {
+                raw: Some(Raw {
+                    language: Some(language),
+                }),
+            });
+        }
+        // The graphql mode is not supported by the Postman collection parser
+        ContentType::GraphQL { query, variables } => {
+            body_class.mode = Some(Mode::Raw);
+            body_class.raw = Some(graphql_export_payload(query, variables));
+            body_class.options = Some(Options {
+                raw: Some(Raw {
+                    language: Some(Language::Json),
+                }),
+            });
+        }
+    }
+
+    Some(Body::BodyClass(body_class))
+}
+
+fn remove_null_values(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            object.retain(|_, value| !value.is_null());
+            object.values_mut().for_each(remove_null_values);
+        }
+        Value::Array(array) => array.iter_mut().for_each(remove_null_values),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use parse_postman_collection::v2_1_0::PostmanCollection_v2_1_0;
+    use serde_json::Value;
+
+    use super::{collection_to_postman, remove_null_values};
+    use crate::app::files::import::postman::parse_collection;
+    use crate::request::collection::Collection;
+
+    /// Export a base collection and import it back, as if written to a file in between
+    fn round_trip(file_name: &str) -> (Collection, Collection) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("base_collections")
+            .join(file_name);
+        let collection: Collection =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+        let mut exported = serde_json::to_value(collection_to_postman(&collection)).unwrap();
+        remove_null_values(&mut exported);
+
+        let exported: PostmanCollection_v2_1_0 = serde_json::from_value(exported).unwrap();
+
+        (collection, parse_collection(exported, 99))
+    }
+
+    fn requests_as_values(collection: &Collection) -> Vec<Value> {
+        collection
+            .all_requests()
+            .iter()
+            .map(|request| {
+                let request = request.read();
+                // The default headers are imported with the current ATAC user agent
+                let header_keys: Vec<&String> = request
+                    .headers
+                    .iter()
+                    .map(|header| &header.data.0)
+                    .collect();
+
+                serde_json::json!({
+                    "name": request.name,
+                    "url": request.url,
+                    "method": request.method,
+                    "headers": header_keys,
+                    "body": request.body,
+                    "auth": request.auth,
+                    "scripts": request.scripts,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn auth_collection_round_trip() {
+        let (collection, imported) = round_trip("auth.json");
+
+        assert_eq!(imported.name, collection.name);
+        assert_eq!(
+            requests_as_values(&imported),
+            requests_as_values(&collection)
+        );
+    }
+
+    #[test]
+    fn scripts_collection_round_trip() {
+        let (collection, imported) = round_trip("Scripts.json");
+
+        assert_eq!(
+            requests_as_values(&imported),
+            requests_as_values(&collection)
+        );
+    }
+}
@@ -0,0 +1,667 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use regex::Regex;
+use reqwest::header::CONTENT_TYPE;
+use serde_json::Value;
+use walkdir::WalkDir;
+
+use crate::app::app::App;
+use crate::app::files::environment::save_environment_to_file;
+use crate::app::startup::args::ARGS;
+use crate::panic_error;
+use crate::request::auth::{ApiKey, ApiKeyLocation, Auth, AwsSigV4, OAuth2, OAuth2GrantType};
+use crate::request::body::ContentType;
+use crate::request::collection::Collection;
+use crate::request::environment::Environment;
+use crate::request::method::Method;
+use crate::request::request::{KeyValue, Request, DEFAULT_HEADERS};
+
+lazy_static! {
+    /// e.g. `@file(./avatar.png)` or `@file(./avatar.png) @contentType(image/png)`
+    static ref FILE_REGEX: Regex = Regex::new(r"@file\((?<path>[^)]*)\)").unwrap();
+}
+
+const HTTP_METHOD_BLOCKS: [&str; 9] = [
+    "get", "post", "put", "patch", "delete", "options", "head", "connect", "trace",
+];
+
+/// A top level `name { ... }` or `name [ ... ]` block of a .bru file, its lines are unindented
+struct BruBlock {
+    name: String,
+    lines: Vec<String>,
+}
+
+impl BruBlock {
+    /// Content of text blocks, such as bodies, scripts and docs
+    fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Content of dictionary blocks, such as headers or params. A key starting with ~ is disabled
+    fn key_values(&self) -> Vec<KeyValue> {
+        self.lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (key, value) = line.split_once(':').unwrap_or((line, ""));
+                let key = key.trim();
+
+                let (key, enabled) = match key.strip_prefix('~') {
+                    Some(key) => (key, false),
+                    None => (key, true),
+                };
+
+                KeyValue {
+                    enabled,
+                    data: (key.to_string(), value.trim().to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn get_value(&self, key: &str) -> Option<String> {
+        self.key_values()
+            .into_iter()
+            .find(|key_value| key_value.enabled && key_value.data.0 == key)
+            .map(|key_value| key_value.data.1)
+    }
+}
+
+impl App<'_> {
+    pub fn import_bruno_collection(&mut self, path_buf: &PathBuf, max_depth: u16) {
+        println!("Parsing Bruno collection");
+
+        if !path_buf.is_dir() {
+            panic_error(format!(
+                "\"{}\" is not a Bruno collection directory",
+                path_buf.display()
+            ));
+        }
+
+        /* COLLECTION NAME */
+
+        let bruno_json = fs::read_to_string(path_buf.join("bruno.json"))
+            .ok()
+            .and_then(|bruno_json| serde_json::from_str::<Value>(&bruno_json).ok());
+
+        let bruno_collection_name = match bruno_json
+            .as_ref()
+            .and_then(|bruno_json| bruno_json.get("name"))
+            .and_then(Value::as_str)
+        {
+            Some(name) => name.to_string(),
+            None => path_buf.file_name().unwrap().to_str().unwrap().to_string(),
+        };
+
+        println!("Collection name: {}", bruno_collection_name);
+
+        /* COLLECTION HEADERS, AUTH & VARIABLES */
+
+        // Bruno folders become collections, they all get the Bruno collection values
+        let collection_bru = path_buf.join("collection.bru");
+
+        let collection_blocks = match collection_bru.is_file() {
+            true => parse_bru_file(&collection_bru),
+            false => vec![],
+        };
+
+        let collection_headers = find_block(&collection_blocks, "headers")
+            .map(BruBlock::key_values)
+            .unwrap_or_default();
+
+        let collection_auth =
+            match find_block(&collection_blocks, "auth").and_then(|auth| auth.get_value("mode")) {
+                None => Auth::NoAuth,
+                Some(auth_mode) => retrieve_auth(&collection_blocks, &auth_mode),
+            };
+
+        let mut collection_variables: IndexMap<String, String> = IndexMap::new();
+
+        if let Some(vars) = find_block(&collection_blocks, "vars:pre-request") {
+            for var in vars.key_values() {
+                if var.enabled {
+                    collection_variables.insert(var.data.0, var.data.1);
+                }
+            }
+        }
+
+        /* REQUESTS */
+
+        // Requests are sorted by their sequence number inside each collection
+        let mut bruno_requests: IndexMap<String, Vec<(u32, Request)>> = IndexMap::new();
+
+        let walker = WalkDir::new(path_buf)
+            .max_depth(max_depth as usize)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.file_name() != "environments" && entry.file_name() != "node_modules"
+            })
+            .filter_map(|e| e.ok());
+
+        for entry in walker {
+            let is_bru_file = entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "bru");
+            let file_name = entry.file_name().to_str().unwrap_or_default();
+
+            if !entry.file_type().is_file()
+                || !is_bru_file
+                || file_name == "folder.bru"
+                || file_name == "collection.bru"
+            {
+                continue;
+            }
+
+            let blocks = parse_bru_file(entry.path());
+
+            let request = match parse_request(&blocks, entry.path()) {
+                None => continue,
+                Some(request) => request,
+            };
+
+            let collection_name =
+                retrieve_collection_name(path_buf, entry.path(), &bruno_collection_name);
+
+            let sequence = find_block(&blocks, "meta")
+                .and_then(|meta| meta.get_value("seq"))
+                .and_then(|sequence| sequence.parse::<u32>().ok())
+                .unwrap_or(u32::MAX);
+
+            bruno_requests
+                .entry(collection_name)
+                .or_default()
+                .push((sequence, request));
+        }
+
+        let file_format = self.config.get_preferred_collection_file_format();
+
+        let mut collections: Vec<Collection> = vec![];
+
+        for (collection_name, mut requests) in bruno_requests {
+            println!("\tFound collection \"{}\"", collection_name);
+
+            if self
+                .collections
+                .iter()
+                .any(|collection| collection.name == collection_name)
+            {
+                panic_error(format!("Collection \"{}\" already exists", collection_name));
+            }
+
+            requests.sort_by_key(|(sequence, _)| *sequence);
+
+            collections.push(Collection {
+                name: collection_name.clone(),
+                headers: collection_headers.clone(),
+                auth: collection_auth.clone(),
+                variables: collection_variables.clone(),
+                requests: requests
+                    .into_iter()
+                    .map(|(_, request)| Arc::new(RwLock::new(request)))
+                    .collect(),
+                folders: vec![],
+                path: ARGS
+                    .directory
+                    .join(format!("{}.{}", collection_name, file_format)),
+                file_format,
+            });
+        }
+
+        /* ENVIRONMENTS */
+
+        let environments = retrieve_environments(&path_buf.join("environments"));
+
+        for environment in &environments {
+            for existing_environment in &self.environments {
+                if existing_environment.read().name == environment.name {
+                    panic_error(format!(
+                        "Environment \"{}\" already exists",
+                        environment.name
+                    ));
+                }
+            }
+        }
+
+        let first_new_collection_index = self.collections.len();
+
+        self.collections.extend(collections);
+
+        for collection_index in first_new_collection_index..self.collections.len() {
+            self.save_collection_to_file(collection_index);
+        }
+
+        for environment in environments {
+            save_environment_to_file(&environment);
+            self.environments.push(Arc::new(RwLock::new(environment)));
+        }
+    }
+}
+
+fn parse_bru_file(path: &Path) -> Vec<BruBlock> {
+    let bru_file = match fs::read_to_string(path) {
+        Ok(bru_file) => bru_file,
+        Err(e) => panic_error(format!(
+            "Could not read Bruno file \"{}\"\n\t{e}",
+            path.display()
+        )),
+    };
+
+    parse_bru(&bru_file)
+}
+
+fn parse_bru(bru_file: &str) -> Vec<BruBlock> {
+    let mut blocks: Vec<BruBlock> = vec![];
+    let mut current_block: Option<BruBlock> = None;
+
+    for line in bru_file.lines() {
+        match current_block.take() {
+            None => {
+                let line = line.trim_end();
+
+                let name = line
+                    .strip_suffix('{')
+                    .or(line.strip_suffix('['))
+                    .map(str::trim);
+
+                if let Some(name) = name {
+                    current_block = Some(BruBlock {
+                        name: name.to_string(),
+                        lines: vec![],
+                    });
+                }
+            }
+            Some(mut block) => {
+                // Blocks are closed by an unindented bracket
+                if line.trim_end() == "}" || line.trim_end() == "]" {
+                    blocks.push(block);
+                    continue;
+                }
+
+                let line = line.strip_prefix("  ").unwrap_or(line);
+                block.lines.push(line.to_string());
+
+                current_block = Some(block);
+            }
+        }
+    }
+
+    blocks
+}
+
+fn find_block<'a>(blocks: &'a [BruBlock], name: &str) -> Option<&'a BruBlock> {
+    blocks.iter().find(|block| block.name == name)
+}
+
+/// Folders are flattened, the collection name contains the name of every parent folder.
+/// Requests placed at the collection root go to a collection named after the Bruno collection
+fn retrieve_collection_name(
+    collection_path: &Path,
+    request_path: &Path,
+    bruno_collection_name: &str,
+) -> String {
+    let mut names: Vec<String> = vec![];
+    let mut current_path = request_path.parent();
+
+    while let Some(path) = current_path {
+        if path == collection_path {
+            break;
+        }
+
+        // The folder name can be overridden in its folder.bru file
+        let folder_bru = path.join("folder.bru");
+
+        let name = match folder_bru.is_file() {
+            true => find_block(&parse_bru_file(&folder_bru), "meta")
+                .and_then(|meta| meta.get_value("name")),
+            false => None,
+        };
+
+        names.push(name.unwrap_or(path.file_name().unwrap().to_str().unwrap().to_string()));
+        current_path = path.parent();
+    }
+
+    let collection_name = match names.is_empty() {
+        true => bruno_collection_name.to_string(),
+        false => {
+            names.reverse();
+            names.join(" ")
+        }
+    };
+
+    collection_name.replace(['/', '\\'], "-").trim().to_string()
+}
+
+fn parse_request(blocks: &[BruBlock], path: &Path) -> Option<Request> {
+    let meta = find_block(blocks, "meta");
+
+    let name = match meta.and_then(|meta| meta.get_value("name")) {
+        Some(name) => name,
+        None => path.file_stem().unwrap().to_str().unwrap().to_string(),
+    };
+
+    let request_type = meta
+        .and_then(|meta| meta.get_value("type"))
+        .unwrap_or(String::from("http"));
+
+    let http_block = blocks
+        .iter()
+        .find(|block| HTTP_METHOD_BLOCKS.contains(&block.name.as_str()));
+
+    let http_block = match http_block {
+        Some(http_block) if request_type == "http" || request_type == "graphql" => http_block,
+        _ => {
+            println!(
+                "\t\tUnsupported request \"{}\" of type \"{}\"",
+                name, request_type
+            );
+            return None;
+        }
+    };
+
+    println!("\t\tFound request \"{}\"", name);
+
+    let mut request = Request {
+        name,
+        ..Default::default()
+    };
+
+    /* METHOD */
+
+    request.method = Method::from(http_block.name.clone());
+
+    /* URL */
+
+    let mut url = http_block.get_value("url").unwrap_or_default();
+
+    // Path params are written as :param in the URL
+    if let Some(path_params) = find_block(blocks, "params:path") {
+        for path_param in path_params.key_values() {
+            let (key, value) = path_param.data;
+            url = url.replace(&format!(":{key}"), &value);
+        }
+    }
+
+    /* QUERY PARAMS */
+
+    // Bruno keeps the query params both in the URL and in their own block
+    match find_block(blocks, "params:query") {
+        None => {}
+        Some(query_params) => {
+            if let Some((url_without_query, _)) = url.split_once('?') {
+                url = url_without_query.to_string();
+            }
+
+            request.params = query_params.key_values();
+        }
+    }
+
+    request.url = url;
+
+    /* HEADERS */
+
+    request.headers = DEFAULT_HEADERS.clone();
+
+    if let Some(headers) = find_block(blocks, "headers") {
+        request.headers.extend(headers.key_values());
+    }
+
+    /* AUTH */
+
+    let auth_mode = http_block.get_value("auth").unwrap_or(String::from("none"));
+
+    request.auth = retrieve_auth(blocks, &auth_mode);
+
+    /* BODY */
+
+    let body_mode = http_block.get_value("body").unwrap_or(String::from("none"));
+
+    request.body = retrieve_body(blocks, &body_mode);
+
+    let has_content_type_header = request
+        .headers
+        .iter()
+        .any(|header| header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+
+    match &request.body {
+        ContentType::NoBody | ContentType::Multipart(_) => {}
+        body if !has_content_type_header => {
+            let content_type = body.to_content_type();
+            request.modify_or_create_header(CONTENT_TYPE.as_str(), &content_type);
+        }
+        _ => {}
+    }
+
+    /* SCRIPTS */
+
+    // Bruno's scripting API differs, scripts are kept as is so they can be adapted
+    request.scripts.pre_request_script =
+        find_block(blocks, "script:pre-request").map(BruBlock::text);
+    request.scripts.post_request_script =
+        find_block(blocks, "script:post-response").map(BruBlock::text);
+
+    Some(request)
+}
+
+fn retrieve_auth(blocks: &[BruBlock], auth_mode: &str) -> Auth {
+    match auth_mode {
+        "none" => Auth::NoAuth,
+        "inherit" => Auth::InheritFromCollection,
+        "basic" => {
+            let basic = find_block(blocks, "auth:basic");
+            let get_field = |field: &str| {
+                basic
+                    .and_then(|basic| basic.get_value(field))
+                    .unwrap_or_default()
+            };
+
+            Auth::BasicAuth(get_field("username"), get_field("password"))
+        }
+        "digest" => {
+            let digest = find_block(blocks, "auth:digest");
+            let get_field = |field: &str| {
+                digest
+                    .and_then(|digest| digest.get_value(field))
+                    .unwrap_or_default()
+            };
+
+            Auth::Digest(get_field("username"), get_field("password"))
+        }
+        "apikey" => {
+            let api_key = find_block(blocks, "auth:apikey");
+            let get_field = |field: &str| {
+                api_key
+                    .and_then(|api_key| api_key.get_value(field))
+                    .unwrap_or_default()
+            };
+
+            let location = match get_field("placement").as_str() {
+                "queryparams" => ApiKeyLocation::Query,
+                _ => ApiKeyLocation::Header,
+            };
+
+            Auth::ApiKey(ApiKey {
+                key: get_field("key"),
+                value: get_field("value"),
+                location,
+            })
+        }
+        "awsv4" => {
+            let awsv4 = find_block(blocks, "auth:awsv4");
+            let get_field = |field: &str| {
+                awsv4
+                    .and_then(|awsv4| awsv4.get_value(field))
+                    .unwrap_or_default()
+            };
+
+            Auth::AwsSigV4(AwsSigV4 {
+                access_key_id: get_field("accessKeyId"),
+                secret_access_key: get_field("secretAccessKey"),
+                session_token: get_field("sessionToken"),
+                region: get_field("region"),
+                service: get_field("service"),
+            })
+        }
+        "bearer" => Auth::BearerToken(
+            find_block(blocks, "auth:bearer")
+                .and_then(|bearer| bearer.get_value("token"))
+                .unwrap_or_default(),
+        ),
+        "oauth2" => {
+            let oauth2 = find_block(blocks, "auth:oauth2");
+            let get_field = |field: &str| {
+                oauth2
+                    .and_then(|oauth2| oauth2.get_value(field))
+                    .unwrap_or_default()
+            };
+
+            let grant_type = match get_field("grant_type").as_str() {
+                "client_credentials" => OAuth2GrantType::ClientCredentials,
+                "password" => OAuth2GrantType::Password,
+                "authorization_code" => OAuth2GrantType::AuthorizationCodePkce,
+                grant_type => {
+                    println!("\t\t\tUnsupported OAuth2 grant type \"{grant_type}\"");
+                    return Auth::NoAuth;
+                }
+            };
+
+            Auth::OAuth2(OAuth2 {
+                grant_type,
+                access_token_url: get_field("access_token_url"),
+                authorization_url: get_field("authorization_url"),
+                redirect_uri: get_field("callback_url"),
+                client_id: get_field("client_id"),
+                client_secret: get_field("client_secret"),
+                scope: get_field("scope"),
+                username: get_field("username"),
+                password: get_field("password"),
+            })
+        }
+        auth_mode => {
+            println!("\t\t\tUnsupported auth mode \"{auth_mode}\"");
+            Auth::NoAuth
+        }
+    }
+}
+
+fn retrieve_body(blocks: &[BruBlock], body_mode: &str) -> ContentType {
+    let text = |name: &str| {
+        find_block(blocks, name)
+            .map(BruBlock::text)
+            .unwrap_or_default()
+    };
+
+    let key_values = |name: &str| {
+        find_block(blocks, name)
+            .map(BruBlock::key_values)
+            .unwrap_or_default()
+    };
+
+    match body_mode {
+        "none" => ContentType::NoBody,
+        "json" => ContentType::Json(text("body:json")),
+        "xml" => ContentType::Xml(text("body:xml")),
+        "text" => ContentType::Raw(text("body:text")),
+        "formUrlEncoded" => ContentType::Form(key_values("body:form-urlencoded")),
+        "multipartForm" => ContentType::Multipart(
+            key_values("body:multipart-form")
+                .into_iter()
+                .map(|mut key_value| {
+                    // A value starting with !! is a file path
+                    if let Some(capture) = FILE_REGEX.captures(&key_value.data.1) {
+                        key_value.data.1 = format!("!!{}", &capture["path"]);
+                    }
+
+                    key_value
+                })
+                .collect(),
+        ),
+        "file" => {
+            let file_path = key_values("body:file")
+                .into_iter()
+                .filter(|key_value| key_value.enabled)
+                .find_map(|key_value| {
+                    FILE_REGEX
+                        .captures(&key_value.data.1)
+                        .map(|capture| capture["path"].to_string())
+                });
+
+            ContentType::File(file_path.unwrap_or_default())
+        }
+        "graphql" => ContentType::GraphQL {
+            query: text("body:graphql"),
+            variables: text("body:graphql:vars"),
+        },
+        body_mode => {
+            println!("\t\t\tUnsupported body mode \"{body_mode}\"");
+            ContentType::NoBody
+        }
+    }
+}
+
+/// Each file of the environments directory becomes an ATAC environment, secret values are left empty
+fn retrieve_environments(environments_path: &Path) -> Vec<Environment> {
+    let mut environments: Vec<Environment> = vec![];
+
+    let entries = match fs::read_dir(environments_path) {
+        Ok(entries) => entries,
+        Err(_) => return environments,
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "bru"))
+        .collect();
+
+    paths.sort();
+
+    for path in paths {
+        let name = path
+            .file_stem()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .replace(['/', '\\'], "-")
+            .trim()
+            .to_string();
+
+        println!("\tFound environment \"{}\"", name);
+
+        let blocks = parse_bru_file(&path);
+
+        let mut values: IndexMap<String, String> = IndexMap::new();
+
+        if let Some(vars) = find_block(&blocks, "vars") {
+            for var in vars.key_values() {
+                if var.enabled {
+                    values.insert(var.data.0, var.data.1);
+                }
+            }
+        }
+
+        if let Some(secret_vars) = find_block(&blocks, "vars:secret") {
+            for secret_var in &secret_vars.lines {
+                let secret_var = secret_var.trim().trim_end_matches(',');
+
+                if !secret_var.is_empty() && !secret_var.starts_with('~') {
+                    values.insert(secret_var.to_string(), String::new());
+                }
+            }
+        }
+
+        environments.push(Environment {
+            path: ARGS.directory.join(format!(".env.{name}")),
+            name,
+            values,
+        });
+    }
+
+    environments
+}
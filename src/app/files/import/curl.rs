@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use regex::Regex;
 use reqwest::header::CONTENT_TYPE;
@@ -46,7 +47,11 @@ impl App<'_> {
 
                 let collection = Collection {
                     name: collection_name.clone(),
+                    headers: vec![],
+                    auth: Auth::NoAuth,
+                    variables: IndexMap::new(),
                     requests: vec![],
+                    folders: vec![],
                     path: ARGS.directory.join(format!(
                         "{}.{}",
                         collection_name.clone(),
@@ -121,7 +126,12 @@ fn parse_request(path: &PathBuf, request_name: String) -> Arc<RwLock<Request>> {
 
     println!("\tRequest name: {}", request_name);
 
-    let parsed_curl = match curl_parser::ParsedRequest::load(&curl_stringed, None::<String>) {
+    // The parser does not know this flag, it is removed and only changes the auth type
+    let digest_regex = Regex::new(r"\s--digest(\s|$)").unwrap();
+    let is_digest = digest_regex.is_match(&curl_stringed);
+    let parsable_curl = digest_regex.replace_all(&curl_stringed, "$1");
+
+    let parsed_curl = match curl_parser::ParsedRequest::load(&parsable_curl, None::<String>) {
         Ok(parsed_curl) => parsed_curl,
         Err(e) => panic_error(format!("Could not parse cURL\n\t{e}")),
     };
@@ -186,10 +196,16 @@ fn parse_request(path: &PathBuf, request_name: String) -> Arc<RwLock<Request>> {
                 Auth::NoAuth
             }
         }
-        Some(capture) => Auth::BasicAuth(
-            capture["username"].to_string(),
-            capture["password"].to_string(),
-        ),
+        Some(capture) => match is_digest {
+            true => Auth::Digest(
+                capture["username"].to_string(),
+                capture["password"].to_string(),
+            ),
+            false => Auth::BasicAuth(
+                capture["username"].to_string(),
+                capture["password"].to_string(),
+            ),
+        },
     };
 
     /* BODY */
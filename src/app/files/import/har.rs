@@ -0,0 +1,313 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use reqwest::header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, COOKIE};
+use reqwest::Url;
+use serde_json::Value;
+
+use crate::app::app::App;
+use crate::app::startup::args::ARGS;
+use crate::panic_error;
+use crate::request::auth::Auth;
+use crate::request::body::ContentType;
+use crate::request::collection::Collection;
+use crate::request::method::Method;
+use crate::request::request::{KeyValue, Request};
+
+impl App<'_> {
+    pub fn import_har_file(&mut self, path_buf: &PathBuf, collection_name: &String) {
+        println!("Parsing HAR file");
+
+        let har_stringed = match fs::read_to_string(path_buf) {
+            Ok(har_stringed) => har_stringed,
+            Err(e) => panic_error(format!("Could not read HAR file\n\t{e}")),
+        };
+
+        let har: Value = match serde_json::from_str(&har_stringed) {
+            Ok(har) => har,
+            Err(e) => panic_error(format!("Could not parse HAR file\n\t{e}")),
+        };
+
+        let entries = match har.pointer("/log/entries").and_then(Value::as_array) {
+            Some(entries) => entries,
+            None => panic_error("No entries found in the HAR file"),
+        };
+
+        println!("Collection name: {}", collection_name);
+
+        let collection_index = match self
+            .collections
+            .iter()
+            .position(|collection| &collection.name == collection_name)
+        {
+            Some(index) => index,
+            None => {
+                println!("Collection does not exist. Creating it...");
+
+                let file_format = self.config.get_preferred_collection_file_format();
+
+                self.collections.push(Collection {
+                    name: collection_name.clone(),
+                    headers: vec![],
+                    auth: Auth::NoAuth,
+                    variables: IndexMap::new(),
+                    requests: vec![],
+                    folders: vec![],
+                    path: ARGS
+                        .directory
+                        .join(format!("{}.{}", collection_name, file_format)),
+                    file_format,
+                });
+
+                self.collections.len() - 1
+            }
+        };
+
+        for entry in entries {
+            let har_request = match entry.get("request") {
+                Some(har_request) => har_request,
+                None => continue,
+            };
+
+            let request = parse_request(har_request);
+
+            self.collections[collection_index]
+                .requests
+                .push(Arc::new(RwLock::new(request)));
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+}
+
+fn parse_request(har_request: &Value) -> Request {
+    let get_str = |pointer: &str| har_request.pointer(pointer).and_then(Value::as_str);
+
+    /* URL */
+
+    let har_url = get_str("/url").unwrap_or_default();
+
+    let mut url = match Url::parse(har_url) {
+        Ok(url) => url,
+        Err(e) => panic_error(format!("Could not parse URL \"{har_url}\"\n\t{e}")),
+    };
+
+    /* QUERY PARAMS */
+
+    // The query string is kept in the query params rather than in the URL
+    let params: Vec<KeyValue> = url
+        .query_pairs()
+        .map(|(key, value)| KeyValue {
+            enabled: true,
+            data: (key.to_string(), value.to_string()),
+        })
+        .collect();
+
+    url.set_query(None);
+
+    /* METHOD */
+
+    let method = get_str("/method").unwrap_or("GET");
+
+    let method = Method::from(method.to_string());
+
+    let name = format!("{} {}", method, url.path());
+
+    println!("\t\tFound request \"{}\"", name);
+
+    /* HEADERS */
+
+    let mut headers: Vec<KeyValue> = vec![];
+    let mut auth = Auth::NoAuth;
+
+    for header in pairs_from_array(har_request.get("headers")) {
+        let (header_name, header_value) = (header.0.to_lowercase(), header.1);
+
+        // HTTP/2 pseudo-headers and computed headers are not sent as is
+        if header_name.starts_with(':') || header_name == CONTENT_LENGTH.as_str() {
+            continue;
+        }
+
+        if header_name == AUTHORIZATION.as_str() {
+            if let Some(parsed_auth) = parse_authorization_header(&header_value) {
+                auth = parsed_auth;
+                continue;
+            }
+        }
+
+        headers.push(KeyValue {
+            enabled: true,
+            data: (header_name, header_value),
+        });
+    }
+
+    /* COOKIES */
+
+    let cookies = pairs_from_array(har_request.get("cookies"));
+    let has_cookie_header = headers
+        .iter()
+        .any(|header| header.data.0 == COOKIE.as_str());
+
+    // Browsers usually already provide the cookie header
+    if !cookies.is_empty() && !has_cookie_header {
+        let cookie_header = cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<String>>()
+            .join("; ");
+
+        headers.push(KeyValue {
+            enabled: true,
+            data: (COOKIE.to_string(), cookie_header),
+        });
+    }
+
+    /* BODY */
+
+    let body = match har_request.get("postData") {
+        None => ContentType::NoBody,
+        Some(post_data) => parse_post_data(post_data),
+    };
+
+    // Multipart boundaries are computed on reqwest's side
+    if let ContentType::Multipart(_) = body {
+        headers.retain(|header| header.data.0 != CONTENT_TYPE.as_str());
+    }
+
+    Request {
+        name,
+        url: url.to_string(),
+        method,
+        params,
+        headers,
+        body,
+        auth,
+        ..Default::default()
+    }
+}
+
+fn parse_post_data(post_data: &Value) -> ContentType {
+    let mime_type = post_data
+        .get("mimeType")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    // Removes the charset or the boundary
+    let mime_type = mime_type.split(';').next().unwrap_or_default().trim();
+
+    let text = post_data
+        .get("text")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    match mime_type {
+        "multipart/form-data" => {
+            let params = post_data
+                .get("params")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+
+            let form_data = params
+                .iter()
+                .map(|param| {
+                    let name = param
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+
+                    // A value starting with !! is a file path
+                    let value = match param.get("fileName").and_then(Value::as_str) {
+                        Some(file_name) => format!("!!{file_name}"),
+                        None => param
+                            .get("value")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default()
+                            .to_string(),
+                    };
+
+                    KeyValue {
+                        enabled: true,
+                        data: (name.to_string(), value),
+                    }
+                })
+                .collect();
+
+            ContentType::Multipart(form_data)
+        }
+        "application/x-www-form-urlencoded" => {
+            let mut form_data: Vec<KeyValue> = pairs_from_array(post_data.get("params"))
+                .into_iter()
+                .map(|(key, value)| KeyValue {
+                    enabled: true,
+                    data: (key, value),
+                })
+                .collect();
+
+            // Some tools only provide the encoded text
+            if form_data.is_empty() {
+                form_data = Url::parse(&format!("http://localhost/?{text}"))
+                    .map(|url| {
+                        url.query_pairs()
+                            .map(|(key, value)| KeyValue {
+                                enabled: true,
+                                data: (key.to_string(), value.to_string()),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+            }
+
+            ContentType::Form(form_data)
+        }
+        "application/json" => ContentType::Json(text),
+        "application/xml" | "text/xml" => ContentType::Xml(text),
+        "text/html" => ContentType::Html(text),
+        "application/javascript" | "text/javascript" => ContentType::Javascript(text),
+        _ if text.is_empty() => ContentType::NoBody,
+        _ => ContentType::Raw(text),
+    }
+}
+
+/// Retrieve the name and value of each object of a HAR array (headers, cookies, params, ...)
+fn pairs_from_array(array: Option<&Value>) -> Vec<(String, String)> {
+    let array = match array.and_then(Value::as_array) {
+        None => return vec![],
+        Some(array) => array,
+    };
+
+    array
+        .iter()
+        .filter_map(|pair| {
+            let name = pair.get("name")?.as_str()?;
+            let value = pair
+                .get("value")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn parse_authorization_header(header_value: &str) -> Option<Auth> {
+    let (scheme, credentials) = header_value.split_once(' ')?;
+
+    match scheme.to_lowercase().as_str() {
+        "bearer" => Some(Auth::BearerToken(credentials.trim().to_string())),
+        "basic" => {
+            let decoded = STANDARD.decode(credentials.trim()).ok()?;
+            let decoded = String::from_utf8(decoded).ok()?;
+            let (username, password) = decoded.split_once(':')?;
+
+            Some(Auth::BasicAuth(username.to_string(), password.to_string()))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,425 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use regex::Regex;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+
+use crate::app::app::App;
+use crate::app::files::environment::save_environment_to_file;
+use crate::app::startup::args::ARGS;
+use crate::panic_error;
+use crate::request::auth::Auth;
+use crate::request::body::ContentType;
+use crate::request::collection::Collection;
+use crate::request::environment::Environment;
+use crate::request::method::Method;
+use crate::request::request::{KeyValue, Request};
+
+lazy_static! {
+    /// e.g. `@baseUrl = https://example.com`
+    static ref VARIABLE_REGEX: Regex = Regex::new(r"^@(?<key>[\w.-]+)\s*=\s*(?<value>.*)$").unwrap();
+    /// e.g. `# @name getUsers` or `// @name getUsers`
+    static ref NAME_REGEX: Regex = Regex::new(r"^(#|//)\s*@name\s+(?<name>.+)$").unwrap();
+    /// e.g. `POST {{baseUrl}}/users HTTP/1.1`, the method is optional
+    static ref REQUEST_LINE_REGEX: Regex =
+        Regex::new(r"^((?<method>[A-Z]+)\s+)?(?<url>\S+)(\s+HTTP/[\d.]+)?$").unwrap();
+    /// e.g. `    &limit=10 HTTP/1.1`
+    static ref QUERY_CONTINUATION_REGEX: Regex =
+        Regex::new(r"^\s*(?<query>[?&]\S*)(\s+HTTP/[\d.]+)?$").unwrap();
+    /// e.g. `Content-Disposition: form-data; name="avatar"; filename="avatar.png"`
+    static ref CONTENT_DISPOSITION_REGEX: Regex =
+        Regex::new(r#"(?i)^content-disposition:\s*form-data;\s*name="(?<name>[^"]*)""#).unwrap();
+}
+
+impl App<'_> {
+    pub fn import_http_file(&mut self, path_buf: &PathBuf, collection_name: &Option<String>) {
+        println!("Parsing HTTP file");
+
+        let http_file = match fs::read_to_string(path_buf) {
+            Ok(http_file) => http_file,
+            Err(e) => panic_error(format!("Could not read HTTP file\n\t{e}")),
+        };
+
+        let collection_name = match collection_name {
+            None => path_buf.file_stem().unwrap().to_str().unwrap().to_string(),
+            Some(collection_name) => collection_name.clone(),
+        };
+
+        println!("Collection name: {}", collection_name);
+
+        let (requests, variables) = parse_http_file(&http_file);
+
+        let collection_index = match self
+            .collections
+            .iter()
+            .position(|collection| collection.name == collection_name)
+        {
+            Some(index) => index,
+            None => {
+                println!("Collection does not exist. Creating it...");
+
+                let file_format = self.config.get_preferred_collection_file_format();
+
+                self.collections.push(Collection {
+                    name: collection_name.clone(),
+                    headers: vec![],
+                    auth: Auth::NoAuth,
+                    variables: IndexMap::new(),
+                    requests: vec![],
+                    folders: vec![],
+                    path: ARGS
+                        .directory
+                        .join(format!("{}.{}", collection_name, file_format)),
+                    file_format,
+                });
+
+                self.collections.len() - 1
+            }
+        };
+
+        self.collections[collection_index].requests.extend(
+            requests
+                .into_iter()
+                .map(|request| Arc::new(RwLock::new(request))),
+        );
+
+        self.save_collection_to_file(collection_index);
+
+        if variables.is_empty() {
+            return;
+        }
+
+        /* VARIABLES */
+
+        // File variables are stored in an environment named after the collection
+        let local_environment = self
+            .environments
+            .iter()
+            .find(|environment| environment.read().name == collection_name)
+            .cloned();
+
+        let local_environment = match local_environment {
+            Some(local_environment) => {
+                println!("Adding variables to environment \"{}\"", collection_name);
+                local_environment
+            }
+            None => {
+                println!("Creating environment \"{}\"", collection_name);
+
+                let environment = Environment {
+                    name: collection_name.clone(),
+                    values: Default::default(),
+                    path: ARGS.directory.join(format!(".env.{collection_name}")),
+                };
+
+                self.environments.push(Arc::new(RwLock::new(environment)));
+                self.environments.last().unwrap().clone()
+            }
+        };
+
+        let mut environment = local_environment.write();
+
+        for (key, value) in variables {
+            environment.values.insert(key, value);
+        }
+
+        save_environment_to_file(&environment);
+    }
+}
+
+/// Parse every request separated by `###` and every `@variable` declaration
+fn parse_http_file(http_file: &str) -> (Vec<Request>, Vec<(String, String)>) {
+    let mut requests: Vec<Request> = vec![];
+    let mut variables: Vec<(String, String)> = vec![];
+
+    let mut block_title: Option<String> = None;
+    let mut block_lines: Vec<&str> = vec![];
+
+    for line in http_file.lines().chain(["###"]) {
+        match line.strip_prefix("###") {
+            None => block_lines.push(line),
+            Some(next_block_title) => {
+                if let Some(request) = parse_block(&block_title, &block_lines, &mut variables) {
+                    requests.push(request);
+                }
+
+                let next_block_title = next_block_title.trim();
+
+                block_title = match next_block_title.is_empty() {
+                    true => None,
+                    false => Some(next_block_title.to_string()),
+                };
+                block_lines.clear();
+            }
+        }
+    }
+
+    (requests, variables)
+}
+
+fn parse_block(
+    block_title: &Option<String>,
+    lines: &[&str],
+    variables: &mut Vec<(String, String)>,
+) -> Option<Request> {
+    let mut name = block_title.clone();
+    let mut lines = lines.iter().map(|line| line.trim_end()).peekable();
+
+    /* REQUEST LINE */
+
+    let mut request_line: Option<&str> = None;
+
+    for line in lines.by_ref() {
+        let trimmed_line = line.trim();
+
+        if let Some(capture) = VARIABLE_REGEX.captures(trimmed_line) {
+            variables.push((
+                capture["key"].to_string(),
+                capture["value"].trim().to_string(),
+            ));
+        } else if let Some(capture) = NAME_REGEX.captures(trimmed_line) {
+            name = Some(capture["name"].trim().to_string());
+        } else if trimmed_line.is_empty()
+            || trimmed_line.starts_with('#')
+            || trimmed_line.starts_with("//")
+        {
+            continue;
+        } else {
+            request_line = Some(trimmed_line);
+            break;
+        }
+    }
+
+    let capture = REQUEST_LINE_REGEX.captures(request_line?)?;
+
+    let method = capture
+        .name("method")
+        .map_or("GET", |method| method.as_str());
+    let method = Method::from(method.to_string());
+
+    let mut url = capture["url"].to_string();
+
+    // The query can be split on the following lines
+    while let Some(line) = lines.next_if(|line| QUERY_CONTINUATION_REGEX.is_match(line)) {
+        let capture = QUERY_CONTINUATION_REGEX.captures(line).unwrap();
+        url.push_str(&capture["query"]);
+    }
+
+    let name = name.unwrap_or(format!("{method} {url}"));
+
+    println!("\t\tFound request \"{}\"", name);
+
+    /* QUERY PARAMS */
+
+    // Params are kept as is, as they may contain variables
+    let (url, params) = match url.split_once('?') {
+        None => (url.clone(), vec![]),
+        Some((url, query)) => (url.to_string(), parse_key_values(query, '&')),
+    };
+
+    /* HEADERS */
+
+    let mut headers: Vec<KeyValue> = vec![];
+    let mut auth = Auth::NoAuth;
+
+    for line in lines.by_ref() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            break;
+        }
+
+        if line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+
+        let (header_name, header_value) = match line.split_once(':') {
+            None => continue,
+            Some((header_name, header_value)) => (header_name.trim(), header_value.trim()),
+        };
+
+        if header_name.eq_ignore_ascii_case(AUTHORIZATION.as_str()) {
+            if let Some(parsed_auth) = parse_authorization_header(header_value) {
+                auth = parsed_auth;
+                continue;
+            }
+        }
+
+        headers.push(KeyValue {
+            enabled: true,
+            data: (header_name.to_string(), header_value.to_string()),
+        });
+    }
+
+    /* BODY */
+
+    let body_lines: Vec<&str> = lines.collect();
+    let body = body_lines.join("\n").trim().to_string();
+
+    let content_type = headers
+        .iter()
+        .find(|header| header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()))
+        .map(|header| header.data.1.clone())
+        .unwrap_or_default();
+
+    let body = parse_body(&body, &content_type);
+
+    // Multipart boundaries are computed on reqwest's side
+    if let ContentType::Multipart(_) = body {
+        headers.retain(|header| !header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+    }
+
+    Some(Request {
+        name,
+        url,
+        method,
+        params,
+        headers,
+        body,
+        auth,
+        ..Default::default()
+    })
+}
+
+fn parse_body(body: &str, content_type: &str) -> ContentType {
+    if body.is_empty() {
+        return ContentType::NoBody;
+    }
+
+    // e.g. `< ./body.json`
+    if let Some(file_path) = body.strip_prefix('<') {
+        if !file_path.contains('\n') {
+            return ContentType::File(file_path.trim().to_string());
+        }
+    }
+
+    let mime_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    match mime_type.as_str() {
+        "multipart/form-data" => match retrieve_boundary(content_type) {
+            None => ContentType::Raw(body.to_string()),
+            Some(boundary) => ContentType::Multipart(parse_multipart_body(body, &boundary)),
+        },
+        "application/x-www-form-urlencoded" => {
+            // The form can be split on multiple lines starting with &
+            let body = body.lines().map(str::trim).collect::<String>();
+            ContentType::Form(parse_key_values(&body, '&'))
+        }
+        "application/json" => ContentType::Json(body.to_string()),
+        "application/xml" | "text/xml" => ContentType::Xml(body.to_string()),
+        "text/html" | "application/html" => ContentType::Html(body.to_string()),
+        "application/javascript" | "text/javascript" => ContentType::Javascript(body.to_string()),
+        _ => ContentType::Raw(body.to_string()),
+    }
+}
+
+fn retrieve_boundary(content_type: &str) -> Option<String> {
+    content_type.split(';').find_map(|part| {
+        part.trim()
+            .strip_prefix("boundary=")
+            .map(|boundary| boundary.trim_matches('"').to_string())
+    })
+}
+
+/// Parts containing a file reference (`< ./file.png`) become file values (`!!./file.png`)
+fn parse_multipart_body(body: &str, boundary: &str) -> Vec<KeyValue> {
+    let delimiter = format!("--{boundary}");
+    let mut form_data: Vec<KeyValue> = vec![];
+
+    for part in body.split(&delimiter) {
+        let part = part.trim_start_matches(['\r', '\n']);
+
+        // End delimiter
+        if part.starts_with("--") || part.trim().is_empty() {
+            continue;
+        }
+
+        let (part_headers, part_content) = match part.split_once("\n\n") {
+            None => (part, ""),
+            Some((part_headers, part_content)) => (part_headers, part_content.trim()),
+        };
+
+        let name = part_headers
+            .lines()
+            .find_map(|line| CONTENT_DISPOSITION_REGEX.captures(line.trim()))
+            .map(|capture| capture["name"].to_string());
+
+        let name = match name {
+            None => continue,
+            Some(name) => name,
+        };
+
+        let value = match part_content.strip_prefix('<') {
+            Some(file_path) => format!("!!{}", file_path.trim()),
+            None => part_content.to_string(),
+        };
+
+        form_data.push(KeyValue {
+            enabled: true,
+            data: (name, value),
+        });
+    }
+
+    form_data
+}
+
+fn parse_key_values(input: &str, separator: char) -> Vec<KeyValue> {
+    input
+        .split(separator)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+
+            KeyValue {
+                enabled: true,
+                data: (key.to_string(), value.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Handles `Basic user:password`, `Basic user password`, `Basic base64`, `Digest user password` and `Bearer token`
+fn parse_authorization_header(header_value: &str) -> Option<Auth> {
+    let (scheme, credentials) = header_value.split_once(' ')?;
+    let credentials = credentials.trim();
+
+    match scheme.to_lowercase().as_str() {
+        "bearer" => Some(Auth::BearerToken(credentials.to_string())),
+        "basic" => {
+            let credentials = match credentials.contains([':', ' ']) {
+                true => credentials.to_string(),
+                false => String::from_utf8(STANDARD.decode(credentials).ok()?).ok()?,
+            };
+
+            let (username, password) = credentials
+                .split_once(':')
+                .or(credentials.split_once(' '))?;
+
+            Some(Auth::BasicAuth(
+                username.to_string(),
+                password.trim().to_string(),
+            ))
+        }
+        // A header already answering a challenge holds `key=value` pairs instead
+        "digest" if !credentials.contains('=') => {
+            let (username, password) = credentials.split_once(' ')?;
+
+            Some(Auth::Digest(
+                username.to_string(),
+                password.trim().to_string(),
+            ))
+        }
+        _ => None,
+    }
+}
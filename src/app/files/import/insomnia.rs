@@ -0,0 +1,532 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use regex::Regex;
+use reqwest::header::CONTENT_TYPE;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::app::app::App;
+use crate::app::files::environment::save_environment_to_file;
+use crate::app::startup::args::ARGS;
+use crate::panic_error;
+use crate::request::auth::{ApiKey, ApiKeyLocation, Auth, AwsSigV4, OAuth1};
+use crate::request::body::ContentType;
+use crate::request::collection::Collection;
+use crate::request::environment::Environment;
+use crate::request::method::Method;
+use crate::request::request::{KeyValue, Request, DEFAULT_HEADERS};
+
+lazy_static! {
+    /// Matches Insomnia variables, e.g. `{{ _.baseUrl }}` or `{{baseUrl}}`
+    static ref INSOMNIA_VARIABLE_REGEX: Regex =
+        Regex::new(r"\{\{\s*(?:_\.)?(?<key>[\w.-]+)\s*}}").unwrap();
+}
+
+#[derive(Deserialize)]
+struct InsomniaExport {
+    __export_format: u8,
+    resources: Vec<Resource>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "_type", rename_all = "snake_case")]
+enum Resource {
+    Workspace {
+        #[serde(rename = "_id")]
+        id: String,
+        name: String,
+    },
+    RequestGroup {
+        #[serde(rename = "_id")]
+        id: String,
+        #[serde(rename = "parentId")]
+        parent_id: String,
+        name: String,
+    },
+    Request(Box<InsomniaRequest>),
+    Environment {
+        #[serde(rename = "_id")]
+        id: String,
+        #[serde(rename = "parentId")]
+        parent_id: String,
+        name: String,
+        #[serde(default)]
+        data: Map<String, Value>,
+    },
+    /// Cookie jars, API specs, unit tests, ...
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InsomniaRequest {
+    parent_id: String,
+    name: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    method: String,
+    #[serde(default)]
+    meta_sort_key: f64,
+    #[serde(default)]
+    parameters: Vec<Pair>,
+    #[serde(default)]
+    headers: Vec<Pair>,
+    #[serde(default)]
+    body: InsomniaBody,
+    #[serde(default)]
+    authentication: Map<String, Value>,
+    setting_follow_redirects: Option<String>,
+    setting_store_cookies: Option<bool>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InsomniaBody {
+    mime_type: Option<String>,
+    text: Option<String>,
+    file_name: Option<String>,
+    #[serde(default)]
+    params: Vec<Pair>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Pair {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    value: String,
+    #[serde(default)]
+    disabled: bool,
+    #[serde(rename = "type")]
+    pair_type: Option<String>,
+    file_name: Option<String>,
+}
+
+impl App<'_> {
+    pub fn import_insomnia_export(&mut self, path_buf: &PathBuf) {
+        println!("Parsing Insomnia export");
+
+        let insomnia_stringed = match fs::read_to_string(path_buf) {
+            Ok(insomnia_stringed) => insomnia_stringed,
+            Err(e) => panic_error(format!("Could not read Insomnia file\n\t{e}")),
+        };
+
+        let insomnia_export: InsomniaExport = match serde_json::from_str(&insomnia_stringed) {
+            Ok(insomnia_export) => insomnia_export,
+            Err(e) => panic_error(format!("Could not parse Insomnia export\n\t{e}")),
+        };
+
+        if insomnia_export.__export_format != 4 {
+            panic_error(format!(
+                "Only Insomnia v4 exports are supported, found v{}",
+                insomnia_export.__export_format
+            ));
+        }
+
+        // Workspaces and request groups, by ID
+        let mut parents: HashMap<&str, (&str, Option<&str>)> = HashMap::new();
+        let mut requests: Vec<&InsomniaRequest> = vec![];
+
+        for resource in &insomnia_export.resources {
+            match resource {
+                Resource::Workspace { id, name } => {
+                    println!("Workspace name: {}", name);
+                    parents.insert(id, (name, None));
+                }
+                Resource::RequestGroup {
+                    id,
+                    parent_id,
+                    name,
+                } => {
+                    parents.insert(id, (name, Some(parent_id)));
+                }
+                Resource::Request(request) => requests.push(request),
+                Resource::Environment { .. } | Resource::Unsupported => {}
+            }
+        }
+
+        // Insomnia sorts the requests by their sort key
+        requests.sort_by(|a, b| a.meta_sort_key.total_cmp(&b.meta_sort_key));
+
+        /* COLLECTIONS */
+
+        let file_format = self.config.get_preferred_collection_file_format();
+
+        let mut collections: Vec<Collection> = vec![];
+
+        for insomnia_request in requests {
+            let collection_name = retrieve_collection_name(&parents, &insomnia_request.parent_id);
+
+            let collection = match collections
+                .iter_mut()
+                .find(|collection| collection.name == collection_name)
+            {
+                Some(collection) => collection,
+                None => {
+                    println!("\tFound collection \"{}\"", collection_name);
+
+                    collections.push(Collection {
+                        name: collection_name.clone(),
+                        headers: vec![],
+                        auth: Auth::NoAuth,
+                        variables: IndexMap::new(),
+                        requests: vec![],
+                        folders: vec![],
+                        path: ARGS
+                            .directory
+                            .join(format!("{}.{}", collection_name, file_format)),
+                        file_format,
+                    });
+
+                    collections.last_mut().unwrap()
+                }
+            };
+
+            let request = parse_request(insomnia_request);
+
+            collection.requests.push(Arc::new(RwLock::new(request)));
+        }
+
+        for collection in &collections {
+            for existing_collection in &self.collections {
+                if existing_collection.name == collection.name {
+                    panic_error(format!("Collection \"{}\" already exists", collection.name));
+                }
+            }
+        }
+
+        /* ENVIRONMENTS */
+
+        let environments = retrieve_environments(&insomnia_export.resources, &parents);
+
+        for environment in &environments {
+            for existing_environment in &self.environments {
+                if existing_environment.read().name == environment.name {
+                    panic_error(format!(
+                        "Environment \"{}\" already exists",
+                        environment.name
+                    ));
+                }
+            }
+        }
+
+        let first_new_collection_index = self.collections.len();
+
+        self.collections.extend(collections);
+
+        for collection_index in first_new_collection_index..self.collections.len() {
+            self.save_collection_to_file(collection_index);
+        }
+
+        for environment in environments {
+            save_environment_to_file(&environment);
+            self.environments.push(Arc::new(RwLock::new(environment)));
+        }
+    }
+}
+
+/// Request groups are flattened, the collection name contains the name of every parent group.
+/// Requests placed at the workspace root go to a collection named after the workspace
+fn retrieve_collection_name(
+    parents: &HashMap<&str, (&str, Option<&str>)>,
+    parent_id: &str,
+) -> String {
+    let mut names: Vec<&str> = vec![];
+    let mut workspace_name = "Insomnia";
+    let mut current_id = Some(parent_id);
+
+    while let Some(id) = current_id {
+        match parents.get(id) {
+            None => break,
+            // Workspace
+            Some((name, None)) => {
+                workspace_name = name;
+                break;
+            }
+            // Request group
+            Some((name, Some(parent_id))) => {
+                names.push(name);
+                current_id = Some(parent_id);
+            }
+        }
+    }
+
+    let collection_name = match names.is_empty() {
+        true => workspace_name.to_string(),
+        false => {
+            names.reverse();
+            names.join(" ")
+        }
+    };
+
+    collection_name.replace(['/', '\\'], "-").trim().to_string()
+}
+
+fn parse_request(insomnia_request: &InsomniaRequest) -> Request {
+    println!("\t\tFound request \"{}\"", insomnia_request.name);
+
+    let mut request = Request {
+        name: insomnia_request.name.clone(),
+        url: convert_variables(&insomnia_request.url),
+        ..Default::default()
+    };
+
+    /* METHOD */
+
+    request.method = Method::from(insomnia_request.method.clone());
+
+    /* QUERY PARAMS */
+
+    request.params = insomnia_request
+        .parameters
+        .iter()
+        .map(pair_to_key_value)
+        .collect();
+
+    /* HEADERS */
+
+    request.headers = DEFAULT_HEADERS.clone();
+    request
+        .headers
+        .extend(insomnia_request.headers.iter().map(pair_to_key_value));
+
+    /* AUTH */
+
+    request.auth = retrieve_auth(&insomnia_request.authentication);
+
+    /* BODY */
+
+    request.body = retrieve_body(&insomnia_request.body);
+
+    let has_content_type_header = request
+        .headers
+        .iter()
+        .any(|header| header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+
+    match &request.body {
+        ContentType::NoBody | ContentType::Multipart(_) => {}
+        body if !has_content_type_header => {
+            let content_type = body.to_content_type();
+            request.modify_or_create_header(CONTENT_TYPE.as_str(), &content_type);
+        }
+        _ => {}
+    }
+
+    /* SETTINGS */
+
+    if insomnia_request.setting_follow_redirects.as_deref() == Some("off") {
+        request.settings.allow_redirects = false;
+    }
+
+    if insomnia_request.setting_store_cookies == Some(false) {
+        request.settings.store_received_cookies = false;
+    }
+
+    request
+}
+
+fn pair_to_key_value(pair: &Pair) -> KeyValue {
+    KeyValue {
+        enabled: !pair.disabled,
+        data: (
+            convert_variables(&pair.name),
+            convert_variables(&pair.value),
+        ),
+    }
+}
+
+fn retrieve_auth(authentication: &Map<String, Value>) -> Auth {
+    let get_field = |field: &str| {
+        authentication
+            .get(field)
+            .and_then(Value::as_str)
+            .map(convert_variables)
+            .unwrap_or_default()
+    };
+
+    if authentication.get("disabled") == Some(&Value::Bool(true)) {
+        return Auth::NoAuth;
+    }
+
+    match authentication.get("type").and_then(Value::as_str) {
+        None | Some("none") => Auth::NoAuth,
+        Some("basic") => Auth::BasicAuth(get_field("username"), get_field("password")),
+        Some("bearer") => Auth::BearerToken(get_field("token")),
+        Some("digest") => Auth::Digest(get_field("username"), get_field("password")),
+        Some("iam") => Auth::AwsSigV4(AwsSigV4 {
+            access_key_id: get_field("accessKeyId"),
+            secret_access_key: get_field("secretAccessKey"),
+            session_token: get_field("sessionToken"),
+            region: get_field("region"),
+            service: get_field("service"),
+        }),
+        Some("oauth1") if matches!(get_field("signatureMethod").as_str(), "" | "HMAC-SHA1") => {
+            Auth::OAuth1(OAuth1 {
+                consumer_key: get_field("consumerKey"),
+                consumer_secret: get_field("consumerSecret"),
+                token: get_field("tokenKey"),
+                token_secret: get_field("tokenSecret"),
+            })
+        }
+        Some("apikey") => Auth::ApiKey(ApiKey {
+            key: get_field("key"),
+            value: get_field("value"),
+            location: match get_field("addTo").as_str() {
+                "queryParams" => ApiKeyLocation::Query,
+                _ => ApiKeyLocation::Header,
+            },
+        }),
+        Some(auth_type) => {
+            println!("\t\t\tUnsupported auth type \"{auth_type}\"");
+            Auth::NoAuth
+        }
+    }
+}
+
+fn retrieve_body(body: &InsomniaBody) -> ContentType {
+    let mime_type = match &body.mime_type {
+        None => return ContentType::NoBody,
+        Some(mime_type) => mime_type.as_str(),
+    };
+
+    let text = convert_variables(body.text.as_deref().unwrap_or_default());
+
+    match mime_type {
+        "multipart/form-data" => ContentType::Multipart(
+            body.params
+                .iter()
+                .map(|param| match param.pair_type.as_deref() {
+                    // A value starting with !! is a file path
+                    Some("file") => KeyValue {
+                        enabled: !param.disabled,
+                        data: (
+                            convert_variables(&param.name),
+                            format!("!!{}", param.file_name.clone().unwrap_or_default()),
+                        ),
+                    },
+                    _ => pair_to_key_value(param),
+                })
+                .collect(),
+        ),
+        "application/x-www-form-urlencoded" => {
+            ContentType::Form(body.params.iter().map(pair_to_key_value).collect())
+        }
+        "application/octet-stream" => ContentType::File(body.file_name.clone().unwrap_or_default()),
+        // GraphQL bodies are stored as JSON
+        "application/json" | "application/graphql" => ContentType::Json(text),
+        "application/xml" | "text/xml" => ContentType::Xml(text),
+        "text/html" => ContentType::Html(text),
+        "application/javascript" | "text/javascript" => ContentType::Javascript(text),
+        _ => ContentType::Raw(text),
+    }
+}
+
+/// Each sub environment becomes an ATAC environment, containing the base environment values overridden by its own.
+/// A workspace without sub environments gets a single environment named after it
+fn retrieve_environments(
+    resources: &[Resource],
+    parents: &HashMap<&str, (&str, Option<&str>)>,
+) -> Vec<Environment> {
+    let mut base_environments: IndexMap<&str, (&str, &Map<String, Value>)> = IndexMap::new();
+    let mut sub_environments: Vec<(&str, &str, &Map<String, Value>)> = vec![];
+
+    for resource in resources {
+        if let Resource::Environment {
+            id,
+            parent_id,
+            name,
+            data,
+        } = resource
+        {
+            match parents.get(parent_id.as_str()) {
+                // Base environments are directly attached to their workspace
+                Some((workspace_name, None)) => {
+                    base_environments.insert(id, (workspace_name, data));
+                }
+                // Request groups environments are not supported
+                Some((_, Some(_))) => {}
+                None => sub_environments.push((parent_id, name, data)),
+            }
+        }
+    }
+
+    let mut environments: Vec<Environment> = vec![];
+
+    for (base_id, (workspace_name, base_data)) in &base_environments {
+        let workspace_sub_environments: Vec<&(&str, &str, &Map<String, Value>)> = sub_environments
+            .iter()
+            .filter(|(parent_id, _, _)| parent_id == base_id)
+            .collect();
+
+        if workspace_sub_environments.is_empty() {
+            if !base_data.is_empty() {
+                environments.push(create_environment(workspace_name, &[base_data]));
+            }
+            continue;
+        }
+
+        for (_, name, data) in workspace_sub_environments {
+            environments.push(create_environment(name, &[base_data, data]));
+        }
+    }
+
+    environments
+}
+
+fn create_environment(name: &str, data_layers: &[&Map<String, Value>]) -> Environment {
+    let name = name.replace(['/', '\\'], "-").trim().to_string();
+
+    println!("\tFound environment \"{}\"", name);
+
+    let mut values: IndexMap<String, String> = IndexMap::new();
+
+    for data in data_layers {
+        flatten_environment_data(data, "", &mut values);
+    }
+
+    Environment {
+        path: ARGS.directory.join(format!(".env.{name}")),
+        name,
+        values,
+    }
+}
+
+/// Nested objects are flattened with dotted keys, as Insomnia allows `{{ _.object.key }}`
+fn flatten_environment_data(
+    data: &Map<String, Value>,
+    prefix: &str,
+    values: &mut IndexMap<String, String>,
+) {
+    for (key, value) in data {
+        let key = format!("{prefix}{key}");
+
+        match value {
+            Value::Object(object) => flatten_environment_data(object, &format!("{key}."), values),
+            Value::String(string) => {
+                values.insert(key, convert_variables(string));
+            }
+            Value::Null => {
+                values.insert(key, String::new());
+            }
+            value => {
+                values.insert(key, value.to_string());
+            }
+        }
+    }
+}
+
+/// Convert Insomnia variables (`{{ _.key }}`) to ATAC ones (`{{key}}`)
+fn convert_variables(input: &str) -> String {
+    INSOMNIA_VARIABLE_REGEX
+        .replace_all(input, "{{$key}}")
+        .to_string()
+}
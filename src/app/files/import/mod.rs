@@ -1,2 +1,7 @@
+pub(super) mod bruno;
 pub(super) mod curl;
+pub(super) mod har;
+pub(super) mod http_file;
+pub(super) mod insomnia;
+pub(super) mod openapi;
 pub(super) mod postman;
@@ -0,0 +1,698 @@
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use serde_json::{json, Map, Value};
+
+use crate::app::app::App;
+use crate::app::startup::args::ARGS;
+use crate::panic_error;
+use crate::request::auth::{ApiKey, ApiKeyLocation, Auth};
+use crate::request::body::ContentType;
+use crate::request::collection::Collection;
+use crate::request::method::Method;
+use crate::request::request::{KeyValue, Request, DEFAULT_HEADERS};
+
+const HTTP_METHODS: [&str; 7] = ["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// Avoids infinite recursion on self-referencing schemas
+const MAX_SCHEMA_DEPTH: u8 = 8;
+
+impl App<'_> {
+    pub fn import_openapi_spec(&mut self, path_buf: &PathBuf) {
+        println!("Parsing OpenAPI specification");
+
+        let spec = parse_spec_file(path_buf);
+
+        let is_swagger_v2 = is_swagger_v2(&spec);
+
+        let spec_title = spec
+            .pointer("/info/title")
+            .and_then(Value::as_str)
+            .unwrap_or("OpenAPI")
+            .trim()
+            .to_string();
+
+        println!("Specification title: {}", spec_title);
+
+        if let Some(base_url) = retrieve_base_url(&spec, is_swagger_v2) {
+            println!("Base URL: {base_url} (add it to an environment as \"baseUrl\")");
+        }
+
+        let paths = match spec.get("paths").and_then(Value::as_object) {
+            Some(paths) => paths,
+            None => panic_error("No paths found in the specification"),
+        };
+
+        let file_format = self.config.get_preferred_collection_file_format();
+
+        let mut collections: Vec<Collection> = vec![];
+
+        for (path, path_item) in paths {
+            let path_item = resolve_ref(&spec, path_item);
+
+            for method in HTTP_METHODS {
+                let operation = match path_item.get(method) {
+                    Some(operation) => operation,
+                    None => continue,
+                };
+
+                // Operations are grouped by their first tag
+                let collection_name = operation
+                    .get("tags")
+                    .and_then(|tags| tags.get(0))
+                    .and_then(Value::as_str)
+                    .map(|tag| tag.replace(['/', '\\'], "-").trim().to_string())
+                    .unwrap_or(spec_title.clone());
+
+                let collection = match collections
+                    .iter_mut()
+                    .find(|collection| collection.name == collection_name)
+                {
+                    Some(collection) => collection,
+                    None => {
+                        println!("\tFound collection \"{}\"", collection_name);
+
+                        collections.push(Collection {
+                            name: collection_name.clone(),
+                            headers: vec![],
+                            auth: Auth::NoAuth,
+                            variables: IndexMap::new(),
+                            requests: vec![],
+                            folders: vec![],
+                            path: ARGS
+                                .directory
+                                .join(format!("{}.{}", collection_name, file_format)),
+                            file_format,
+                        });
+
+                        collections.last_mut().unwrap()
+                    }
+                };
+
+                let request =
+                    parse_operation(&spec, is_swagger_v2, path, method, path_item, operation);
+
+                collection.requests.push(Arc::new(RwLock::new(request)));
+            }
+        }
+
+        for collection in &collections {
+            for existing_collection in &self.collections {
+                if existing_collection.name == collection.name {
+                    panic_error(format!("Collection \"{}\" already exists", collection.name));
+                }
+            }
+        }
+
+        let first_new_collection_index = self.collections.len();
+
+        self.collections.extend(collections);
+
+        for collection_index in first_new_collection_index..self.collections.len() {
+            self.save_collection_to_file(collection_index);
+        }
+    }
+}
+
+fn parse_spec_file(path_buf: &PathBuf) -> Value {
+    let spec_stringed = match fs::read_to_string(path_buf) {
+        Ok(spec_stringed) => spec_stringed,
+        Err(e) => panic_error(format!("Could not read OpenAPI file\n\t{e}")),
+    };
+
+    let is_yaml = matches!(
+        path_buf
+            .extension()
+            .and_then(|extension| extension.to_str()),
+        Some("yaml") | Some("yml")
+    );
+
+    let spec: Result<Value, String> = match is_yaml {
+        true => serde_yaml::from_str(&spec_stringed).map_err(|e| e.to_string()),
+        false => serde_json::from_str(&spec_stringed).map_err(|e| e.to_string()),
+    };
+
+    match spec {
+        Ok(spec) => spec,
+        Err(e) => panic_error(format!("Could not parse OpenAPI file\n\t{e}")),
+    }
+}
+
+fn is_swagger_v2(spec: &Value) -> bool {
+    match (spec.get("swagger"), spec.get("openapi")) {
+        (Some(Value::String(version)), _) if version.starts_with('2') => true,
+        (_, Some(Value::String(version))) if version.starts_with('3') => false,
+        _ => panic_error("Only OpenAPI 3 and Swagger 2 specifications are supported"),
+    }
+}
+
+fn retrieve_base_url(spec: &Value, is_swagger_v2: bool) -> Option<String> {
+    if is_swagger_v2 {
+        let host = spec.get("host")?.as_str()?;
+        let base_path = spec.get("basePath").and_then(Value::as_str).unwrap_or("");
+        let scheme = spec
+            .pointer("/schemes/0")
+            .and_then(Value::as_str)
+            .unwrap_or("https");
+
+        Some(format!("{scheme}://{host}{base_path}"))
+    } else {
+        let url = spec.pointer("/servers/0/url")?.as_str()?;
+
+        Some(url.trim_end_matches('/').to_string())
+    }
+}
+
+/// Follow local references like `#/components/schemas/Pet`
+fn resolve_ref<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    let mut value = value;
+
+    // Limits the number of chained references
+    for _ in 0..MAX_SCHEMA_DEPTH {
+        let reference = match value.get("$ref").and_then(Value::as_str) {
+            Some(reference) => reference,
+            None => break,
+        };
+
+        match reference
+            .strip_prefix('#')
+            .and_then(|pointer| spec.pointer(pointer))
+        {
+            Some(resolved_value) => value = resolved_value,
+            None => break,
+        }
+    }
+
+    value
+}
+
+fn parse_operation(
+    spec: &Value,
+    is_swagger_v2: bool,
+    path: &str,
+    method: &str,
+    path_item: &Value,
+    operation: &Value,
+) -> Request {
+    let name = operation
+        .get("summary")
+        .or(operation.get("operationId"))
+        .and_then(Value::as_str)
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or(format!("{} {path}", method.to_uppercase()));
+
+    println!("\t\tFound request \"{}\"", name);
+
+    let mut request = Request {
+        name,
+        // Path parameters use the same syntax as environment variables once doubled
+        url: format!(
+            "{{{{baseUrl}}}}{}",
+            path.replace('{', "{{").replace('}', "}}")
+        ),
+        method: Method::from_str(&method.to_uppercase()).unwrap(),
+        headers: DEFAULT_HEADERS.clone(),
+        ..Default::default()
+    };
+
+    /* PARAMETERS */
+
+    // Operation parameters override the path ones
+    let mut parameters: Vec<&Value> = vec![];
+
+    for parameters_holder in [operation, path_item] {
+        let holder_parameters = match parameters_holder
+            .get("parameters")
+            .and_then(Value::as_array)
+        {
+            Some(holder_parameters) => holder_parameters,
+            None => continue,
+        };
+
+        for parameter in holder_parameters {
+            let parameter = resolve_ref(spec, parameter);
+
+            let is_overridden = parameters.iter().any(|existing_parameter| {
+                existing_parameter.get("name") == parameter.get("name")
+                    && existing_parameter.get("in") == parameter.get("in")
+            });
+
+            if !is_overridden {
+                parameters.push(parameter);
+            }
+        }
+    }
+
+    let mut form_parameters: Vec<&Value> = vec![];
+
+    for parameter in parameters {
+        let parameter_name = match parameter.get("name").and_then(Value::as_str) {
+            Some(parameter_name) => parameter_name.to_string(),
+            None => continue,
+        };
+
+        let is_required = parameter
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let key_value = KeyValue {
+            enabled: is_required,
+            data: (parameter_name.clone(), format!("{{{{{parameter_name}}}}}")),
+        };
+
+        match parameter.get("in").and_then(Value::as_str) {
+            Some("query") => request.params.push(key_value),
+            Some("header") => request.headers.push(key_value),
+            // Swagger 2 body
+            Some("body") => {
+                if let Some(schema) = parameter.get("schema") {
+                    let example = generate_example(spec, schema, 0);
+                    let body = serde_json::to_string_pretty(&example).unwrap();
+
+                    request.body = ContentType::Json(body);
+                }
+            }
+            // Swagger 2 form
+            Some("formData") => form_parameters.push(parameter),
+            _ => {}
+        }
+    }
+
+    /* BODY */
+
+    if is_swagger_v2 {
+        if !form_parameters.is_empty() {
+            let has_file = form_parameters
+                .iter()
+                .any(|parameter| parameter.get("type").and_then(Value::as_str) == Some("file"));
+
+            let form: Vec<KeyValue> = form_parameters
+                .iter()
+                .filter_map(|parameter| {
+                    let parameter_name = parameter.get("name")?.as_str()?;
+                    let is_file = parameter.get("type").and_then(Value::as_str) == Some("file");
+
+                    Some(form_key_value(parameter_name, is_file))
+                })
+                .collect();
+
+            request.body = match has_file {
+                true => ContentType::Multipart(form),
+                false => ContentType::Form(form),
+            };
+        }
+    } else if let Some(request_body) = operation.get("requestBody") {
+        let request_body = resolve_ref(spec, request_body);
+
+        if let Some(body) = retrieve_body(spec, request_body) {
+            request.body = body;
+        }
+    }
+
+    match &request.body {
+        ContentType::NoBody | ContentType::Multipart(_) => {}
+        body_type => {
+            let content_type = body_type.to_content_type();
+            request.modify_or_create_header("content-type", &content_type);
+        }
+    }
+
+    /* AUTH */
+
+    let security = operation.get("security").or(spec.get("security"));
+
+    if let Some(security) = security {
+        apply_security(spec, is_swagger_v2, security, &mut request);
+    }
+
+    request
+}
+
+fn retrieve_body(spec: &Value, request_body: &Value) -> Option<ContentType> {
+    let content = request_body.get("content")?.as_object()?;
+
+    let find_media_type = |predicate: fn(&str) -> bool| {
+        content
+            .iter()
+            .find(|(media_type, _)| predicate(media_type))
+            .map(|(_, media_type_object)| media_type_object)
+    };
+
+    if let Some(media_type_object) = find_media_type(|media_type| media_type.contains("json")) {
+        let example = retrieve_media_type_example(spec, media_type_object);
+        let body = serde_json::to_string_pretty(&example).unwrap();
+
+        return Some(ContentType::Json(body));
+    }
+
+    for form_media_type in ["application/x-www-form-urlencoded", "multipart/form-data"] {
+        if let Some(media_type_object) = content.get(form_media_type) {
+            let schema = resolve_ref(spec, media_type_object.get("schema")?);
+            let properties = schema.get("properties")?.as_object()?;
+
+            let form = properties
+                .iter()
+                .map(|(property_name, property)| {
+                    let property = resolve_ref(spec, property);
+                    let is_file = property.get("format").and_then(Value::as_str) == Some("binary");
+
+                    form_key_value(property_name, is_file)
+                })
+                .collect();
+
+            return match form_media_type {
+                "multipart/form-data" => Some(ContentType::Multipart(form)),
+                _ => Some(ContentType::Form(form)),
+            };
+        }
+    }
+
+    if let Some(media_type_object) = find_media_type(|media_type| media_type.contains("xml")) {
+        let example = media_type_object.get("example").and_then(Value::as_str);
+        return Some(ContentType::Xml(example.unwrap_or_default().to_string()));
+    }
+
+    if let Some(media_type_object) = find_media_type(|media_type| media_type.starts_with("text/")) {
+        let example = media_type_object.get("example").and_then(Value::as_str);
+        return Some(ContentType::Raw(example.unwrap_or_default().to_string()));
+    }
+
+    if content.contains_key("application/octet-stream") {
+        return Some(ContentType::File(String::new()));
+    }
+
+    None
+}
+
+fn retrieve_media_type_example(spec: &Value, media_type_object: &Value) -> Value {
+    if let Some(example) = media_type_object.get("example") {
+        return example.clone();
+    }
+
+    let first_example = media_type_object
+        .get("examples")
+        .and_then(Value::as_object)
+        .and_then(|examples| examples.values().next())
+        .map(|example| resolve_ref(spec, example))
+        .and_then(|example| example.get("value"));
+
+    if let Some(example) = first_example {
+        return example.clone();
+    }
+
+    match media_type_object.get("schema") {
+        Some(schema) => generate_example(spec, schema, 0),
+        None => json!({}),
+    }
+}
+
+/// Build an example value from a JSON schema, preferring the examples and defaults it may provide
+fn generate_example(spec: &Value, schema: &Value, depth: u8) -> Value {
+    let schema = resolve_ref(spec, schema);
+
+    if depth > MAX_SCHEMA_DEPTH {
+        return Value::Null;
+    }
+
+    for example_key in ["example", "default"] {
+        if let Some(example) = schema.get(example_key) {
+            return example.clone();
+        }
+    }
+
+    if let Some(first_enum_value) = schema.pointer("/enum/0") {
+        return first_enum_value.clone();
+    }
+
+    for composition_key in ["allOf", "oneOf", "anyOf"] {
+        let sub_schemas = match schema.get(composition_key).and_then(Value::as_array) {
+            Some(sub_schemas) => sub_schemas,
+            None => continue,
+        };
+
+        // Only allOf requires merging every sub schema
+        if composition_key != "allOf" {
+            return match sub_schemas.first() {
+                Some(sub_schema) => generate_example(spec, sub_schema, depth + 1),
+                None => Value::Null,
+            };
+        }
+
+        let mut merged = Map::new();
+
+        for sub_schema in sub_schemas {
+            if let Value::Object(object) = generate_example(spec, sub_schema, depth + 1) {
+                merged.extend(object);
+            }
+        }
+
+        return Value::Object(merged);
+    }
+
+    let schema_type = match schema.get("type").and_then(Value::as_str) {
+        Some(schema_type) => schema_type,
+        None if schema.get("properties").is_some() => "object",
+        None => return Value::Null,
+    };
+
+    match schema_type {
+        "object" => {
+            let mut object = Map::new();
+
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (property_name, property) in properties {
+                    object.insert(
+                        property_name.clone(),
+                        generate_example(spec, property, depth + 1),
+                    );
+                }
+            }
+
+            Value::Object(object)
+        }
+        "array" => match schema.get("items") {
+            Some(items) => json!([generate_example(spec, items, depth + 1)]),
+            None => json!([]),
+        },
+        "string" => match schema.get("format").and_then(Value::as_str) {
+            Some("date-time") => json!("1970-01-01T00:00:00Z"),
+            Some("date") => json!("1970-01-01"),
+            Some("uuid") => json!("00000000-0000-0000-0000-000000000000"),
+            _ => json!("string"),
+        },
+        "integer" => json!(0),
+        "number" => json!(0.0),
+        "boolean" => json!(false),
+        _ => Value::Null,
+    }
+}
+
+fn form_key_value(name: &str, is_file: bool) -> KeyValue {
+    let value = match is_file {
+        // Multipart files are prefixed by !!
+        true => format!("!!{{{{{name}}}}}"),
+        false => format!("{{{{{name}}}}}"),
+    };
+
+    KeyValue {
+        enabled: true,
+        data: (name.to_string(), value),
+    }
+}
+
+/// Use the first security scheme of the first requirement to fill the request auth
+fn apply_security(spec: &Value, is_swagger_v2: bool, security: &Value, request: &mut Request) {
+    let scheme_name = match security
+        .get(0)
+        .and_then(Value::as_object)
+        .and_then(|requirement| requirement.keys().next())
+    {
+        Some(scheme_name) => scheme_name,
+        // An empty security requirement means that no auth is needed
+        None => return,
+    };
+
+    let schemes_pointer = match is_swagger_v2 {
+        true => "/securityDefinitions",
+        false => "/components/securitySchemes",
+    };
+
+    let scheme = match spec
+        .pointer(schemes_pointer)
+        .and_then(|schemes| schemes.get(scheme_name))
+    {
+        Some(scheme) => resolve_ref(spec, scheme),
+        None => return,
+    };
+
+    let scheme_type = scheme.get("type").and_then(Value::as_str).unwrap_or("");
+    let http_scheme = scheme
+        .get("scheme")
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .to_lowercase();
+
+    match (scheme_type, http_scheme.as_str()) {
+        ("basic", _) | ("http", "basic") => {
+            request.auth =
+                Auth::BasicAuth(String::from("{{username}}"), String::from("{{password}}"));
+        }
+        ("http", "bearer") | ("oauth2", _) | ("openIdConnect", _) => {
+            request.auth = Auth::BearerToken(String::from("{{token}}"));
+        }
+        ("apiKey", _) => {
+            let key_name = match scheme.get("name").and_then(Value::as_str) {
+                Some(key_name) => key_name.to_string(),
+                None => return,
+            };
+
+            // API keys sent in a cookie are not supported
+            let location = match scheme.get("in").and_then(Value::as_str) {
+                Some("header") => ApiKeyLocation::Header,
+                Some("query") => ApiKeyLocation::Query,
+                _ => return,
+            };
+
+            request.auth = Auth::ApiKey(ApiKey {
+                key: key_name,
+                value: format!("{{{{{scheme_name}}}}}"),
+                location,
+            });
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::Value;
+
+    use super::{is_swagger_v2, parse_operation, parse_spec_file, retrieve_base_url};
+    use crate::request::auth::{ApiKeyLocation, Auth};
+    use crate::request::body::ContentType;
+    use crate::request::method::Method;
+    use crate::request::request::{KeyValue, Request};
+
+    fn parse_fixture_operation(spec: &Value, path: &str, method: &str) -> Request {
+        let path_item = &spec["paths"][path];
+
+        parse_operation(spec, true, path, method, path_item, &path_item[method])
+    }
+
+    fn key_values(key_values: &[KeyValue]) -> Vec<(&str, &str, bool)> {
+        key_values
+            .iter()
+            .map(|key_value| {
+                (
+                    key_value.data.0.as_str(),
+                    key_value.data.1.as_str(),
+                    key_value.enabled,
+                )
+            })
+            .collect()
+    }
+
+    fn header<'a>(request: &'a Request, header_name: &str) -> Option<&'a str> {
+        request
+            .headers
+            .iter()
+            .find(|header| header.data.0 == header_name)
+            .map(|header| header.data.1.as_str())
+    }
+
+    #[test]
+    fn swagger_v2_petstore() {
+        let spec = parse_spec_file(&PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/import_tests/swagger-petstore-v2.json"
+        )));
+
+        assert!(is_swagger_v2(&spec));
+        assert_eq!(
+            retrieve_base_url(&spec, true).as_deref(),
+            Some("https://petstore.swagger.io/v2")
+        );
+
+        /* BODY PARAMETER, OAUTH2 */
+
+        let add_pet = parse_fixture_operation(&spec, "/pet", "post");
+
+        assert_eq!(add_pet.name, "Add a new pet to the store");
+        assert_eq!(add_pet.method, Method::POST);
+        assert_eq!(add_pet.url, "{{baseUrl}}/pet");
+        assert_eq!(header(&add_pet, "content-type"), Some("application/json"));
+        assert!(matches!(&add_pet.body, ContentType::Json(body) if body.contains("\"doggie\"")));
+        assert!(matches!(&add_pet.auth, Auth::BearerToken(token) if token == "{{token}}"));
+
+        /* QUERY PARAMETERS */
+
+        let find_by_status = parse_fixture_operation(&spec, "/pet/findByStatus", "get");
+
+        assert_eq!(
+            key_values(&find_by_status.params),
+            vec![
+                ("status", "{{status}}", true),
+                ("limit", "{{limit}}", false)
+            ]
+        );
+
+        /* PATH PARAMETERS, API KEY */
+
+        let get_pet = parse_fixture_operation(&spec, "/pet/{petId}", "get");
+
+        assert_eq!(get_pet.url, "{{baseUrl}}/pet/{{petId}}");
+        assert!(get_pet.params.is_empty());
+        assert!(matches!(
+            &get_pet.auth,
+            Auth::ApiKey(api_key) if api_key.key == "api_key"
+                && api_key.value == "{{api_key}}"
+                && matches!(api_key.location, ApiKeyLocation::Header)
+        ));
+
+        /* HEADER PARAMETER */
+
+        let delete_pet = parse_fixture_operation(&spec, "/pet/{petId}", "delete");
+
+        assert_eq!(delete_pet.method, Method::DELETE);
+        assert_eq!(header(&delete_pet, "api_key"), Some("{{api_key}}"));
+
+        /* FORM DATA */
+
+        let upload_image = parse_fixture_operation(&spec, "/pet/{petId}/uploadImage", "post");
+
+        match &upload_image.body {
+            ContentType::Multipart(form) => assert_eq!(
+                key_values(form),
+                vec![
+                    ("additionalMetadata", "{{additionalMetadata}}", true),
+                    ("file", "!!{{file}}", true)
+                ]
+            ),
+            body => panic!("Expected a multipart body, got {body:?}"),
+        }
+
+        let update_user = parse_fixture_operation(&spec, "/user/{username}", "put");
+
+        assert_eq!(update_user.url, "{{baseUrl}}/user/{{username}}");
+        assert_eq!(
+            header(&update_user, "content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert!(matches!(&update_user.body, ContentType::Form(form) if form.len() == 1));
+
+        /* NO SUMMARY, NO SECURITY */
+
+        let login = parse_fixture_operation(&spec, "/user/login", "get");
+
+        assert_eq!(login.name, "loginUser");
+        assert!(matches!(login.auth, Auth::NoAuth));
+    }
+}
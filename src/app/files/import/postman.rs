@@ -1,19 +1,21 @@
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use parse_postman_collection::v2_1_0::{
-    AuthType, Body, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode, RequestClass,
-    RequestUnion, Url,
+    Auth as PostmanAuth, AuthType, Body, FormParameterSrcUnion, HeaderUnion, Host, Items, Language,
+    Mode, PostmanCollection_v2_1_0, RequestClass, RequestUnion, Url, Variable,
 };
+use serde_json::Value;
 
 use crate::app::app::App;
 use crate::app::startup::args::ARGS;
 use crate::panic_error;
-use crate::request::auth::Auth;
+use crate::request::auth::{Auth, AwsSigV4, OAuth1, OAuth2, OAuth2GrantType};
 use crate::request::body::ContentType;
-use crate::request::collection::{Collection, CollectionFileFormat};
+use crate::request::collection::{Collection, Folder};
 use crate::request::method::Method;
 use crate::request::request::{KeyValue, Request, DEFAULT_HEADERS};
 use crate::request::settings::RequestSettings;
@@ -22,7 +24,7 @@ impl App<'_> {
     pub fn import_postman_collection(&mut self, path_buf: &PathBuf, max_depth: u16) {
         println!("Parsing Postman collection");
 
-        let mut postman_collection = match parse_postman_collection::from_path(path_buf) {
+        let postman_collection = match parse_postman_collection::from_path(path_buf) {
             Ok(postman_collection) => postman_collection,
             Err(e) => panic_error(format!("Could not parse Postman collection\n\t{e}")),
         };
@@ -39,135 +41,110 @@ impl App<'_> {
 
         let file_format = self.config.get_preferred_collection_file_format();
 
-        let mut collections: Vec<Collection> = vec![Collection {
-            name: collection_name.clone(),
-            requests: vec![],
-            path: ARGS
-                .directory
-                .join(format!("{}.{}", collection_name, file_format)),
-            file_format,
-        }];
+        let mut collection = parse_collection(postman_collection, max_depth);
 
-        let mut depth_level: u16 = 0;
+        collection.path = ARGS
+            .directory
+            .join(format!("{}.{}", collection_name, file_format));
+        collection.file_format = file_format;
 
-        if max_depth == 0 {
-            for item in postman_collection.item.iter_mut() {
-                collections[0].requests.extend(recursive_get_requests(item));
-            }
-        } else {
-            for mut item in postman_collection.item {
-                if item.name.is_none() {
-                    continue;
-                }
+        self.collections.push(collection);
 
-                // If this is a folder
-                if is_folder(&item) {
-                    let mut temp_nesting_prefix = String::new();
-                    let new_collections: Vec<Collection> = vec![];
-
-                    let file_format = self.config.get_preferred_collection_file_format();
-
-                    recursive_has_requests(
-                        &mut item,
-                        &mut collections,
-                        &mut temp_nesting_prefix,
-                        &mut depth_level,
-                        max_depth,
-                        file_format,
-                    );
-
-                    collections.extend(new_collections);
-                } else {
-                    collections[0]
-                        .requests
-                        .push(Arc::new(RwLock::new(parse_request(item))));
-                }
-            }
-        }
+        // Only save the newly imported collection, which is placed after the existing ones
+        let collection_index = self.collections.len() - 1;
+        self.save_collection_to_file(collection_index);
+    }
+}
 
-        // Prevent from having an empty collection
-        if collections.len() > 1 && collections[0].requests.is_empty() {
-            collections.remove(0);
+/// Collection with its folders and requests, without its file path and format
+pub(in crate::app::files) fn parse_collection(
+    postman_collection: PostmanCollection_v2_1_0,
+    max_depth: u16,
+) -> Collection {
+    let mut collection = Collection {
+        name: postman_collection.info.name.clone(),
+        headers: vec![],
+        auth: retrieve_auth(&postman_collection.auth).unwrap_or_default(),
+        variables: retrieve_variables(&postman_collection.variable),
+        requests: vec![],
+        folders: vec![],
+        ..Default::default()
+    };
+
+    for mut item in postman_collection.item {
+        if item.name.is_none() {
+            continue;
         }
 
-        let collections_length = collections.len();
+        // If this is a folder
+        if is_folder(&item) {
+            match max_depth {
+                // Folders are not kept, only their requests
+                0 => collection
+                    .requests
+                    .extend(recursive_get_requests(&mut item)),
+                _ => collection
+                    .folders
+                    .push(parse_folder(&mut item, 1, max_depth)),
+            }
+        } else {
+            collection
+                .requests
+                .push(Arc::new(RwLock::new(parse_request(item))));
+        }
+    }
 
-        self.collections.extend(collections);
+    // The requests without auth inherit it from the collection, it is useless if there is none
+    if collection.auth.is_no_auth() {
+        for request in collection.all_requests() {
+            let mut request = request.write();
 
-        for collection_index in 0..collections_length {
-            self.save_collection_to_file(collection_index);
+            if let Auth::InheritFromCollection = request.auth {
+                request.auth = Auth::NoAuth;
+            }
         }
     }
-}
 
-fn recursive_has_requests(
-    item: &mut Items,
-    collections: &mut Vec<Collection>,
-    nesting_prefix: &mut String,
-    depth_level: &mut u16,
-    max_depth: u16,
-    file_format: CollectionFileFormat,
-) -> Option<Arc<RwLock<Request>>> {
-    return if is_folder(item) {
-        let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+    collection
+}
 
-        let mut folder_name = item.clone().name.unwrap();
-        folder_name = folder_name.replace('/', "-");
-        folder_name = folder_name.replace('\\', "-");
-        folder_name = folder_name.trim().to_string();
+/// Keep the folder hierarchy until the max depth, the deeper requests are put in the last folder
+fn parse_folder(item: &mut Items, depth_level: u16, max_depth: u16) -> Folder {
+    let folder_name = item.name.clone().unwrap().trim().to_string();
 
-        let collection_name = format!("{nesting_prefix}{folder_name}");
+    println!("\tFound folder \"{}\"", folder_name);
 
-        *depth_level += 1;
+    let mut folder = Folder {
+        name: folder_name,
+        requests: vec![],
+        folders: vec![],
+    };
 
-        if *depth_level == max_depth {
-            println!("\tMet max depth level");
-            requests = recursive_get_requests(item);
-        } else {
-            nesting_prefix.push_str(&format!("{folder_name} "));
-
-            let mut has_sub_folders = false;
-
-            for mut sub_item in item.item.clone().unwrap() {
-                if let Some(request) = recursive_has_requests(
-                    &mut sub_item,
-                    collections,
-                    nesting_prefix,
-                    depth_level,
-                    max_depth,
-                    file_format,
-                ) {
-                    requests.push(request);
-                } else {
-                    has_sub_folders = true;
-                }
-            }
+    if depth_level == max_depth {
+        println!("\tMet max depth level");
+        folder.requests = recursive_get_requests(item);
+        return folder;
+    }
 
-            if has_sub_folders {
-                nesting_prefix.clear();
-            }
+    for mut sub_item in item.item.clone().unwrap() {
+        if sub_item.name.is_none() {
+            continue;
         }
 
-        if !requests.is_empty() {
-            println!("\tFound collection \"{}\"", collection_name);
-
-            let collection = Collection {
-                name: collection_name.clone(),
-                requests,
-                path: ARGS
-                    .directory
-                    .join(format!("{}.{}", collection_name, file_format)),
-                file_format,
-            };
-
-            collections.push(collection);
-            *depth_level -= 1;
+        if is_folder(&sub_item) {
+            folder
+                .folders
+                .push(parse_folder(&mut sub_item, depth_level + 1, max_depth));
+        } else {
+            folder
+                .requests
+                .push(Arc::new(RwLock::new(parse_request(sub_item))));
         }
+    }
 
-        None
-    } else {
-        Some(Arc::new(RwLock::new(parse_request(item.clone()))))
-    };
+    apply_folder_auth(item, &folder.all_requests());
+
+    folder
 }
 
 fn recursive_get_requests(item: &mut Items) -> Vec<Arc<RwLock<Request>>> {
@@ -178,12 +155,33 @@ fn recursive_get_requests(item: &mut Items) -> Vec<Arc<RwLock<Request>>> {
             requests.extend(recursive_get_requests(item));
         }
 
+        apply_folder_auth(item, &requests);
+
         requests
     } else {
         vec![Arc::new(RwLock::new(parse_request(item.clone())))]
     }
 }
 
+/// The folders have no auth of their own, their requests inheriting one get the folder auth.
+/// Deeper folders must already be applied, so that their auth takes precedence
+fn apply_folder_auth(folder: &Items, requests: &[Arc<RwLock<Request>>]) {
+    // Inherited from the parent folder or the collection
+    if folder.auth.is_none() {
+        return;
+    }
+
+    let folder_auth = retrieve_auth(&folder.auth).unwrap_or_default();
+
+    for request in requests {
+        let mut request = request.write();
+
+        if let Auth::InheritFromCollection = request.auth {
+            request.auth = folder_auth.clone();
+        }
+    }
+}
+
 fn is_folder(folder: &Items) -> bool {
     folder.item.is_some()
 }
@@ -198,7 +196,8 @@ fn parse_request(item: Items) -> Request {
         ..Request::default()
     };
 
-    request.scripts.pre_request_script = retrieve_request_scripts(&item);
+    request.scripts.pre_request_script = retrieve_request_scripts(&item, "prerequest");
+    request.scripts.post_request_script = retrieve_request_scripts(&item, "test");
 
     /* SETTINGS */
 
@@ -219,7 +218,15 @@ fn parse_request(item: Items) -> Request {
             if let Some(url) = &request_class.url {
                 match url {
                     Url::String(url) => request.url = url.to_string(),
-                    Url::UrlClass(url_class) => request.url = url_class.raw.clone().unwrap(),
+                    Url::UrlClass(url_class) => {
+                        let raw_url = url_class.raw.clone().unwrap();
+
+                        // The query params are already retrieved from the query field
+                        request.url = match (&url_class.query, raw_url.split_once('?')) {
+                            (Some(_), Some((url, _))) => url.to_string(),
+                            _ => raw_url,
+                        };
+                    }
                 }
             }
 
@@ -241,9 +248,11 @@ fn parse_request(item: Items) -> Request {
 
             /* AUTH */
 
-            match retrieve_auth(request_class) {
-                None => {}
-                Some(auth) => request.auth = auth,
+            match (&request_class.auth, retrieve_auth(&request_class.auth)) {
+                // Postman requests without auth inherit it from their parent
+                (None, _) => request.auth = Auth::InheritFromCollection,
+                (Some(_), None) => {}
+                (Some(_), Some(auth)) => request.auth = auth,
             }
 
             /* HEADERS */
@@ -392,8 +401,8 @@ fn retrieve_body(request_class: &RequestClass) -> Option<ContentType> {
     }
 }
 
-fn retrieve_auth(request_class: &RequestClass) -> Option<Auth> {
-    let auth = request_class.auth.clone()?;
+fn retrieve_auth(postman_auth: &Option<PostmanAuth>) -> Option<Auth> {
+    let auth = postman_auth.clone()?;
 
     match auth.auth_type {
         AuthType::Basic => {
@@ -425,16 +434,146 @@ fn retrieve_auth(request_class: &RequestClass) -> Option<Auth> {
 
             Some(Auth::BearerToken(bearer_token))
         }
-        AuthType::Awsv4 => None,
-        AuthType::Digest => None,
+        AuthType::Awsv4 => {
+            let awsv4_attributes = auth.awsv4?;
+
+            let mut aws_sig_v4 = AwsSigV4::default();
+
+            for awsv4_attribute in awsv4_attributes {
+                let value = match awsv4_attribute.value.as_ref().and_then(Value::as_str) {
+                    None => continue,
+                    Some(value) => value.to_string(),
+                };
+
+                match awsv4_attribute.key.as_str() {
+                    "accessKey" => aws_sig_v4.access_key_id = value,
+                    "secretKey" => aws_sig_v4.secret_access_key = value,
+                    "sessionToken" => aws_sig_v4.session_token = value,
+                    "region" => aws_sig_v4.region = value,
+                    "service" => aws_sig_v4.service = value,
+                    _ => {}
+                }
+            }
+
+            Some(Auth::AwsSigV4(aws_sig_v4))
+        }
+        AuthType::Digest => {
+            let digest_attributes = auth.digest?;
+
+            let mut username = String::new();
+            let mut password = String::new();
+
+            for digest_attribute in digest_attributes {
+                let value = match digest_attribute.value.as_ref().and_then(Value::as_str) {
+                    None => continue,
+                    Some(value) => value.to_string(),
+                };
+
+                match digest_attribute.key.as_str() {
+                    "username" => username = value,
+                    "password" => password = value,
+                    _ => {}
+                }
+            }
+
+            Some(Auth::Digest(username, password))
+        }
         AuthType::Hawk => None,
         AuthType::Noauth => None,
         AuthType::Ntlm => None,
-        AuthType::Oauth1 => None,
-        AuthType::Oauth2 => None,
+        AuthType::Oauth1 => {
+            let oauth1_attributes = auth.oauth1?;
+
+            let mut oauth1 = OAuth1::default();
+
+            for oauth1_attribute in oauth1_attributes {
+                let value = match oauth1_attribute.value.as_ref().and_then(Value::as_str) {
+                    None => continue,
+                    Some(value) => value.to_string(),
+                };
+
+                match oauth1_attribute.key.as_str() {
+                    "consumerKey" => oauth1.consumer_key = value,
+                    "consumerSecret" => oauth1.consumer_secret = value,
+                    "token" => oauth1.token = value,
+                    "tokenSecret" => oauth1.token_secret = value,
+                    "signatureMethod" if value != "HMAC-SHA1" => {
+                        println!("\t\t\tUnsupported OAuth1 signature method \"{value}\"");
+                        return None;
+                    }
+                    _ => {}
+                }
+            }
+
+            Some(Auth::OAuth1(oauth1))
+        }
+        AuthType::Oauth2 => {
+            let oauth2_attributes = auth.oauth2?;
+
+            let mut oauth2 = OAuth2::default();
+
+            for oauth2_attribute in oauth2_attributes {
+                let value = match oauth2_attribute.value.as_ref().and_then(Value::as_str) {
+                    None => continue,
+                    Some(value) => value.to_string(),
+                };
+
+                match oauth2_attribute.key.as_str() {
+                    "grant_type" => {
+                        oauth2.grant_type = match value.as_str() {
+                            "client_credentials" => OAuth2GrantType::ClientCredentials,
+                            "password_credentials" => OAuth2GrantType::Password,
+                            "authorization_code" | "authorization_code_with_pkce" => {
+                                OAuth2GrantType::AuthorizationCodePkce
+                            }
+                            grant_type => {
+                                println!("\t\t\tUnsupported OAuth2 grant type \"{grant_type}\"");
+                                return None;
+                            }
+                        }
+                    }
+                    "accessTokenUrl" => oauth2.access_token_url = value,
+                    "authUrl" => oauth2.authorization_url = value,
+                    "redirect_uri" => oauth2.redirect_uri = value,
+                    "clientId" => oauth2.client_id = value,
+                    "clientSecret" => oauth2.client_secret = value,
+                    "scope" => oauth2.scope = value,
+                    "username" => oauth2.username = value,
+                    "password" => oauth2.password = value,
+                    _ => {}
+                }
+            }
+
+            Some(Auth::OAuth2(oauth2))
+        }
     }
 }
 
+fn retrieve_variables(variables: &Option<Vec<Variable>>) -> IndexMap<String, String> {
+    let mut collection_variables: IndexMap<String, String> = IndexMap::new();
+
+    for variable in variables.iter().flatten() {
+        if variable.disabled == Some(true) {
+            continue;
+        }
+
+        let key = match &variable.key {
+            None => continue,
+            Some(key) => key.clone(),
+        };
+
+        let value = match &variable.value {
+            None => String::new(),
+            Some(Value::String(value)) => value.clone(),
+            Some(value) => value.to_string(),
+        };
+
+        collection_variables.insert(key, value);
+    }
+
+    collection_variables
+}
+
 fn retrieve_headers(request_class: &RequestClass) -> Option<Vec<KeyValue>> {
     let headers = request_class.header.clone()?;
 
@@ -455,19 +594,22 @@ fn retrieve_headers(request_class: &RequestClass) -> Option<Vec<KeyValue>> {
     }
 }
 
-fn retrieve_request_scripts(item: &Items) -> Option<String> {
+/// Listen is "prerequest" for pre-request scripts and "test" for post-request scripts
+fn retrieve_request_scripts(item: &Items, listen: &str) -> Option<String> {
     let events = item.event.clone()?;
 
     for event in events {
-        if event.listen == "prerequest" {
+        if event.listen == listen {
             let script = event.script?;
             match script.exec? {
                 Host::String(_) => {}
                 Host::StringArray(exec) => {
+                    // Without a trailing newline, so that exported scripts are imported back as is
                     let script: String = exec
                         .iter()
-                        .map(|line| line.replace("pm.", "") + "\n")
-                        .collect();
+                        .map(|line| line.replace("pm.", ""))
+                        .collect::<Vec<String>>()
+                        .join("\n");
 
                     return Some(script);
                 }
@@ -493,3 +635,81 @@ fn retrieve_settings(item: &Items) -> Option<RequestSettings> {
 
     Some(settings)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::parse_collection;
+    use crate::request::auth::Auth;
+    use crate::request::collection::Collection;
+
+    fn import_fixture(file_name: &str, max_depth: u16) -> Collection {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("import_tests")
+            .join(file_name);
+
+        parse_collection(
+            parse_postman_collection::from_path(path).unwrap(),
+            max_depth,
+        )
+    }
+
+    fn request_auth(collection: &Collection, request_name: &str) -> Auth {
+        collection
+            .all_requests()
+            .iter()
+            .find(|request| request.read().name == request_name)
+            .map(|request| request.read().auth.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn nested_folders_auth() {
+        // Folders kept, then flattened
+        for max_depth in [99, 0] {
+            let collection = import_fixture("folder-auth.postman_collection.json", max_depth);
+
+            let admin_auth = request_auth(&collection, "List users");
+            assert!(
+                matches!(&admin_auth, Auth::BasicAuth(username, _) if username == "admin"),
+                "{admin_auth:?}"
+            );
+
+            let settings_auth = request_auth(&collection, "Settings");
+            assert!(
+                matches!(&settings_auth, Auth::BasicAuth(..)),
+                "{settings_auth:?}"
+            );
+
+            let public_auth = request_auth(&collection, "Health");
+            assert!(matches!(&public_auth, Auth::NoAuth), "{public_auth:?}");
+
+            let own_auth = request_auth(&collection, "Own token");
+            assert!(
+                matches!(&own_auth, Auth::BearerToken(token) if token == "own-token"),
+                "{own_auth:?}"
+            );
+
+            let root_auth = request_auth(&collection, "Root");
+            assert!(
+                matches!(&root_auth, Auth::InheritFromCollection),
+                "{root_auth:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn digest_auth_without_password() {
+        let collection = import_fixture("digest-auth.postman_collection.json", 99);
+        let request = collection.requests[0].read();
+
+        match &request.auth {
+            Auth::Digest(username, password) => {
+                assert_eq!(username, "user");
+                assert_eq!(password, "");
+            }
+            auth => panic!("Expected a digest auth, got {auth:?}"),
+        }
+    }
+}
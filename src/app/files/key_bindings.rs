@@ -31,6 +31,10 @@ nest! {
 
             pub move_request_up: KeyCombination,
             pub move_request_down: KeyCombination,
+            #[serde(default = "default_move_element_into_folder")]
+            pub move_element_into_folder: KeyCombination,
+            #[serde(default = "default_move_element_out_of_folder")]
+            pub move_element_out_of_folder: KeyCombination,
 
             pub next_environment: KeyCombination,
 
@@ -56,7 +60,11 @@ nest! {
                 pub text_area_mode: #[derive(Copy, Clone, PartialEq, Deserialize)] pub enum TextAreaMode {
                     VimEmulation,
                     Custom(CustomTextArea)
-                }
+                },
+
+                /// GraphQL query, in both text area modes
+                #[serde(default = "default_autocomplete")]
+                pub autocomplete: KeyCombination,
             },
 
             /// Navigation in tables, popups, up and down in the collections list
@@ -92,16 +100,29 @@ nest! {
             pub param_next_tab: KeyCombination,
             pub change_url: KeyCombination,
             pub change_method: KeyCombination,
+            #[serde(default = "default_custom_method")]
+            pub custom_method: KeyCombination,
             pub request_settings: KeyCombination,
 
             pub next_view: KeyCombination,
 
             pub send_request: KeyCombination,
             pub alt_send_request: KeyCombination,
+            #[serde(default = "default_cancel_request")]
+            pub cancel_request: KeyCombination,
+
+            #[serde(default = "default_copy_as_curl")]
+            pub copy_as_curl: KeyCombination,
+            #[serde(default = "default_generate_code_snippet")]
+            pub generate_code_snippet: KeyCombination,
 
             pub param_tabs: #[derive(Copy, Clone, Deserialize)] pub struct ParamTabs {
                 pub change_auth_method: KeyCombination,
                 pub change_body_content_type: KeyCombination,
+                #[serde(default = "default_fetch_graphql_schema")]
+                pub fetch_graphql_schema: KeyCombination,
+                #[serde(default = "default_display_graphql_schema")]
+                pub display_graphql_schema: KeyCombination,
             },
 
             pub result_tabs: #[derive(Copy, Clone, Deserialize)] pub struct ResultTabs {
@@ -161,6 +182,8 @@ impl Default for KeyBindings {
 
                 move_request_up: key!(ctrl - up),
                 move_request_down: key!(ctrl - down),
+                move_element_into_folder: default_move_element_into_folder(),
+                move_element_out_of_folder: default_move_element_out_of_folder(),
 
                 next_environment: key!(e),
 
@@ -182,6 +205,7 @@ impl Default for KeyBindings {
                         move_cursor_right: key!(right),
                     },
                     text_area_mode: TextAreaMode::Custom(CustomTextArea::default()),
+                    autocomplete: default_autocomplete(),
                 },
 
                 navigation: Navigation {
@@ -212,6 +236,7 @@ impl Default for KeyBindings {
 
                 change_url: key!(u),
                 change_method: key!(m),
+                custom_method: default_custom_method(),
 
                 request_settings: key!(s),
 
@@ -221,10 +246,16 @@ impl Default for KeyBindings {
                 // https://github.com/crossterm-rs/crossterm/issues/685
                 send_request: key!(space),
                 alt_send_request: key!(ctrl - enter),
+                cancel_request: default_cancel_request(),
+
+                copy_as_curl: default_copy_as_curl(),
+                generate_code_snippet: default_generate_code_snippet(),
 
                 param_tabs: ParamTabs {
                     change_auth_method: key!(ctrl - a),
                     change_body_content_type: key!(ctrl - b),
+                    fetch_graphql_schema: default_fetch_graphql_schema(),
+                    display_graphql_schema: default_display_graphql_schema(),
                 },
                 result_tabs: ResultTabs {
                     scroll_up: key!(ctrl - up),
@@ -241,6 +272,44 @@ impl Default for KeyBindings {
     }
 }
 
+/* Defaults of the key bindings added after the templates, so that older files still parse */
+
+fn default_move_element_into_folder() -> KeyCombination {
+    key!(ctrl - right)
+}
+
+fn default_move_element_out_of_folder() -> KeyCombination {
+    key!(ctrl - left)
+}
+
+fn default_copy_as_curl() -> KeyCombination {
+    key!(x)
+}
+
+fn default_generate_code_snippet() -> KeyCombination {
+    key!(g)
+}
+
+fn default_custom_method() -> KeyCombination {
+    key!(shift - m)
+}
+
+fn default_cancel_request() -> KeyCombination {
+    key!(ctrl - x)
+}
+
+fn default_fetch_graphql_schema() -> KeyCombination {
+    key!(ctrl - r)
+}
+
+fn default_display_graphql_schema() -> KeyCombination {
+    key!(ctrl - g)
+}
+
+fn default_autocomplete() -> KeyCombination {
+    key!(ctrl - space)
+}
+
 impl Default for CustomTextArea {
     fn default() -> Self {
         CustomTextArea {
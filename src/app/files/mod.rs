@@ -1,6 +1,7 @@
 pub mod collection;
 pub mod config;
 pub mod environment;
+pub mod export;
 pub mod import;
 pub mod key_bindings;
 pub mod log;
@@ -1,6 +1,7 @@
 pub mod app;
 mod app_logic;
 pub mod app_states;
+mod cli;
 mod event_key_bindings;
 pub mod events;
 mod files;
@@ -23,12 +23,60 @@ pub struct Args {
 
 #[derive(Subcommand, Debug, PartialEq)]
 pub enum Command {
-    /// Import a collection or request from other file formats (Postman v2.1.0, cURL)
+    /// Import a collection or request from other file formats (Postman v2.1.0, cURL, HAR 1.2, Insomnia v4, Bruno, OpenAPI 3, Swagger 2, .http files)
     Import {
         /// The type of file to import
         #[command(subcommand)]
         import_type: ImportType,
     },
+
+    /// Send a request from a collection and print the response, without starting the TUI
+    Send {
+        /// Name of the collection containing the request
+        collection_name: String,
+
+        /// Name of the request to send
+        request_name: String,
+
+        /// Environment to use (the first one found will be used if none is provided, like in the TUI)
+        #[arg(long)]
+        env: Option<String>,
+    },
+
+    /// Send every request of a collection in order and print a summary, without starting the TUI
+    Run {
+        /// Name of the collection to run
+        collection_name: String,
+
+        /// Environment to use (the first one found will be used if none is provided, like in the TUI)
+        #[arg(long)]
+        env: Option<String>,
+
+        /// Write a JUnit XML report to the given path
+        #[arg(long)]
+        junit: Option<PathBuf>,
+
+        /// Write a JSON report to the given path
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+
+    /// Export a request or a collection to other file formats (cURL, HAR 1.2, Postman v2.1.0, Bruno, .http file), without starting the TUI
+    Export {
+        /// The format to export to
+        #[command(subcommand)]
+        export_type: ExportType,
+    },
+}
+
+impl Command {
+    /// Headless commands only print their result to stdout and do not start the TUI
+    pub fn is_headless(&self) -> bool {
+        match self {
+            Command::Import { .. } => false,
+            Command::Send { .. } | Command::Run { .. } | Command::Export { .. } => true,
+        }
+    }
 }
 
 #[derive(Subcommand, Debug, PartialEq)]
@@ -38,7 +86,7 @@ pub enum ImportType {
         /// Path to the file to import
         import_path: PathBuf,
 
-        /// Max depth at which import should stop creating nested collections and only get the deeper requests
+        /// Max depth at which import should stop creating nested folders and only get the deeper requests
         #[arg(long)]
         max_depth: Option<u16>,
     },
@@ -62,6 +110,118 @@ pub enum ImportType {
         #[arg(long, requires = "recursive", conflicts_with = "request_name")]
         max_depth: Option<u16>,
     },
+
+    /// Import a HAR 1.2 file, each entry becomes a request
+    Har {
+        /// Path to the file to import
+        import_path: PathBuf,
+
+        /// Collection name to save the requests to
+        collection_name: String,
+    },
+
+    /// Import a .http file (VS Code REST Client / JetBrains HTTP client), file variables are saved in an environment named after the collection
+    Http {
+        /// Path to the file to import
+        import_path: PathBuf,
+
+        /// Collection name to save the requests to (will use the file name if none is provided)
+        collection_name: Option<String>,
+    },
+
+    /// Import an Insomnia v4 export, request groups become collections and environments become environment files
+    Insomnia {
+        /// Path to the file to import
+        import_path: PathBuf,
+    },
+
+    /// Import a Bruno collection directory, folders become collections and environments become environment files
+    Bruno {
+        /// Path to the Bruno collection directory
+        import_path: PathBuf,
+
+        /// Max depth at which import should stop looking for requests in sub folders
+        #[arg(long)]
+        max_depth: Option<u16>,
+    },
+
+    /// Import an OpenAPI 3 or Swagger 2 specification (JSON or YAML), operations are grouped by tag into collections
+    #[command(name = "openapi")]
+    OpenApi {
+        /// Path to the file to import
+        import_path: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug, PartialEq)]
+pub enum ExportType {
+    /// Export a request as a curl command line
+    Curl {
+        /// Name of the collection containing the request
+        collection_name: String,
+
+        /// Name of the request to export
+        request_name: String,
+
+        /// Replace the environment keys by the values of the given environment (they are kept as is if none is provided)
+        #[arg(long)]
+        env: Option<String>,
+    },
+
+    /// Export a request as a HAR 1.2 file, with its response if it is sent
+    Har {
+        /// Name of the collection containing the request
+        collection_name: String,
+
+        /// Name of the request to export
+        request_name: String,
+
+        /// Path of the file to write
+        export_path: PathBuf,
+
+        /// Environment to use (the first one found will be used if none is provided, like in the TUI)
+        #[arg(long)]
+        env: Option<String>,
+
+        /// Send the request to export its response, responses are not saved otherwise
+        #[arg(long)]
+        send: bool,
+    },
+
+    /// Export a collection as a Postman v2.1.0 file, JWT auths are exported as no auth
+    Postman {
+        /// Name of the collection to export
+        collection_name: String,
+
+        /// Path of the file to write
+        export_path: PathBuf,
+    },
+
+    /// Export a collection as a Bruno collection directory
+    Bruno {
+        /// Name of the collection to export
+        collection_name: String,
+
+        /// Path of the directory to write
+        export_path: PathBuf,
+
+        /// Also export the given environment in the environments directory
+        #[arg(long)]
+        env: Option<String>,
+    },
+
+    /// Export a collection as a .http file (VS Code REST Client / JetBrains HTTP client)
+    Http {
+        /// Name of the collection to export
+        collection_name: String,
+
+        /// Path of the file to write
+        export_path: PathBuf,
+
+        /// Declare the values of the given environment as file variables
+        #[arg(long)]
+        env: Option<String>,
+    },
 }
 
 pub struct ParsedArgs {
@@ -70,6 +230,8 @@ pub struct ParsedArgs {
     pub is_directory_from_env: bool,
     pub command: Option<Command>,
     pub should_save: bool,
+    /// Whether the command only prints its result, in which case the startup logs are hidden
+    pub is_headless: bool,
 }
 
 lazy_static! {
@@ -87,11 +249,14 @@ lazy_static! {
             }
         };
 
+        let is_headless = args.command.as_ref().is_some_and(Command::is_headless);
+
         ParsedArgs {
             directory,
             is_directory_from_env,
             command: args.command,
-            should_save: !args.dry_run
+            should_save: !args.dry_run,
+            is_headless,
         }
     };
 }
@@ -8,11 +8,15 @@ use crate::request::collection::CollectionFileFormat;
 impl App<'_> {
     /// Method called before running the app
     pub fn startup(&mut self) -> &mut Self {
-        self.parse_key_bindings_file();
+        // Key bindings are useless without the TUI
+        if !ARGS.is_headless {
+            self.parse_key_bindings_file();
+        }
+
         self.parse_app_directory();
 
-        // Creates the log file only if the app is allowed to save files
-        if ARGS.should_save {
+        // Creates the log file only if the app is allowed to save files and the TUI will be used
+        if ARGS.should_save && !ARGS.is_headless {
             self.create_log_file();
         }
 
@@ -37,7 +41,30 @@ impl App<'_> {
                         recursive,
                         max_depth.unwrap_or(99),
                     ),
+
+                    ImportType::Har {
+                        import_path,
+                        collection_name,
+                    } => self.import_har_file(import_path, collection_name),
+
+                    ImportType::Http {
+                        import_path,
+                        collection_name,
+                    } => self.import_http_file(import_path, collection_name),
+
+                    ImportType::Insomnia { import_path } => {
+                        self.import_insomnia_export(import_path)
+                    }
+
+                    ImportType::Bruno {
+                        import_path,
+                        max_depth,
+                    } => self.import_bruno_collection(import_path, max_depth.unwrap_or(99)),
+
+                    ImportType::OpenApi { import_path } => self.import_openapi_spec(import_path),
                 },
+                // Handled by handle_headless_command once the app has started
+                Command::Send { .. } | Command::Run { .. } | Command::Export { .. } => {}
             }
         }
 
@@ -62,7 +89,9 @@ impl App<'_> {
 
             let file_name = path.file_name().unwrap().to_str().unwrap();
 
-            println!("Checking: {}", path.display());
+            if !ARGS.is_headless {
+                println!("Checking: {}", path.display());
+            }
 
             if file_name.ends_with(".json") {
                 self.set_collections_from_file(path, CollectionFileFormat::Json);
@@ -72,11 +101,13 @@ impl App<'_> {
                 self.add_environment_from_file(path)
             } else if file_name == "atac.toml" {
                 self.parse_config_file(path);
-            } else if file_name == "atac.log" {
+            } else if file_name == "atac.log" && !ARGS.is_headless {
                 println!("Nothing to parse here")
             }
 
-            println!();
+            if !ARGS.is_headless {
+                println!();
+            }
         }
     }
 
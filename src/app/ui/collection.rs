@@ -1,11 +1,14 @@
 use crate::app::app::App;
 use crate::app::ui::request::request_to_tree_item;
-use crate::request::collection::Collection;
+use crate::request::collection::{Collection, Folder};
+use crate::request::request::Request;
+use parking_lot::RwLock;
 use ratatui::layout::Rect;
-use ratatui::prelude::{Modifier, Style};
+use ratatui::prelude::{Modifier, Style, Stylize};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders};
 use ratatui::Frame;
+use std::sync::Arc;
 use tui_tree_widget::{Tree, TreeItem};
 
 
@@ -14,17 +17,41 @@ pub fn collection_to_tree_item<'a>(collection: &Collection, identifier: usize) -
 
     let line = Line::from(vec![
         Span::raw(name),
-        Span::from(format!(" ({})", collection.requests.len())),
+        Span::from(format!(" ({})", collection.all_requests().len())),
     ]);
 
-    let items: Vec<TreeItem<usize>> = collection
-        .requests
+    let items = children_to_tree_items(&collection.folders, &collection.requests);
+
+    TreeItem::new(identifier, line, items).unwrap()
+}
+
+fn folder_to_tree_item<'a>(folder: &Folder, identifier: usize) -> TreeItem<'a, usize> {
+    let line = Line::from(vec![
+        Span::raw(format!("{}/", folder.name)).italic(),
+        Span::from(format!(" ({})", folder.all_requests().len())),
+    ]);
+
+    let items = children_to_tree_items(&folder.folders, &folder.requests);
+
+    TreeItem::new(identifier, line, items).unwrap()
+}
+
+/// Folders come first, the requests identifiers follow the folders ones
+fn children_to_tree_items<'a>(
+    folders: &[Folder],
+    requests: &[Arc<RwLock<Request>>],
+) -> Vec<TreeItem<'a, usize>> {
+    let mut items: Vec<TreeItem<usize>> = folders
         .iter()
         .enumerate()
-        .map(|(request_index, request)| request_to_tree_item(&request.read(), request_index))
+        .map(|(folder_index, folder)| folder_to_tree_item(folder, folder_index))
         .collect();
 
-    TreeItem::new(identifier, line, items).unwrap()
+    items.extend(requests.iter().enumerate().map(|(request_index, request)| {
+        request_to_tree_item(&request.read(), folders.len() + request_index)
+    }));
+
+    items
 }
 
 impl<'a> App<'a> {
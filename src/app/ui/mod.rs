@@ -7,3 +7,4 @@ pub mod request;
 pub mod result_tabs;
 pub mod ui;
 pub mod views;
+pub mod websocket_session;
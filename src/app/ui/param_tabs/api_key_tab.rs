@@ -0,0 +1,76 @@
+use crate::app::app::App;
+use crate::app::app_states::AppState::{EditingRequestAuthField, SelectedRequest};
+use crate::request::auth::ApiKey;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+impl App<'_> {
+    pub(super) fn render_api_key_tab(&mut self, frame: &mut Frame, area: Rect, api_key: &ApiKey) {
+        let api_key_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ],
+        )
+        .vertical_margin(1)
+        .horizontal_margin(4)
+        .split(area);
+
+        let mut key_block = Block::new().title("Key").borders(Borders::ALL);
+        let mut value_block = Block::new().title("Value").borders(Borders::ALL);
+        let mut location_block = Block::new().title("Add to").borders(Borders::ALL);
+
+        // Prevent from rendering the selection while the tab is not focused
+        let should_color_blocks = matches!(self.state, SelectedRequest | EditingRequestAuthField);
+        let input_selected = self.auth_text_input_selection.selected;
+
+        if should_color_blocks {
+            match input_selected {
+                0 => key_block = key_block.yellow(),
+                1 => value_block = value_block.yellow(),
+                2 => location_block = location_block.yellow(),
+                _ => {}
+            }
+        }
+
+        let mut key_line = self.add_color_to_env_keys(&api_key.key);
+        let mut value_line = self.add_color_to_env_keys(&api_key.value);
+
+        if self.state == EditingRequestAuthField && input_selected < 2 {
+            let input_layout = api_key_layout[input_selected];
+
+            let (padded_text, input_cursor_position) = self
+                .auth_field_text_input
+                .get_padded_text_and_cursor(input_layout.width as usize - 2);
+
+            frame.set_cursor(
+                input_layout.x + input_cursor_position as u16 + 1,
+                input_layout.y + 1,
+            );
+
+            match input_selected {
+                0 => key_line = self.add_color_to_env_keys(&padded_text),
+                _ => value_line = self.add_color_to_env_keys(&padded_text),
+            }
+        }
+
+        let location_line = Line::from(vec![
+            api_key.location.to_string().into(),
+            " (Enter to change)".dark_gray(),
+        ]);
+
+        let key_paragraph = Paragraph::new(key_line).block(key_block);
+        let value_paragraph = Paragraph::new(value_line).block(value_block);
+        let location_paragraph = Paragraph::new(location_line).block(location_block);
+
+        frame.render_widget(key_paragraph, api_key_layout[0]);
+        frame.render_widget(value_paragraph, api_key_layout[1]);
+        frame.render_widget(location_paragraph, api_key_layout[2]);
+    }
+}
@@ -0,0 +1,96 @@
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::prelude::{Color, Style};
+use ratatui::style::Color::Yellow;
+use ratatui::style::Stylize;
+use ratatui::widgets::block::{Position, Title};
+use ratatui::widgets::{Block, Borders};
+use ratatui::Frame;
+
+use crate::app::app::App;
+use crate::app::app_states::AppState;
+use crate::request::request::Request;
+
+impl App<'_> {
+    pub(super) fn render_graphql_body_tab(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        request: &Request,
+    ) {
+        let graphql_layout = Layout::new(
+            Vertical,
+            vec![Constraint::Percentage(60), Constraint::Percentage(40)],
+        )
+        .split(area);
+
+        self.body_text_area
+            .set_line_number_style(Style::new().fg(Color::DarkGray));
+        self.graphql_editor
+            .variables_text_area
+            .set_line_number_style(Style::new().fg(Color::DarkGray));
+
+        let query_text_area = &mut self.body_text_area;
+        let variables_text_area = &mut self.graphql_editor.variables_text_area;
+
+        let title = match self.graphql_editor.selection {
+            0 => " Query ",
+            1 => " Variables ",
+            _ => "",
+        };
+
+        if self.state == AppState::SelectedRequest {
+            match self.graphql_editor.selection {
+                0 => {
+                    query_text_area.set_style(Style::new().fg(Yellow));
+                    variables_text_area.set_style(Style::new());
+                }
+                1 => {
+                    query_text_area.set_style(Style::new());
+                    variables_text_area.set_style(Style::new().fg(Yellow));
+                }
+                _ => {}
+            };
+        } else {
+            query_text_area.set_style(Style::new());
+            variables_text_area.set_style(Style::new());
+        }
+
+        let schema_title = match &request.graphql_schema {
+            None => String::from(" No schema "),
+            Some(schema) => {
+                let types_count = schema
+                    .types
+                    .iter()
+                    .filter(|graphql_type| !graphql_type.is_builtin())
+                    .count();
+
+                format!(" Schema: {types_count} types ")
+            }
+        };
+
+        // The completion candidates replace the title while editing
+        let title = if self.state == AppState::EditingRequestBodyString
+            && !self.graphql_editor.completions.is_empty()
+        {
+            let completions = format!(" {} ", self.graphql_editor.completions.join(" "));
+            Title::from(completions.yellow())
+        } else {
+            Title::from(title)
+        };
+
+        let query_block = Block::default()
+            .borders(Borders::BOTTOM)
+            .title(title.position(Position::Bottom).alignment(Alignment::Left))
+            .title(
+                Title::from(schema_title.dark_gray())
+                    .position(Position::Bottom)
+                    .alignment(Alignment::Right),
+            );
+
+        query_text_area.set_block(query_block);
+
+        frame.render_widget(query_text_area.widget(), graphql_layout[0]);
+        frame.render_widget(variables_text_area.widget(), graphql_layout[1]);
+    }
+}
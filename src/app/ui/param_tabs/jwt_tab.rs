@@ -0,0 +1,77 @@
+use crate::app::app::App;
+use crate::app::app_states::AppState::{EditingRequestAuthField, SelectedRequest};
+use crate::request::auth::{Jwt, JwtAlgorithm, JwtField, DEFAULT_JWT_EXPIRES_IN};
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+const LABEL_WIDTH: u16 = 15;
+
+impl App<'_> {
+    pub(super) fn render_jwt_tab(&mut self, frame: &mut Frame, area: Rect, jwt: &Jwt) {
+        let jwt_layout = Layout::new(Vertical, vec![Constraint::Length(1); Jwt::FIELDS.len()])
+            .vertical_margin(1)
+            .horizontal_margin(4)
+            .split(area);
+
+        // Prevent from rendering the selection while the tab is not focused
+        let should_color_selection =
+            matches!(self.state, SelectedRequest | EditingRequestAuthField);
+        let input_selected = self.auth_text_input_selection.selected;
+
+        for (index, field) in Jwt::FIELDS.iter().enumerate() {
+            let field_layout = Layout::new(
+                Horizontal,
+                [Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)],
+            )
+            .split(jwt_layout[index]);
+
+            let is_selected = should_color_selection && index == input_selected;
+
+            // Asymmetric algorithms sign with a private key read from a file
+            let label = match (field, jwt.algorithm) {
+                (JwtField::Secret, JwtAlgorithm::RS256 | JwtAlgorithm::ES256) => {
+                    String::from("Key file")
+                }
+                (field, _) => field.to_string(),
+            };
+
+            let label = match is_selected {
+                true => Line::from(label.yellow()),
+                false => Line::from(label),
+            };
+
+            let value = match field {
+                JwtField::Algorithm => Line::from(vec![
+                    jwt.algorithm.to_string().into(),
+                    " (Enter to change)".dark_gray(),
+                ]),
+                _ if is_selected && self.state == EditingRequestAuthField => {
+                    let (padded_text, input_cursor_position) = self
+                        .auth_field_text_input
+                        .get_padded_text_and_cursor(field_layout[1].width as usize);
+
+                    frame.set_cursor(
+                        field_layout[1].x + input_cursor_position as u16,
+                        field_layout[1].y,
+                    );
+
+                    self.add_color_to_env_keys(&padded_text)
+                }
+                JwtField::Claims if jwt.claims.trim().is_empty() => {
+                    Line::from("JSON object, iat and exp are added".dark_gray())
+                }
+                JwtField::ExpiresIn if jwt.expires_in.is_empty() => {
+                    Line::from(format!("{DEFAULT_JWT_EXPIRES_IN} seconds").dark_gray())
+                }
+                field => self.add_color_to_env_keys(&jwt.get_field(*field)),
+            };
+
+            frame.render_widget(Paragraph::new(label), field_layout[0]);
+            frame.render_widget(Paragraph::new(value), field_layout[1]);
+        }
+    }
+}
@@ -1,8 +1,14 @@
+mod api_key_tab;
+mod aws_sig_v4_tab;
 mod basic_auth_tab;
 mod bearer_token_tab;
 mod body_file_tab;
 mod body_form_tab;
+mod graphql_body_tab;
 mod headers_tab;
+mod jwt_tab;
+mod oauth1_tab;
+mod oauth2_tab;
 pub mod param_tabs;
 mod query_params_tab;
 mod script;
@@ -0,0 +1,63 @@
+use crate::app::app::App;
+use crate::app::app_states::AppState::{EditingRequestAuthField, SelectedRequest};
+use crate::request::auth::{OAuth1, OAuth1Field};
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+const LABEL_WIDTH: u16 = 17;
+
+impl App<'_> {
+    pub(super) fn render_oauth1_tab(&mut self, frame: &mut Frame, area: Rect, oauth1: &OAuth1) {
+        let oauth1_layout =
+            Layout::new(Vertical, vec![Constraint::Length(1); OAuth1::FIELDS.len()])
+                .vertical_margin(1)
+                .horizontal_margin(4)
+                .split(area);
+
+        // Prevent from rendering the selection while the tab is not focused
+        let should_color_selection =
+            matches!(self.state, SelectedRequest | EditingRequestAuthField);
+        let input_selected = self.auth_text_input_selection.selected;
+
+        for (index, field) in OAuth1::FIELDS.iter().enumerate() {
+            let field_layout = Layout::new(
+                Horizontal,
+                [Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)],
+            )
+            .split(oauth1_layout[index]);
+
+            let is_selected = should_color_selection && index == input_selected;
+
+            let label = match is_selected {
+                true => Line::from(field.to_string().yellow()),
+                false => Line::from(field.to_string()),
+            };
+
+            let value = match field {
+                _ if is_selected && self.state == EditingRequestAuthField => {
+                    let (padded_text, input_cursor_position) = self
+                        .auth_field_text_input
+                        .get_padded_text_and_cursor(field_layout[1].width as usize);
+
+                    frame.set_cursor(
+                        field_layout[1].x + input_cursor_position as u16,
+                        field_layout[1].y,
+                    );
+
+                    self.add_color_to_env_keys(&padded_text)
+                }
+                OAuth1Field::Token if oauth1.token.is_empty() => {
+                    Line::from("Empty for two-legged requests".dark_gray())
+                }
+                field => self.add_color_to_env_keys(oauth1.get_field(*field)),
+            };
+
+            frame.render_widget(Paragraph::new(label), field_layout[0]);
+            frame.render_widget(Paragraph::new(value), field_layout[1]);
+        }
+    }
+}
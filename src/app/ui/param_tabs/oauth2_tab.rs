@@ -0,0 +1,122 @@
+use std::time::Instant;
+
+use crate::app::app::App;
+use crate::app::app_logic::request::oauth2::{get_oauth2_token_state, OAuth2TokenState};
+use crate::app::app_states::AppState::{EditingRequestAuthField, SelectedRequest};
+use crate::request::auth::{OAuth2, OAuth2Field, DEFAULT_OAUTH2_REDIRECT_URI};
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Paragraph, Wrap};
+use ratatui::Frame;
+
+const LABEL_WIDTH: u16 = 15;
+
+impl App<'_> {
+    pub(super) fn render_oauth2_tab(&mut self, frame: &mut Frame, area: Rect, oauth2: &OAuth2) {
+        let fields = oauth2.fields();
+
+        let mut constraints = vec![Constraint::Length(1); fields.len()];
+        constraints.push(Constraint::Length(1));
+        constraints.push(Constraint::Fill(1));
+
+        let oauth2_layout = Layout::new(Vertical, constraints)
+            .vertical_margin(1)
+            .horizontal_margin(4)
+            .split(area);
+
+        // Prevent from rendering the selection while the tab is not focused
+        let should_color_selection =
+            matches!(self.state, SelectedRequest | EditingRequestAuthField);
+        let input_selected = self.auth_text_input_selection.selected;
+
+        for (index, field) in fields.iter().enumerate() {
+            let field_layout = Layout::new(
+                Horizontal,
+                [Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)],
+            )
+            .split(oauth2_layout[index]);
+
+            let is_selected = should_color_selection && index == input_selected;
+
+            let label = match is_selected {
+                true => Line::from(field.to_string().yellow()),
+                false => Line::from(field.to_string()),
+            };
+
+            let value = match field {
+                _ if is_selected && self.state == EditingRequestAuthField => {
+                    let (padded_text, input_cursor_position) = self
+                        .auth_field_text_input
+                        .get_padded_text_and_cursor(field_layout[1].width as usize);
+
+                    frame.set_cursor(
+                        field_layout[1].x + input_cursor_position as u16,
+                        field_layout[1].y,
+                    );
+
+                    self.add_color_to_env_keys(&padded_text)
+                }
+                OAuth2Field::GrantType => Line::from(vec![
+                    oauth2.grant_type.to_string().into(),
+                    " (Enter to change)".dark_gray(),
+                ]),
+                OAuth2Field::RedirectUri if oauth2.redirect_uri.is_empty() => {
+                    Line::from(DEFAULT_OAUTH2_REDIRECT_URI.dark_gray())
+                }
+                field => self.add_color_to_env_keys(&oauth2.get_field(*field)),
+            };
+
+            frame.render_widget(Paragraph::new(label), field_layout[0]);
+            frame.render_widget(Paragraph::new(value), field_layout[1]);
+        }
+
+        let token_state_paragraph =
+            Paragraph::new(self.oauth2_token_state_lines(oauth2)).wrap(Wrap { trim: false });
+
+        frame.render_widget(token_state_paragraph, oauth2_layout[fields.len() + 1]);
+    }
+
+    pub(super) fn oauth2_token_state_lines(&self, oauth2: &OAuth2) -> Vec<Line<'static>> {
+        match get_oauth2_token_state(&self.resolve_oauth2(oauth2)) {
+            None => vec![Line::from(
+                "No token yet, it will be fetched when sending the request".dark_gray(),
+            )],
+            Some(OAuth2TokenState::Fetching) => vec![Line::from("Fetching token...".yellow())],
+            Some(OAuth2TokenState::WaitingForAuthorization(authorization_url)) => vec![
+                Line::from("Waiting for authorization in the browser, or open:".yellow()),
+                Line::from(authorization_url),
+            ],
+            Some(OAuth2TokenState::Token(token)) => {
+                let token_state = match token.expires_at {
+                    _ if token.is_expired() => match token.refresh_token {
+                        None => "Token expired, a new one will be fetched".yellow(),
+                        Some(_) => "Token expired, it will be refreshed".yellow(),
+                    },
+                    None => "Token valid".green(),
+                    Some(expires_at) => format!(
+                        "Token valid, expires in {}s",
+                        expires_at
+                            .saturating_duration_since(Instant::now())
+                            .as_secs()
+                    )
+                    .green(),
+                };
+
+                vec![
+                    Line::from(token_state),
+                    Line::from(format!("Access token: {}", token.access_token).dark_gray()),
+                ]
+            }
+            Some(OAuth2TokenState::Error(error)) => error
+                .lines()
+                .enumerate()
+                .map(|(index, line)| match index {
+                    0 => Line::from(format!("Token error: {line}").red()),
+                    _ => Line::from(line.trim().to_string().red()),
+                })
+                .collect(),
+        }
+    }
+}
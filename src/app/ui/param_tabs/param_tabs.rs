@@ -41,7 +41,15 @@ impl App<'_> {
             },
             RequestParamsTabs::Auth => match request.auth {
                 NoAuth => tab.to_string(),
-                BasicAuth(_, _) | BearerToken(_) => {
+                BasicAuth(_, _)
+                | BearerToken(_)
+                | Digest(_, _)
+                | ApiKey(_)
+                | AwsSigV4(_)
+                | Jwt(_)
+                | OAuth1(_)
+                | OAuth2(_)
+                | InheritFromCollection => {
                     format!("{} ({})", tab, request.auth)
                 }
             },
@@ -51,8 +59,15 @@ impl App<'_> {
             },
             RequestParamsTabs::Body => match request.body {
                 NoBody => tab.to_string(),
-                Multipart(_) | Form(_) | File(_) | Raw(_) | Json(_) | Xml(_) | Html(_)
-                | Javascript(_) => format!("{} ({})", tab, request.body),
+                Multipart(_)
+                | Form(_)
+                | File(_)
+                | Raw(_)
+                | Json(_)
+                | Xml(_)
+                | Html(_)
+                | Javascript(_)
+                | GraphQL { .. } => format!("{} ({})", tab, request.body),
             },
             RequestParamsTabs::Scripts => tab.to_string(),
         });
@@ -102,12 +117,48 @@ impl App<'_> {
 
                     frame.render_widget(auth_paragraph, request_params_layout[1]);
                 }
-                BasicAuth(_, _) => {
+                BasicAuth(_, _) | Digest(_, _) => {
                     self.render_basic_auth_tab(frame, request_params_layout[1]);
                 }
                 BearerToken(_) => {
                     self.render_bearer_token_tab(frame, request_params_layout[1]);
                 }
+                ApiKey(api_key) => {
+                    self.render_api_key_tab(frame, request_params_layout[1], api_key);
+                }
+                AwsSigV4(aws_sig_v4) => {
+                    self.render_aws_sig_v4_tab(frame, request_params_layout[1], aws_sig_v4);
+                }
+                Jwt(jwt) => {
+                    self.render_jwt_tab(frame, request_params_layout[1], jwt);
+                }
+                OAuth1(oauth1) => {
+                    self.render_oauth1_tab(frame, request_params_layout[1], oauth1);
+                }
+                OAuth2(oauth2) => {
+                    self.render_oauth2_tab(frame, request_params_layout[1], oauth2);
+                }
+                InheritFromCollection => {
+                    let selected_request_index = self.collections_tree.selected.as_ref().unwrap();
+                    let collection_auth = self.collections[selected_request_index[0]]
+                        .resolve_auth(&request.auth)
+                        .clone();
+
+                    let mut auth_lines = vec![
+                        Line::default(),
+                        Line::from(format!("Inherited from collection: {collection_auth}")),
+                        Line::from("(Change auth method with ^a)".dark_gray()),
+                    ];
+
+                    if let OAuth2(oauth2) = &collection_auth {
+                        auth_lines.push(Line::default());
+                        auth_lines.extend(self.oauth2_token_state_lines(oauth2));
+                    }
+
+                    let auth_paragraph = Paragraph::new(auth_lines).centered();
+
+                    frame.render_widget(auth_paragraph, request_params_layout[1]);
+                }
             },
             RequestParamsTabs::Headers => match self.headers_table.selection {
                 None => {
@@ -172,6 +223,9 @@ impl App<'_> {
                         .set_line_number_style(Style::new().fg(Color::DarkGray));
                     frame.render_widget(self.body_text_area.widget(), request_params_layout[1]);
                 }
+                GraphQL { .. } => {
+                    self.render_graphql_body_tab(frame, request_params_layout[1], request);
+                }
             },
             RequestParamsTabs::Scripts => {
                 self.render_request_script(frame, request_params_layout[1]);
@@ -0,0 +1,46 @@
+use ratatui::layout::Constraint;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::Layout;
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Tabs};
+use ratatui::Frame;
+use strum::IntoEnumIterator;
+
+use crate::app::app::App;
+use crate::app::files::export::code_snippets::CodeLanguage;
+use crate::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_code_snippet_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title("Code snippet")
+            .borders(Borders::ALL)
+            .white()
+            .on_dark_gray();
+
+        let area = centered_rect(100, 30, frame.size());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let code_snippet_layout =
+            Layout::new(Vertical, [Constraint::Length(2), Constraint::Fill(1)])
+                .vertical_margin(1)
+                .horizontal_margin(1)
+                .split(area);
+
+        let languages = CodeLanguage::iter().map(|language| language.to_string());
+
+        let languages_tabs = Tabs::new(languages)
+            .highlight_style(Style::default().yellow())
+            .select(self.code_snippet_popup.language as usize)
+            .block(Block::new().borders(Borders::BOTTOM));
+
+        let snippet_paragraph = Paragraph::new(self.code_snippet_popup.highlighted_snippet.clone())
+            .scroll((self.code_snippet_popup.scroll, 0))
+            .on_black();
+
+        frame.render_widget(languages_tabs, code_snippet_layout[0]);
+        frame.render_widget(snippet_paragraph, code_snippet_layout[1]);
+    }
+}
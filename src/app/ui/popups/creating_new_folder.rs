@@ -0,0 +1,43 @@
+use crate::app::app::App;
+use crate::utils::centered_rect::centered_rect;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::{Color, Style};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+impl App<'_> {
+    pub fn render_creating_new_folder_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().bg(Color::DarkGray));
+
+        let area = centered_rect(50, 6, frame.size());
+
+        let new_folder_layout =
+            Layout::new(Vertical, vec![Constraint::Length(3), Constraint::Length(3)]).split(area);
+
+        let parent_name =
+            self.get_folder_display_path(self.new_folder_parent[0], &self.new_folder_parent[1..]);
+        let parent_paragraph =
+            Paragraph::new(parent_name).block(Block::new().title("Parent").borders(Borders::ALL));
+
+        let adjusted_input_length = new_folder_layout[1].width as usize - 2;
+        let (padded_text, input_cursor_position) = self
+            .new_folder_input
+            .get_padded_text_and_cursor(adjusted_input_length);
+
+        let new_folder_name_paragraph = Paragraph::new(padded_text)
+            .block(Block::new().title("Folder name").borders(Borders::ALL));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+        frame.render_widget(parent_paragraph, new_folder_layout[0]);
+        frame.render_widget(new_folder_name_paragraph, new_folder_layout[1]);
+
+        frame.set_cursor(
+            new_folder_layout[1].x + input_cursor_position as u16 + 1,
+            new_folder_layout[1].y + 1,
+        )
+    }
+}
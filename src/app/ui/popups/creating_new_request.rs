@@ -17,9 +17,10 @@ impl App<'_> {
         let new_request_layout =
             Layout::new(Vertical, vec![Constraint::Length(3), Constraint::Length(3)]).split(area);
 
-        let selected_collection_name = self.collections[self.new_request_popup.selected_collection]
-            .name
-            .clone();
+        let selected_collection_name = self.get_folder_display_path(
+            self.new_request_popup.selected_collection,
+            &self.new_request_popup.selected_folder,
+        );
         let selected_collection_paragraph = Paragraph::new(selected_collection_name)
             .block(Block::new().title("Collection ↑ ↓").borders(Borders::ALL));
 
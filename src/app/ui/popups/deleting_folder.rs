@@ -0,0 +1,41 @@
+use crate::app::app::App;
+use crate::utils::centered_rect::centered_rect;
+use ratatui::layout::Direction::Horizontal;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::{Color, Style};
+use ratatui::style::Color::Yellow;
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+impl App<'_> {
+    pub fn render_deleting_folder_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title("Confirm delete folder and its content")
+            .borders(Borders::ALL)
+            .style(Style::default().bg(Color::DarkGray));
+
+        let area = centered_rect(40, 3, frame.size());
+
+        let deleting_folder_layout = Layout::new(
+            Horizontal,
+            vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        )
+        .vertical_margin(1)
+        .horizontal_margin(1)
+        .split(area);
+
+        let mut no_paragraph = Paragraph::new("no").centered();
+        let mut yes_paragraph = Paragraph::new("yes").centered();
+
+        match self.delete_folder_popup.state {
+            false => no_paragraph = no_paragraph.fg(Yellow).bold(),
+            true => yes_paragraph = yes_paragraph.fg(Yellow).bold(),
+        }
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+        frame.render_widget(no_paragraph, deleting_folder_layout[0]);
+        frame.render_widget(yes_paragraph, deleting_folder_layout[1]);
+    }
+}
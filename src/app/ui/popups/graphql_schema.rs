@@ -0,0 +1,93 @@
+use ratatui::layout::Direction::Horizontal;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Padding, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::app::App;
+use crate::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_graphql_schema_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title("GraphQL schema")
+            .borders(Borders::ALL)
+            .white()
+            .on_dark_gray();
+
+        let area = centered_rect(100, 30, frame.size());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let schema_layout = Layout::new(
+            Horizontal,
+            [Constraint::Percentage(30), Constraint::Percentage(70)],
+        )
+        .vertical_margin(1)
+        .horizontal_margin(1)
+        .split(area);
+
+        let schema = match &self.graphql_schema_popup.schema {
+            None => return,
+            Some(schema) => schema,
+        };
+
+        let types: Vec<ListItem> = schema
+            .types
+            .iter()
+            .map(|graphql_type| {
+                let line = Line::from(vec![
+                    Span::raw(format!("{} ", graphql_type.keyword())).dark_gray(),
+                    Span::raw(graphql_type.name.clone()),
+                ]);
+
+                ListItem::new(line)
+            })
+            .collect();
+
+        let types_list = List::new(types)
+            .highlight_style(Style::default().yellow())
+            .block(Block::new().borders(Borders::RIGHT));
+
+        let mut fields_lines: Vec<Line> = vec![];
+
+        if let Some(selection) = self.graphql_schema_popup.types_state.selected() {
+            let graphql_type = &schema.types[selection];
+
+            if let Some(description) = &graphql_type.description {
+                fields_lines.extend(
+                    description
+                        .lines()
+                        .map(|line| Line::from(line.to_string()).dark_gray()),
+                );
+                fields_lines.push(Line::default());
+            }
+
+            for field in &graphql_type.fields {
+                let mut spans = vec![Span::raw(field.to_string())];
+
+                if let Some(description) = &field.description {
+                    spans.push(
+                        Span::raw(format!("  # {}", description.replace('\n', " "))).dark_gray(),
+                    );
+                }
+
+                fields_lines.push(Line::from(spans));
+            }
+        }
+
+        let fields_paragraph = Paragraph::new(fields_lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.graphql_schema_popup.scroll, 0))
+            .block(Block::new().padding(Padding::left(1)));
+
+        frame.render_stateful_widget(
+            types_list,
+            schema_layout[0],
+            &mut self.graphql_schema_popup.types_state,
+        );
+        frame.render_widget(fields_paragraph, schema_layout[1]);
+    }
+}
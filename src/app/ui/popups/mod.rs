@@ -1,10 +1,15 @@
+pub mod code_snippet;
 pub mod cookies;
 pub mod creating_element;
 pub mod creating_new_collection;
+pub mod creating_new_folder;
 pub mod creating_new_request;
 pub mod deleting_collection;
+pub mod deleting_folder;
 pub mod deleting_request;
+pub mod graphql_schema;
 pub mod help;
 pub mod renaming_collection;
+pub mod renaming_folder;
 pub mod renaming_request;
 pub mod request_settings;
@@ -0,0 +1,33 @@
+use crate::app::app::App;
+use crate::utils::centered_rect::centered_rect;
+use ratatui::prelude::{Color, Style};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+impl App<'_> {
+    pub fn render_renaming_folder_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title("Enter the new folder name")
+            .borders(Borders::ALL)
+            .style(Style::default().bg(Color::DarkGray));
+
+        let area = centered_rect(50, 3, frame.size());
+        let renaming_folder_area = popup_block.inner(area);
+
+        let adjusted_input_length = renaming_folder_area.width as usize;
+        let (padded_text, input_cursor_position) = self
+            .rename_folder_input
+            .get_padded_text_and_cursor(adjusted_input_length);
+
+        let new_folder_paragraph = Paragraph::new(padded_text);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+        frame.render_widget(new_folder_paragraph, renaming_folder_area);
+
+        frame.set_cursor(
+            renaming_folder_area.x + input_cursor_position as u16,
+            renaming_folder_area.y,
+        )
+    }
+}
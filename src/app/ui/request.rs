@@ -1,5 +1,5 @@
 use crate::app::app::App;
-use crate::app::app_states::AppState::EditingRequestUrl;
+use crate::app::app_states::AppState::{EditingRequestMethod, EditingRequestUrl};
 use crate::app::ui::views::RequestView;
 use crate::request::request::Request;
 use crate::request::method::Method;
@@ -23,6 +23,7 @@ pub fn method_to_color(method: &Method) -> Color {
         Method::DELETE => Color::LightRed,
         Method::HEAD => Color::Green,
         Method::OPTIONS => Color::Magenta,
+        Method::Custom(_) => Color::DarkGray,
     }
 }
 
@@ -80,7 +81,7 @@ impl App<'_> {
 
         // REQUEST METHOD
 
-        let method = request.method;
+        let method = &request.method;
 
         let method_block = Block::new()
             .title("Method")
@@ -90,13 +91,26 @@ impl App<'_> {
 
         let method_area = method_block.inner(request_header_layout[0]);
 
-        let method_paragraph = Paragraph::new(method.to_string())
-            .bg(method_to_color(&method))
-            .fg(White)
-            .centered();
-
         frame.render_widget(method_block, request_header_layout[0]);
-        frame.render_widget(method_paragraph, method_area);
+
+        if self.state == EditingRequestMethod {
+            let (padded_text, input_cursor_position) = self
+                .method_text_input
+                .get_padded_text_and_cursor(method_area.width as usize);
+
+            frame.render_widget(Paragraph::new(padded_text), method_area);
+            frame.set_cursor(
+                method_area.x + input_cursor_position as u16,
+                method_area.y,
+            );
+        } else {
+            let method_paragraph = Paragraph::new(method.to_string())
+                .bg(method_to_color(method))
+                .fg(White)
+                .centered();
+
+            frame.render_widget(method_paragraph, method_area);
+        }
 
         // REQUEST URL
 
@@ -50,7 +50,10 @@ impl App<'_> {
         let result_tabs = RequestResultTabs::iter().filter_map(|tab| match tab {
             RequestResultTabs::Body => {
                 if let Some(duration) = &request.response.duration {
-                    Some(format!("{} ({})", tab, duration))
+                    match request.response.attempts {
+                        0 | 1 => Some(format!("{} ({})", tab, duration)),
+                        attempts => Some(format!("{} ({}, {} attempts)", tab, duration, attempts)),
+                    }
                 } else {
                     Some(format!("{}", tab))
                 }
@@ -90,9 +93,17 @@ impl App<'_> {
         else {
             // REQUEST RESULT STATUS CODE
 
-            let status_code = match &request.response.status_code {
-                None => "",
-                Some(status_code) => status_code,
+            let status_code = match (
+                &request.websocket,
+                &request.response.http_version,
+                &request.response.status_code,
+            ) {
+                (Some(session), _, _) => session.status.to_string(),
+                (None, _, None) => String::new(),
+                (None, None, Some(status_code)) => status_code.clone(),
+                (None, Some(http_version), Some(status_code)) => {
+                    format!("{http_version} {status_code}")
+                }
             };
 
             let status_code_paragraph = Paragraph::new(status_code).centered().dark_gray();
@@ -101,6 +112,13 @@ impl App<'_> {
             // REQUEST RESULT CONTENT
 
             match self.request_result_tab {
+                RequestResultTabs::Body if request.websocket.is_some() => {
+                    self.render_websocket_session(
+                        frame,
+                        request_result_layout[2],
+                        request.websocket.as_ref().unwrap(),
+                    );
+                }
                 RequestResultTabs::Body => match &request.response.content {
                     None => {}
                     Some(content) => match content {
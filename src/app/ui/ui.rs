@@ -76,7 +76,7 @@ impl App<'_> {
 
         // REQUEST
 
-        match self.collections_tree.selected {
+        match self.collections_tree.selected.clone() {
             None => self.render_homepage(frame, inner_layout[1]),
             Some(selection) => {
                 let selected_request = self
@@ -108,11 +108,16 @@ impl App<'_> {
             ChoosingElementToCreate => self.render_creating_element_popup(frame),
             CreatingNewCollection => self.render_creating_new_collection_popup(frame),
             CreatingNewRequest => self.render_creating_new_request_popup(frame),
+            CreatingNewFolder => self.render_creating_new_folder_popup(frame),
             DeletingCollection => self.render_deleting_collection_popup(frame),
             DeletingRequest => self.render_deleting_request_popup(frame),
+            DeletingFolder => self.render_deleting_folder_popup(frame),
             EditingRequestSettings => self.render_request_settings_popup(frame),
+            DisplayingCodeSnippet => self.render_code_snippet_popup(frame),
+            DisplayingGraphQLSchema => self.render_graphql_schema_popup(frame),
             RenamingCollection => self.render_renaming_collection_popup(frame),
             RenamingRequest => self.render_renaming_request_popup(frame),
+            RenamingFolder => self.render_renaming_folder_popup(frame),
             _ => {}
         }
 
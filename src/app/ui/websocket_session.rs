@@ -0,0 +1,98 @@
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use crate::app::app::App;
+use crate::app::app_states::AppState;
+use crate::request::websocket::{WebSocketDirection, WebSocketSession};
+
+impl App<'_> {
+    /// Message log, newest first, and the message input while the connection is open
+    pub(super) fn render_websocket_session(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        session: &WebSocketSession,
+    ) {
+        let input_height = match session.is_open() {
+            true => 3,
+            false => 0,
+        };
+
+        let websocket_layout = Layout::new(
+            Vertical,
+            [Constraint::Length(input_height), Constraint::Fill(1)],
+        )
+        .split(area);
+
+        // MESSAGE INPUT
+
+        if session.is_open() {
+            let is_editing = self.state == AppState::EditingWebSocketMessage;
+
+            let adjusted_input_length = websocket_layout[0].width.saturating_sub(2) as usize;
+            let (padded_text, input_cursor_position) = self
+                .websocket_message_text_input
+                .get_padded_text_and_cursor(adjusted_input_length);
+
+            let input_block = match is_editing {
+                true => Block::new().title("Message").borders(Borders::ALL).yellow(),
+                false => Block::new()
+                    .title("Message")
+                    .borders(Borders::ALL)
+                    .dark_gray(),
+            };
+
+            let input_paragraph =
+                Paragraph::new(self.add_color_to_env_keys(&padded_text)).block(input_block);
+
+            frame.render_widget(input_paragraph, websocket_layout[0]);
+
+            if is_editing {
+                frame.set_cursor(
+                    websocket_layout[0].x + input_cursor_position as u16 + 1,
+                    websocket_layout[0].y + 1,
+                )
+            }
+        }
+
+        // MESSAGES
+
+        let mut lines: Vec<Line> = vec![];
+
+        for message in session.messages.iter().rev() {
+            let direction = match message.direction {
+                WebSocketDirection::Sent => Span::raw("↑ Sent").green(),
+                WebSocketDirection::Received => Span::raw("↓ Received").cyan(),
+            };
+
+            lines.push(Line::from(vec![
+                direction,
+                Span::raw(format!(" {}", message.time)).dark_gray(),
+            ]));
+            lines.extend(
+                message
+                    .content
+                    .lines()
+                    .map(|line| Line::raw(line.to_string())),
+            );
+            lines.push(Line::default());
+        }
+
+        if lines.is_empty() {
+            lines.push(Line::raw("No message yet").dark_gray().centered());
+        }
+
+        self.result_vertical_scrollbar.set_max_scroll(lines.len());
+
+        let messages_paragraph = Paragraph::new(lines).scroll((
+            self.result_vertical_scrollbar.scroll,
+            self.result_horizontal_scrollbar.scroll,
+        ));
+
+        frame.render_widget(messages_paragraph, websocket_layout[1]);
+    }
+}
@@ -12,6 +12,7 @@ use ratatui::crossterm::ExecutableCommand;
 use ratatui::Terminal;
 
 use crate::app::app::App;
+use crate::app::startup::args::ARGS;
 
 mod app;
 mod request;
@@ -19,14 +20,22 @@ mod utils;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let mut app = App::new();
+
+    app.startup();
+
+    // Headless commands print their result and exit without starting the TUI
+    if ARGS.is_headless {
+        let is_success = app.handle_headless_command().await;
+        exit(match is_success {
+            true => 0,
+            false => 1,
+        });
+    }
+
     let terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
-    App::new()
-        .startup()
-        .prepare_terminal()
-        .chain_hook()
-        .run(terminal)
-        .await?;
+    app.prepare_terminal().chain_hook().run(terminal).await?;
 
     stdout().execute(LeaveAlternateScreen)?;
     disable_raw_mode()?;
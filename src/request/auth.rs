@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
+pub const DEFAULT_OAUTH2_REDIRECT_URI: &str = "http://127.0.0.1:8910/callback";
+
 #[derive(Clone, Default, Debug, Display, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Auth {
@@ -11,4 +13,402 @@ pub enum Auth {
     BasicAuth(String, String),
     #[strum(to_string = "Bearer")]
     BearerToken(String),
+    /// Username and password, answering the challenge of the server
+    #[strum(to_string = "Digest")]
+    Digest(String, String),
+    #[strum(to_string = "API Key")]
+    ApiKey(ApiKey),
+    /// Signs the final request, just before it is sent
+    #[strum(to_string = "AWS Signature")]
+    AwsSigV4(AwsSigV4),
+    /// Signed on each send, then sent as a bearer token
+    #[strum(to_string = "JWT")]
+    Jwt(Jwt),
+    /// Signed with HMAC-SHA1, just before the request is sent
+    #[serde(rename = "oauth1")]
+    #[strum(to_string = "OAuth1")]
+    OAuth1(OAuth1),
+    #[serde(rename = "oauth2")]
+    #[strum(to_string = "OAuth2")]
+    OAuth2(OAuth2),
+    /// Use the auth of the collection containing the request
+    #[strum(to_string = "Inherit")]
+    InheritFromCollection,
+}
+
+impl Auth {
+    pub fn is_no_auth(&self) -> bool {
+        matches!(self, Auth::NoAuth)
+    }
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub key: String,
+    pub value: String,
+    #[serde(default)]
+    pub location: ApiKeyLocation,
+}
+
+/// Where the API key is sent
+#[derive(Clone, Copy, Default, Debug, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyLocation {
+    #[default]
+    #[strum(to_string = "Header")]
+    Header,
+    #[strum(to_string = "Query param")]
+    Query,
+}
+
+pub fn next_api_key_location(location: &ApiKeyLocation) -> ApiKeyLocation {
+    match location {
+        ApiKeyLocation::Header => ApiKeyLocation::Query,
+        ApiKeyLocation::Query => ApiKeyLocation::Header,
+    }
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct AwsSigV4 {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// Only needed for temporary credentials
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub session_token: String,
+    pub region: String,
+    pub service: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display)]
+pub enum AwsSigV4Field {
+    #[strum(to_string = "Access key ID")]
+    AccessKeyId,
+    #[strum(to_string = "Secret key")]
+    SecretAccessKey,
+    #[strum(to_string = "Session token")]
+    SessionToken,
+    #[strum(to_string = "Region")]
+    Region,
+    #[strum(to_string = "Service")]
+    Service,
+}
+
+impl AwsSigV4 {
+    pub const FIELDS: [AwsSigV4Field; 5] = [
+        AwsSigV4Field::AccessKeyId,
+        AwsSigV4Field::SecretAccessKey,
+        AwsSigV4Field::SessionToken,
+        AwsSigV4Field::Region,
+        AwsSigV4Field::Service,
+    ];
+
+    pub fn get_field(&self, field: AwsSigV4Field) -> &String {
+        match field {
+            AwsSigV4Field::AccessKeyId => &self.access_key_id,
+            AwsSigV4Field::SecretAccessKey => &self.secret_access_key,
+            AwsSigV4Field::SessionToken => &self.session_token,
+            AwsSigV4Field::Region => &self.region,
+            AwsSigV4Field::Service => &self.service,
+        }
+    }
+
+    pub fn get_field_mut(&mut self, field: AwsSigV4Field) -> &mut String {
+        match field {
+            AwsSigV4Field::AccessKeyId => &mut self.access_key_id,
+            AwsSigV4Field::SecretAccessKey => &mut self.secret_access_key,
+            AwsSigV4Field::SessionToken => &mut self.session_token,
+            AwsSigV4Field::Region => &mut self.region,
+            AwsSigV4Field::Service => &mut self.service,
+        }
+    }
+
+    /// Every text value, used to replace the variables and environment keys
+    pub fn values_mut(&mut self) -> Vec<&mut String> {
+        vec![
+            &mut self.access_key_id,
+            &mut self.secret_access_key,
+            &mut self.session_token,
+            &mut self.region,
+            &mut self.service,
+        ]
+    }
+}
+
+pub const DEFAULT_JWT_EXPIRES_IN: u64 = 300;
+
+/// The `iat` and `exp` claims are computed on each send
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct Jwt {
+    pub algorithm: JwtAlgorithm,
+    /// Secret for HMAC, path to a PEM private key file otherwise
+    #[serde(default)]
+    pub secret: String,
+    /// JSON object template of the claims
+    #[serde(default)]
+    pub claims: String,
+    /// Lifetime of the token in seconds, DEFAULT_JWT_EXPIRES_IN when empty
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub expires_in: String,
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Display, Serialize, Deserialize)]
+pub enum JwtAlgorithm {
+    #[default]
+    HS256,
+    RS256,
+    ES256,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display)]
+pub enum JwtField {
+    #[strum(to_string = "Algorithm")]
+    Algorithm,
+    #[strum(to_string = "Secret")]
+    Secret,
+    #[strum(to_string = "Claims")]
+    Claims,
+    #[strum(to_string = "Expires in")]
+    ExpiresIn,
+}
+
+impl Jwt {
+    pub const FIELDS: [JwtField; 4] = [
+        JwtField::Algorithm,
+        JwtField::Secret,
+        JwtField::Claims,
+        JwtField::ExpiresIn,
+    ];
+
+    pub fn get_field(&self, field: JwtField) -> String {
+        match field {
+            JwtField::Algorithm => self.algorithm.to_string(),
+            JwtField::Secret => self.secret.clone(),
+            JwtField::Claims => self.claims.clone(),
+            JwtField::ExpiresIn => self.expires_in.clone(),
+        }
+    }
+
+    /// None for the algorithm, which is not a text field
+    pub fn get_field_mut(&mut self, field: JwtField) -> Option<&mut String> {
+        match field {
+            JwtField::Algorithm => None,
+            JwtField::Secret => Some(&mut self.secret),
+            JwtField::Claims => Some(&mut self.claims),
+            JwtField::ExpiresIn => Some(&mut self.expires_in),
+        }
+    }
+
+    /// Every text value, used to replace the variables and environment keys
+    pub fn values_mut(&mut self) -> Vec<&mut String> {
+        vec![&mut self.secret, &mut self.claims, &mut self.expires_in]
+    }
+}
+
+pub fn next_jwt_algorithm(algorithm: &JwtAlgorithm) -> JwtAlgorithm {
+    match algorithm {
+        JwtAlgorithm::HS256 => JwtAlgorithm::RS256,
+        JwtAlgorithm::RS256 => JwtAlgorithm::ES256,
+        JwtAlgorithm::ES256 => JwtAlgorithm::HS256,
+    }
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct OAuth1 {
+    pub consumer_key: String,
+    pub consumer_secret: String,
+    /// Empty for two-legged requests, only signed with the consumer credentials
+    #[serde(default)]
+    pub token: String,
+    #[serde(default)]
+    pub token_secret: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display)]
+pub enum OAuth1Field {
+    #[strum(to_string = "Consumer key")]
+    ConsumerKey,
+    #[strum(to_string = "Consumer secret")]
+    ConsumerSecret,
+    #[strum(to_string = "Token")]
+    Token,
+    #[strum(to_string = "Token secret")]
+    TokenSecret,
+}
+
+impl OAuth1 {
+    pub const FIELDS: [OAuth1Field; 4] = [
+        OAuth1Field::ConsumerKey,
+        OAuth1Field::ConsumerSecret,
+        OAuth1Field::Token,
+        OAuth1Field::TokenSecret,
+    ];
+
+    pub fn get_field(&self, field: OAuth1Field) -> &String {
+        match field {
+            OAuth1Field::ConsumerKey => &self.consumer_key,
+            OAuth1Field::ConsumerSecret => &self.consumer_secret,
+            OAuth1Field::Token => &self.token,
+            OAuth1Field::TokenSecret => &self.token_secret,
+        }
+    }
+
+    pub fn get_field_mut(&mut self, field: OAuth1Field) -> &mut String {
+        match field {
+            OAuth1Field::ConsumerKey => &mut self.consumer_key,
+            OAuth1Field::ConsumerSecret => &mut self.consumer_secret,
+            OAuth1Field::Token => &mut self.token,
+            OAuth1Field::TokenSecret => &mut self.token_secret,
+        }
+    }
+
+    /// Every text value, used to replace the variables and environment keys
+    pub fn values_mut(&mut self) -> Vec<&mut String> {
+        vec![
+            &mut self.consumer_key,
+            &mut self.consumer_secret,
+            &mut self.token,
+            &mut self.token_secret,
+        ]
+    }
+}
+
+/// The access token is fetched before sending the request, then cached until it expires
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct OAuth2 {
+    pub grant_type: OAuth2GrantType,
+    #[serde(default)]
+    pub access_token_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub authorization_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub redirect_uri: String,
+    #[serde(default)]
+    pub client_id: String,
+    #[serde(default)]
+    pub client_secret: String,
+    #[serde(default)]
+    pub scope: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub username: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub password: String,
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2GrantType {
+    #[default]
+    #[strum(to_string = "Client credentials")]
+    ClientCredentials,
+    #[strum(to_string = "Password")]
+    Password,
+    /// Authorization code with PKCE, the code is received on a local redirect URI
+    #[strum(to_string = "Authorization code (PKCE)")]
+    AuthorizationCodePkce,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display)]
+pub enum OAuth2Field {
+    #[strum(to_string = "Grant type")]
+    GrantType,
+    #[strum(to_string = "Auth URL")]
+    AuthorizationUrl,
+    #[strum(to_string = "Token URL")]
+    AccessTokenUrl,
+    #[strum(to_string = "Redirect URI")]
+    RedirectUri,
+    #[strum(to_string = "Client ID")]
+    ClientId,
+    #[strum(to_string = "Client secret")]
+    ClientSecret,
+    #[strum(to_string = "Scope")]
+    Scope,
+    #[strum(to_string = "Username")]
+    Username,
+    #[strum(to_string = "Password")]
+    Password,
+}
+
+impl OAuth2 {
+    /// Fields used by the grant type, in display order
+    pub fn fields(&self) -> Vec<OAuth2Field> {
+        match self.grant_type {
+            OAuth2GrantType::ClientCredentials => vec![
+                OAuth2Field::GrantType,
+                OAuth2Field::AccessTokenUrl,
+                OAuth2Field::ClientId,
+                OAuth2Field::ClientSecret,
+                OAuth2Field::Scope,
+            ],
+            OAuth2GrantType::Password => vec![
+                OAuth2Field::GrantType,
+                OAuth2Field::AccessTokenUrl,
+                OAuth2Field::ClientId,
+                OAuth2Field::ClientSecret,
+                OAuth2Field::Scope,
+                OAuth2Field::Username,
+                OAuth2Field::Password,
+            ],
+            OAuth2GrantType::AuthorizationCodePkce => vec![
+                OAuth2Field::GrantType,
+                OAuth2Field::AuthorizationUrl,
+                OAuth2Field::AccessTokenUrl,
+                OAuth2Field::RedirectUri,
+                OAuth2Field::ClientId,
+                OAuth2Field::ClientSecret,
+                OAuth2Field::Scope,
+            ],
+        }
+    }
+
+    pub fn get_field(&self, field: OAuth2Field) -> String {
+        match field {
+            OAuth2Field::GrantType => self.grant_type.to_string(),
+            OAuth2Field::AuthorizationUrl => self.authorization_url.clone(),
+            OAuth2Field::AccessTokenUrl => self.access_token_url.clone(),
+            OAuth2Field::RedirectUri => self.redirect_uri.clone(),
+            OAuth2Field::ClientId => self.client_id.clone(),
+            OAuth2Field::ClientSecret => self.client_secret.clone(),
+            OAuth2Field::Scope => self.scope.clone(),
+            OAuth2Field::Username => self.username.clone(),
+            OAuth2Field::Password => self.password.clone(),
+        }
+    }
+
+    /// None for the grant type, which is not a text field
+    pub fn get_field_mut(&mut self, field: OAuth2Field) -> Option<&mut String> {
+        match field {
+            OAuth2Field::GrantType => None,
+            OAuth2Field::AuthorizationUrl => Some(&mut self.authorization_url),
+            OAuth2Field::AccessTokenUrl => Some(&mut self.access_token_url),
+            OAuth2Field::RedirectUri => Some(&mut self.redirect_uri),
+            OAuth2Field::ClientId => Some(&mut self.client_id),
+            OAuth2Field::ClientSecret => Some(&mut self.client_secret),
+            OAuth2Field::Scope => Some(&mut self.scope),
+            OAuth2Field::Username => Some(&mut self.username),
+            OAuth2Field::Password => Some(&mut self.password),
+        }
+    }
+
+    /// Every text value, used to replace the variables and environment keys
+    pub fn values_mut(&mut self) -> Vec<&mut String> {
+        vec![
+            &mut self.access_token_url,
+            &mut self.authorization_url,
+            &mut self.redirect_uri,
+            &mut self.client_id,
+            &mut self.client_secret,
+            &mut self.scope,
+            &mut self.username,
+            &mut self.password,
+        ]
+    }
+}
+
+pub fn next_oauth2_grant_type(grant_type: &OAuth2GrantType) -> OAuth2GrantType {
+    match grant_type {
+        OAuth2GrantType::ClientCredentials => OAuth2GrantType::Password,
+        OAuth2GrantType::Password => OAuth2GrantType::AuthorizationCodePkce,
+        OAuth2GrantType::AuthorizationCodePkce => OAuth2GrantType::ClientCredentials,
+    }
 }
@@ -26,6 +26,14 @@ pub enum ContentType {
     Html(String),
     #[strum(to_string = "Javascript")]
     Javascript(String),
+    #[strum(to_string = "GraphQL")]
+    #[serde(rename = "graphql")]
+    GraphQL {
+        query: String,
+        /// JSON object, can be empty
+        #[serde(default)]
+        variables: String,
+    },
 }
 
 impl ContentType {
@@ -36,6 +44,8 @@ impl ContentType {
             Form(_) => String::from("application/x-www-form-urlencoded"),
             Raw(_) => String::from("text/plain"),
             File(_) => String::from("application/octet-stream"),
+            // Sent as a JSON envelope
+            GraphQL { .. } => String::from("application/json"),
             Json(_) | Xml(_) | Html(_) | Javascript(_) => {
                 format!("application/{}", self.to_string().to_lowercase())
             }
@@ -1,4 +1,7 @@
-use crate::request::request::Request;
+use crate::request::auth::Auth;
+use crate::request::body::ContentType;
+use crate::request::request::{KeyValue, Request};
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -8,8 +11,24 @@ use strum::Display;
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Collection {
     pub name: String,
+
+    /// Sent with every request of the collection, unless the request has a header with the same name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<KeyValue>,
+
+    /// Used by the requests whose auth is inherited from the collection
+    #[serde(default, skip_serializing_if = "Auth::is_no_auth")]
+    pub auth: Auth,
+
+    /// Replace the {{keys}} of the requests, the selected environment values take precedence
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub variables: IndexMap<String, String>,
+
     pub requests: Vec<Arc<RwLock<Request>>>,
 
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub folders: Vec<Folder>,
+
     #[serde(skip)]
     pub path: PathBuf,
 
@@ -17,6 +36,27 @@ pub struct Collection {
     pub file_format: CollectionFileFormat,
 }
 
+/// Named group of requests inside a collection, can contain other folders
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Folder {
+    pub name: String,
+
+    #[serde(default)]
+    pub requests: Vec<Arc<RwLock<Request>>>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub folders: Vec<Folder>,
+}
+
+/// Folders and requests of a collection or of a folder
+pub type ChildrenMut<'a> = (&'a mut Vec<Folder>, &'a mut Vec<Arc<RwLock<Request>>>);
+
+/// Element found at a tree path inside a collection
+pub enum CollectionElement<'a> {
+    Folder(&'a Folder),
+    Request(&'a Arc<RwLock<Request>>),
+}
+
 #[derive(Debug, Default, Copy, Clone, Display, Serialize, Deserialize)]
 pub enum CollectionFileFormat {
     #[default]
@@ -27,3 +67,201 @@ pub enum CollectionFileFormat {
     #[strum(to_string = "yaml")]
     Yaml,
 }
+
+// In the collections tree, the children of a collection or of a folder are its folders followed by its requests.
+// A tree path is made of the child indexes leading to an element, the collection index excluded.
+
+impl Collection {
+    pub fn get_element(&self, tree_path: &[usize]) -> Option<CollectionElement<'_>> {
+        get_element(&self.folders, &self.requests, tree_path)
+    }
+
+    pub fn get_request(&self, tree_path: &[usize]) -> Option<Arc<RwLock<Request>>> {
+        match self.get_element(tree_path) {
+            Some(CollectionElement::Request(request)) => Some(request.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_folder(&self, tree_path: &[usize]) -> Option<&Folder> {
+        match self.get_element(tree_path) {
+            Some(CollectionElement::Folder(folder)) => Some(folder),
+            _ => None,
+        }
+    }
+
+    /// Folders and requests of the folder at the given tree path, or of the collection itself if the path is empty
+    pub fn get_children_mut(&mut self, tree_path: &[usize]) -> Option<ChildrenMut<'_>> {
+        let mut folders = &mut self.folders;
+        let mut requests = &mut self.requests;
+
+        for &index in tree_path {
+            let folder = folders.get_mut(index)?;
+
+            folders = &mut folder.folders;
+            requests = &mut folder.requests;
+        }
+
+        Some((folders, requests))
+    }
+
+    /// Auth to use for a request, the collection one if the request inherits it
+    pub fn resolve_auth<'a>(&'a self, auth: &'a Auth) -> &'a Auth {
+        match (auth, &self.auth) {
+            // A collection cannot inherit its auth
+            (Auth::InheritFromCollection, Auth::InheritFromCollection) => &Auth::NoAuth,
+            (Auth::InheritFromCollection, collection_auth) => collection_auth,
+            (auth, _) => auth,
+        }
+    }
+
+    /// Add the collection headers before the request ones, unless the request already has them
+    pub fn apply_headers(&self, request: &mut Request) {
+        let collection_headers = self.headers.iter().filter(|collection_header| {
+            collection_header.enabled
+                && !request.headers.iter().any(|header| {
+                    header.enabled
+                        && header
+                            .data
+                            .0
+                            .eq_ignore_ascii_case(&collection_header.data.0)
+                })
+        });
+
+        request.headers = collection_headers
+            .chain(request.headers.iter())
+            .cloned()
+            .collect();
+    }
+
+    /// Replace the {{keys}} of the request by the collection variables values, except the excluded keys
+    pub fn apply_variables(&self, request: &mut Request, excluded_keys: &[String]) {
+        let variables: Vec<(String, &String)> = self
+            .variables
+            .iter()
+            .filter(|(key, _)| !excluded_keys.contains(key))
+            .map(|(key, value)| (format!("{{{{{key}}}}}"), value))
+            .collect();
+
+        if variables.is_empty() {
+            return;
+        }
+
+        let replace = |input: &mut String| {
+            for (key, value) in &variables {
+                *input = input.replace(key, value);
+            }
+        };
+
+        replace(&mut request.url);
+
+        for key_value in request.params.iter_mut().chain(request.headers.iter_mut()) {
+            replace(&mut key_value.data.0);
+            replace(&mut key_value.data.1);
+        }
+
+        match &mut request.auth {
+            Auth::NoAuth | Auth::InheritFromCollection => {}
+            Auth::BasicAuth(username, password) | Auth::Digest(username, password) => {
+                replace(username);
+                replace(password);
+            }
+            Auth::BearerToken(bearer_token) => replace(bearer_token),
+            Auth::ApiKey(api_key) => {
+                replace(&mut api_key.key);
+                replace(&mut api_key.value);
+            }
+            Auth::AwsSigV4(aws_sig_v4) => {
+                for value in aws_sig_v4.values_mut() {
+                    replace(value);
+                }
+            }
+            Auth::Jwt(jwt) => {
+                for value in jwt.values_mut() {
+                    replace(value);
+                }
+            }
+            Auth::OAuth1(oauth1) => {
+                for value in oauth1.values_mut() {
+                    replace(value);
+                }
+            }
+            Auth::OAuth2(oauth2) => {
+                for value in oauth2.values_mut() {
+                    replace(value);
+                }
+            }
+        }
+
+        match &mut request.body {
+            ContentType::NoBody => {}
+            ContentType::Multipart(form_data) | ContentType::Form(form_data) => {
+                for key_value in form_data {
+                    replace(&mut key_value.data.0);
+                    replace(&mut key_value.data.1);
+                }
+            }
+            ContentType::File(body)
+            | ContentType::Raw(body)
+            | ContentType::Json(body)
+            | ContentType::Xml(body)
+            | ContentType::Html(body)
+            | ContentType::Javascript(body) => replace(body),
+            ContentType::GraphQL { query, variables } => {
+                replace(query);
+                replace(variables);
+            }
+        }
+    }
+
+    /// Every request of the collection, in tree order
+    pub fn all_requests(&self) -> Vec<Arc<RwLock<Request>>> {
+        let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+
+        for folder in &self.folders {
+            requests.extend(folder.all_requests());
+        }
+
+        requests.extend(self.requests.iter().cloned());
+
+        requests
+    }
+}
+
+impl Folder {
+    pub fn all_requests(&self) -> Vec<Arc<RwLock<Request>>> {
+        let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+
+        for folder in &self.folders {
+            requests.extend(folder.all_requests());
+        }
+
+        requests.extend(self.requests.iter().cloned());
+
+        requests
+    }
+}
+
+fn get_element<'a>(
+    folders: &'a [Folder],
+    requests: &'a [Arc<RwLock<Request>>],
+    tree_path: &[usize],
+) -> Option<CollectionElement<'a>> {
+    let (&index, sub_path) = tree_path.split_first()?;
+
+    if index < folders.len() {
+        let folder = &folders[index];
+
+        match sub_path.is_empty() {
+            true => Some(CollectionElement::Folder(folder)),
+            false => get_element(&folder.folders, &folder.requests, sub_path),
+        }
+    } else {
+        match sub_path.is_empty() {
+            true => requests
+                .get(index - folders.len())
+                .map(CollectionElement::Request),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,539 @@
+use std::fmt::{Display, Formatter};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Standard introspection query, as sent by GraphiQL without the directives
+pub const INTROSPECTION_QUERY: &str = r#"query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      description
+      fields(includeDeprecated: true) {
+        name
+        description
+        args { name type { ...TypeRef } }
+        type { ...TypeRef }
+      }
+      inputFields { name description type { ...TypeRef } }
+      enumValues(includeDeprecated: true) { name description }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType { kind name ofType { kind name ofType { kind name ofType { kind name
+    ofType { kind name ofType { kind name } } } } } }
+}"#;
+
+const OPERATION_KEYWORDS: [&str; 4] = ["query", "mutation", "subscription", "fragment"];
+
+/// Build the standard JSON envelope of a GraphQL request.
+/// The variables are omitted when empty, and must otherwise be a JSON object
+pub fn graphql_payload(query: &str, variables: &str) -> Result<String, String> {
+    let mut payload = Map::new();
+
+    payload.insert(String::from("query"), Value::String(query.to_string()));
+
+    if !variables.trim().is_empty() {
+        match serde_json::from_str::<Value>(variables) {
+            Ok(variables @ Value::Object(_)) => {
+                payload.insert(String::from("variables"), variables);
+            }
+            Ok(_) => return Err(String::from("The variables must be a JSON object")),
+            Err(e) => return Err(format!("Could not parse the variables\n\t{e}")),
+        }
+    }
+
+    Ok(Value::Object(payload).to_string())
+}
+
+/// Same as graphql_payload, but the variables are written as they are when they cannot be parsed,
+/// e.g. because of an environment key used as a number
+pub fn graphql_export_payload(query: &str, variables: &str) -> String {
+    match graphql_payload(query, variables) {
+        Ok(payload) => payload,
+        Err(_) => format!(
+            "{{\"query\":{},\"variables\":{}}}",
+            Value::String(query.to_string()),
+            variables.trim()
+        ),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphQLSchema {
+    pub query_type: Option<String>,
+    pub mutation_type: Option<String>,
+    pub subscription_type: Option<String>,
+    pub types: Vec<GraphQLType>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphQLType {
+    pub name: String,
+    /// e.g. OBJECT, INPUT_OBJECT, ENUM
+    pub kind: String,
+    pub description: Option<String>,
+    /// Also contains the input fields and the enum values
+    pub fields: Vec<GraphQLField>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphQLField {
+    pub name: String,
+    pub description: Option<String>,
+    pub arguments: Vec<(String, String)>,
+    /// Rendered type, e.g. `[User!]!`, empty for enum values
+    pub type_name: String,
+}
+
+impl GraphQLType {
+    pub fn keyword(&self) -> &str {
+        match self.kind.as_str() {
+            "OBJECT" => "type",
+            "INTERFACE" => "interface",
+            "UNION" => "union",
+            "ENUM" => "enum",
+            "INPUT_OBJECT" => "input",
+            "SCALAR" => "scalar",
+            _ => "",
+        }
+    }
+
+    /// Introspection types, e.g. __Schema or __Type
+    pub fn is_builtin(&self) -> bool {
+        self.name.starts_with("__")
+    }
+}
+
+impl Display for GraphQLField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        if !self.arguments.is_empty() {
+            let arguments = self
+                .arguments
+                .iter()
+                .map(|(name, type_name)| format!("{name}: {type_name}"))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            write!(f, "({arguments})")?;
+        }
+
+        if !self.type_name.is_empty() {
+            write!(f, ": {}", self.type_name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* INTROSPECTION RESPONSE */
+
+#[derive(Deserialize)]
+struct IntrospectionResponse {
+    data: Option<IntrospectionData>,
+    #[serde(default)]
+    errors: Vec<IntrospectionError>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: RawSchema,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSchema {
+    query_type: Option<NamedType>,
+    mutation_type: Option<NamedType>,
+    subscription_type: Option<NamedType>,
+    types: Vec<RawType>,
+}
+
+#[derive(Deserialize)]
+struct NamedType {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawType {
+    kind: String,
+    name: Option<String>,
+    description: Option<String>,
+    fields: Option<Vec<RawField>>,
+    input_fields: Option<Vec<RawInputValue>>,
+    enum_values: Option<Vec<RawEnumValue>>,
+}
+
+#[derive(Deserialize)]
+struct RawField {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    args: Vec<RawInputValue>,
+    #[serde(rename = "type")]
+    type_ref: TypeRef,
+}
+
+#[derive(Deserialize)]
+struct RawInputValue {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    type_ref: TypeRef,
+}
+
+#[derive(Deserialize)]
+struct RawEnumValue {
+    name: String,
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeRef {
+    kind: String,
+    name: Option<String>,
+    of_type: Option<Box<TypeRef>>,
+}
+
+impl Display for TypeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.kind.as_str(), &self.of_type) {
+            ("NON_NULL", Some(of_type)) => write!(f, "{of_type}!"),
+            ("LIST", Some(of_type)) => write!(f, "[{of_type}]"),
+            _ => write!(f, "{}", self.name.as_deref().unwrap_or("?")),
+        }
+    }
+}
+
+impl GraphQLSchema {
+    /// Parse the response body of the introspection query
+    pub fn from_introspection_response(body: &str) -> Result<GraphQLSchema, String> {
+        let response = match serde_json::from_str::<IntrospectionResponse>(body) {
+            Ok(response) => response,
+            Err(e) => return Err(format!("Could not parse the introspection response\n\t{e}")),
+        };
+
+        let raw_schema = match response.data {
+            Some(data) => data.schema,
+            None => {
+                let messages = response
+                    .errors
+                    .into_iter()
+                    .map(|error| error.message)
+                    .collect::<Vec<String>>()
+                    .join("\n\t");
+
+                return Err(format!("The introspection query failed\n\t{messages}"));
+            }
+        };
+
+        let mut types: Vec<GraphQLType> = raw_schema
+            .types
+            .into_iter()
+            .filter_map(|raw_type| {
+                let name = raw_type.name?;
+
+                let fields = raw_type
+                    .fields
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|field| GraphQLField {
+                        name: field.name,
+                        description: field.description,
+                        arguments: field
+                            .args
+                            .into_iter()
+                            .map(|arg| (arg.name, arg.type_ref.to_string()))
+                            .collect(),
+                        type_name: field.type_ref.to_string(),
+                    });
+
+                let input_fields =
+                    raw_type
+                        .input_fields
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|input_field| GraphQLField {
+                            name: input_field.name,
+                            description: input_field.description,
+                            arguments: vec![],
+                            type_name: input_field.type_ref.to_string(),
+                        });
+
+                let enum_values =
+                    raw_type
+                        .enum_values
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|enum_value| GraphQLField {
+                            name: enum_value.name,
+                            description: enum_value.description,
+                            arguments: vec![],
+                            type_name: String::new(),
+                        });
+
+                Some(GraphQLType {
+                    name,
+                    kind: raw_type.kind,
+                    description: raw_type.description,
+                    fields: fields.chain(input_fields).chain(enum_values).collect(),
+                })
+            })
+            .collect();
+
+        // Own types first, introspection types last
+        types.sort_by(|a, b| (a.is_builtin(), &a.name).cmp(&(b.is_builtin(), &b.name)));
+
+        Ok(GraphQLSchema {
+            query_type: raw_schema.query_type.map(|named_type| named_type.name),
+            mutation_type: raw_schema.mutation_type.map(|named_type| named_type.name),
+            subscription_type: raw_schema
+                .subscription_type
+                .map(|named_type| named_type.name),
+            types,
+        })
+    }
+
+    pub fn find_type(&self, name: &str) -> Option<&GraphQLType> {
+        self.types
+            .iter()
+            .find(|graphql_type| graphql_type.name == name)
+    }
+
+    fn find_field(&self, type_name: &str, field_name: &str) -> Option<&GraphQLField> {
+        self.find_type(type_name)?
+            .fields
+            .iter()
+            .find(|field| field.name == field_name)
+    }
+
+    /// Returns the word being typed at the end of the query and the names that could complete it,
+    /// depending on the selection set the cursor is in
+    pub fn completions(&self, query: &str) -> (String, Vec<String>) {
+        let prefix_start = query
+            .char_indices()
+            .rev()
+            .take_while(|(_, char)| char.is_alphanumeric() || *char == '_')
+            .last()
+            .map_or(query.len(), |(index, _)| index);
+
+        let prefix = &query[prefix_start..];
+        let context = QueryContext::parse(self, &query[..prefix_start]);
+
+        let candidates: Vec<String> = if context.expecting_type_condition {
+            self.types
+                .iter()
+                .filter(|graphql_type| {
+                    !graphql_type.is_builtin()
+                        && matches!(graphql_type.kind.as_str(), "OBJECT" | "INTERFACE" | "UNION")
+                })
+                .map(|graphql_type| graphql_type.name.clone())
+                .collect()
+        } else if let Some((type_name, field_name)) = &context.arguments_of {
+            match self.find_field(type_name, field_name) {
+                None => vec![],
+                Some(field) => field
+                    .arguments
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect(),
+            }
+        } else {
+            match context.selection_sets.last() {
+                None => OPERATION_KEYWORDS
+                    .iter()
+                    .map(|keyword| keyword.to_string())
+                    .collect(),
+                Some(None) => vec![],
+                Some(Some(type_name)) => match self.find_type(type_name) {
+                    None => vec![],
+                    Some(graphql_type) => graphql_type
+                        .fields
+                        .iter()
+                        .map(|field| field.name.clone())
+                        .chain([String::from("__typename")])
+                        .collect(),
+                },
+            }
+        };
+
+        let mut candidates: Vec<String> = candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(prefix) && candidate != prefix)
+            .collect();
+
+        candidates.sort();
+        candidates.dedup();
+
+        (prefix.to_string(), candidates)
+    }
+}
+
+/// Where the end of a query is, only as much as needed to complete it
+struct QueryContext {
+    /// Type of each opened selection set, None when it could not be found in the schema
+    selection_sets: Vec<Option<String>>,
+    /// Type and field whose arguments are being written
+    arguments_of: Option<(String, String)>,
+    /// After an `on` keyword
+    expecting_type_condition: bool,
+}
+
+impl QueryContext {
+    fn parse(schema: &GraphQLSchema, query: &str) -> QueryContext {
+        let mut selection_sets: Vec<Option<String>> = vec![];
+        let mut arguments_of: Option<(String, String)> = None;
+        let mut parenthesis_depth = 0;
+
+        let mut operation_type: Option<String> = None;
+        let mut type_condition: Option<String> = None;
+        let mut last_field: Option<String> = None;
+        let mut expecting_type_condition = false;
+        let mut previous_token = String::new();
+
+        for token in tokenize(query) {
+            match token.as_str() {
+                "(" => {
+                    if parenthesis_depth == 0 {
+                        if let (Some(Some(type_name)), Some(field)) =
+                            (selection_sets.last(), &last_field)
+                        {
+                            arguments_of = Some((type_name.clone(), field.clone()));
+                        }
+                    }
+
+                    parenthesis_depth += 1;
+                }
+                ")" => {
+                    parenthesis_depth -= 1;
+
+                    if parenthesis_depth <= 0 {
+                        parenthesis_depth = 0;
+                        arguments_of = None;
+                    }
+                }
+                // Object values in the arguments
+                _ if parenthesis_depth > 0 => {}
+                "{" => {
+                    let selection_set_type = if let Some(type_condition) = type_condition.take() {
+                        Some(type_condition)
+                    } else {
+                        match selection_sets.last() {
+                            // Shorthand query
+                            None => operation_type.take().or_else(|| schema.query_type.clone()),
+                            Some(None) => None,
+                            Some(Some(type_name)) => last_field
+                                .as_ref()
+                                .and_then(|field| schema.find_field(type_name, field))
+                                .map(|field| field.type_name.replace(['[', ']', '!'], "")),
+                        }
+                    };
+
+                    selection_sets.push(selection_set_type);
+                    last_field = None;
+                }
+                "}" => {
+                    selection_sets.pop();
+                    last_field = None;
+                }
+                "on" if previous_token == "..." || selection_sets.is_empty() => {
+                    expecting_type_condition = true;
+                }
+                name if name.starts_with(|char: char| char.is_alphabetic() || char == '_') => {
+                    if expecting_type_condition {
+                        type_condition = Some(name.to_string());
+                        expecting_type_condition = false;
+                    } else if selection_sets.is_empty() {
+                        operation_type = match name {
+                            "query" => schema.query_type.clone(),
+                            "mutation" => schema.mutation_type.clone(),
+                            "subscription" => schema.subscription_type.clone(),
+                            // Operation or fragment name
+                            _ => operation_type,
+                        };
+                    } else if previous_token != "$" && previous_token != "@" {
+                        last_field = Some(name.to_string());
+                    }
+                }
+                _ => {}
+            }
+
+            previous_token = token;
+        }
+
+        QueryContext {
+            selection_sets,
+            arguments_of,
+            expecting_type_condition,
+        }
+    }
+}
+
+/// Split a query into names and punctuators, skipping the comments and the strings
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = vec![];
+    let mut chars = query.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '#' => {
+                for char in chars.by_ref() {
+                    if char == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                while let Some(char) = chars.next() {
+                    match char {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '.' => {
+                if tokens.last().is_some_and(|token| token.starts_with('.')) {
+                    tokens.last_mut().unwrap().push(char);
+                } else {
+                    tokens.push(String::from(char));
+                }
+            }
+            char if char.is_alphanumeric() || char == '_' => {
+                let mut name = String::from(char);
+
+                while let Some(next_char) =
+                    chars.next_if(|next_char| next_char.is_alphanumeric() || *next_char == '_')
+                {
+                    name.push(next_char);
+                }
+
+                tokens.push(name);
+            }
+            char if char.is_whitespace() || char == ',' => {}
+            char => tokens.push(String::from(char)),
+        }
+    }
+
+    tokens
+}
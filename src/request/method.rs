@@ -1,7 +1,12 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-#[derive(Default, Debug, Copy, Clone, EnumString, Display, Serialize, Deserialize)]
+/// Serialized as a plain string, e.g. "GET" or "PROPFIND"
+#[derive(Default, Debug, Clone, PartialEq, EnumString, Display, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[strum(ascii_case_insensitive)]
 pub enum Method {
     #[default]
     #[strum(to_string = "GET")]
@@ -18,22 +23,41 @@ pub enum Method {
     HEAD,
     #[strum(to_string = "OPTIONS")]
     OPTIONS,
+    /// Any other method, e.g. WebDAV ones such as PROPFIND or MKCOL
+    #[strum(default)]
+    Custom(String),
 }
 
 impl Method {
-    pub fn to_reqwest(&self) -> reqwest::Method {
+    /// None if the custom method is not a valid HTTP token
+    pub fn to_reqwest(&self) -> Option<reqwest::Method> {
         match self {
-            Method::GET => reqwest::Method::GET,
-            Method::POST => reqwest::Method::POST,
-            Method::PUT => reqwest::Method::PUT,
-            Method::PATCH => reqwest::Method::PATCH,
-            Method::DELETE => reqwest::Method::DELETE,
-            Method::HEAD => reqwest::Method::HEAD,
-            Method::OPTIONS => reqwest::Method::OPTIONS,
+            Method::GET => Some(reqwest::Method::GET),
+            Method::POST => Some(reqwest::Method::POST),
+            Method::PUT => Some(reqwest::Method::PUT),
+            Method::PATCH => Some(reqwest::Method::PATCH),
+            Method::DELETE => Some(reqwest::Method::DELETE),
+            Method::HEAD => Some(reqwest::Method::HEAD),
+            Method::OPTIONS => Some(reqwest::Method::OPTIONS),
+            Method::Custom(method) => reqwest::Method::from_bytes(method.as_bytes()).ok(),
         }
     }
 }
 
+impl From<String> for Method {
+    /// The standard methods are matched case-insensitively, e.g. "get" from a HAR file.
+    /// Cannot fail thanks to the custom variant
+    fn from(method: String) -> Self {
+        Method::from_str(&method).unwrap()
+    }
+}
+
+impl From<Method> for String {
+    fn from(method: Method) -> Self {
+        method.to_string()
+    }
+}
+
 pub fn next_method(method: &Method) -> Method {
     match *method {
         Method::GET => Method::POST,
@@ -42,6 +66,25 @@ pub fn next_method(method: &Method) -> Method {
         Method::PATCH => Method::DELETE,
         Method::DELETE => Method::HEAD,
         Method::HEAD => Method::OPTIONS,
-        Method::OPTIONS => Method::GET,
+        Method::OPTIONS | Method::Custom(_) => Method::GET,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Method;
+
+    #[test]
+    fn standard_methods_are_case_insensitive() {
+        assert_eq!(Method::from(String::from("get")), Method::GET);
+        assert_eq!(Method::from(String::from("Post")), Method::POST);
+        assert_eq!(Method::from(String::from("OPTIONS")), Method::OPTIONS);
+
+        // Sent as written
+        assert_eq!(
+            Method::from(String::from("propfind")),
+            Method::Custom(String::from("propfind"))
+        );
+        assert_eq!(Method::from(String::from("get")).to_string(), "GET");
     }
 }
@@ -2,8 +2,10 @@ pub mod auth;
 pub mod body;
 pub mod collection;
 pub mod environment;
+pub mod graphql;
 pub mod method;
 pub mod request;
 pub mod response;
 mod scripts;
 pub mod settings;
+pub mod websocket;
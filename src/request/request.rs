@@ -1,12 +1,16 @@
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::task::AbortHandle;
 
 use crate::request::auth::Auth;
 use crate::request::body::ContentType;
+use crate::request::graphql::GraphQLSchema;
 use crate::request::method::Method;
 use crate::request::response::RequestResponse;
 use crate::request::scripts::RequestScripts;
 use crate::request::settings::RequestSettings;
+use crate::request::websocket::WebSocketSession;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Request {
@@ -25,6 +29,18 @@ pub struct Request {
 
     #[serde(skip)]
     pub is_pending: bool,
+
+    /// Task sending the request, used to cancel it while pending
+    #[serde(skip)]
+    pub abort_handle: Option<Arc<AbortHandle>>,
+
+    /// Fetched by introspection, for a GraphQL body
+    #[serde(skip)]
+    pub graphql_schema: Option<Arc<GraphQLSchema>>,
+
+    /// Connection opened by sending a request with a WebSocket URL
+    #[serde(skip)]
+    pub websocket: Option<WebSocketSession>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
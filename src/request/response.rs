@@ -9,6 +9,14 @@ pub struct RequestResponse {
     #[serde(skip)]
     pub status_code: Option<String>,
 
+    /// More than 1 when the request has been retried
+    #[serde(skip)]
+    pub attempts: u32,
+
+    /// Negotiated protocol, e.g. HTTP/1.1 or HTTP/2.0
+    #[serde(skip)]
+    pub http_version: Option<String>,
+
     pub content: Option<ResponseContent>,
 
     pub cookies: Option<String>,
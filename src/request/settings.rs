@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestSettings {
@@ -6,6 +7,171 @@ pub struct RequestSettings {
     pub allow_redirects: bool,
     pub store_received_cookies: bool,
     pub pretty_print_response_content: bool,
+    #[serde(default = "default_true")]
+    pub use_config_tls: bool,
+    /// Only meant for local development, e.g. self-signed certificates
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+    /// Added to the config TLS settings, only editable in the collection file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsSettings>,
+    /// Whole request duration, 0 for none
+    #[serde(default)]
+    pub timeout_ms: u32,
+    /// 0 for none
+    #[serde(default = "default_connect_timeout_ms")]
+    pub connect_timeout_ms: u32,
+    /// 1 for no retry
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled before each next one
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u32,
+    /// Connection errors and timeouts
+    #[serde(default = "default_true")]
+    pub retry_on_connection_errors: bool,
+    #[serde(default = "default_retry_on_status_codes")]
+    pub retry_on_status_codes: Vec<u16>,
+    #[serde(default)]
+    pub http_version: HttpVersion,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpVersion {
+    /// HTTP/2 when negotiated through TLS (ALPN), HTTP/1.1 otherwise
+    #[default]
+    Auto,
+    Http1Only,
+    /// Sends HTTP/2 without negotiation, also allows HTTP/2 without TLS (h2c)
+    Http2PriorKnowledge,
+}
+
+impl Display for HttpVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpVersion::Auto => write!(f, "auto"),
+            HttpVersion::Http1Only => write!(f, "HTTP/1.1 only"),
+            HttpVersion::Http2PriorKnowledge => write!(f, "HTTP/2 prior"),
+        }
+    }
+}
+
+impl HttpVersion {
+    pub fn next(self) -> HttpVersion {
+        match self {
+            HttpVersion::Auto => HttpVersion::Http1Only,
+            HttpVersion::Http1Only => HttpVersion::Http2PriorKnowledge,
+            HttpVersion::Http2PriorKnowledge => HttpVersion::Auto,
+        }
+    }
+
+    pub fn previous(self) -> HttpVersion {
+        match self {
+            HttpVersion::Auto => HttpVersion::Http2PriorKnowledge,
+            HttpVersion::Http1Only => HttpVersion::Auto,
+            HttpVersion::Http2PriorKnowledge => HttpVersion::Http1Only,
+        }
+    }
+}
+
+/// Certificates files, the paths can contain environment keys
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TlsSettings {
+    /// Extra CA certificates or bundles (PEM or DER)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ca_certificates: Vec<String>,
+    /// Client certificate for mTLS, PEM or PKCS12 (.p12, .pfx)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate: Option<String>,
+    /// PEM private key, unless already contained in the client certificate file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<String>,
+    /// PKCS12 only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate_password: Option<String>,
+}
+
+/// Value of a setting, as edited in the request settings popup
+#[derive(Debug, Clone, PartialEq)]
+pub enum Setting {
+    Bool(bool),
+    /// Milliseconds
+    Duration(u32),
+    /// At least 1
+    Count(u32),
+    HttpVersion(HttpVersion),
+    StatusCodes(Vec<u16>),
+}
+
+/// Choices cycled through in the request settings popup
+const STATUS_CODES_CHOICES: [&[u16]; 7] = [
+    &[],
+    &[429],
+    &[503],
+    &[429, 503],
+    &[502, 503, 504],
+    &[429, 502, 503, 504],
+    &[429, 500, 502, 503, 504],
+];
+
+impl Setting {
+    /// Next status codes choice, a list edited in the collection file restarts the cycle
+    pub fn next_status_codes(status_codes: &[u16]) -> Vec<u16> {
+        let next_index = match STATUS_CODES_CHOICES.iter().position(|c| *c == status_codes) {
+            Some(index) => (index + 1) % STATUS_CODES_CHOICES.len(),
+            None => 0,
+        };
+
+        STATUS_CODES_CHOICES[next_index].to_vec()
+    }
+
+    pub fn previous_status_codes(status_codes: &[u16]) -> Vec<u16> {
+        let previous_index = match STATUS_CODES_CHOICES.iter().position(|c| *c == status_codes) {
+            Some(0) | None => STATUS_CODES_CHOICES.len() - 1,
+            Some(index) => index - 1,
+        };
+
+        STATUS_CODES_CHOICES[previous_index].to_vec()
+    }
+}
+
+impl Display for Setting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Setting::Bool(value) => write!(f, "{value}"),
+            Setting::Duration(0) => write!(f, "none"),
+            Setting::Duration(milliseconds) => write!(f, "{milliseconds} ms"),
+            Setting::Count(value) => write!(f, "{value}"),
+            Setting::HttpVersion(http_version) => write!(f, "{http_version}"),
+            Setting::StatusCodes(status_codes) if status_codes.is_empty() => write!(f, "none"),
+            Setting::StatusCodes(status_codes) => {
+                let status_codes: Vec<String> =
+                    status_codes.iter().map(|code| code.to_string()).collect();
+                write!(f, "{}", status_codes.join(", "))
+            }
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_connect_timeout_ms() -> u32 {
+    10_000
+}
+
+fn default_max_attempts() -> u32 {
+    1
+}
+
+fn default_retry_backoff_ms() -> u32 {
+    1_000
+}
+
+fn default_retry_on_status_codes() -> Vec<u16> {
+    vec![429, 502, 503, 504]
 }
 
 impl Default for RequestSettings {
@@ -15,35 +181,101 @@ impl Default for RequestSettings {
             allow_redirects: true,
             store_received_cookies: true,
             pretty_print_response_content: true,
+            use_config_tls: true,
+            accept_invalid_certs: false,
+            tls: None,
+            timeout_ms: 0,
+            connect_timeout_ms: default_connect_timeout_ms(),
+            max_attempts: default_max_attempts(),
+            retry_backoff_ms: default_retry_backoff_ms(),
+            retry_on_connection_errors: true,
+            retry_on_status_codes: default_retry_on_status_codes(),
+            http_version: HttpVersion::Auto,
         }
     }
 }
 
 impl RequestSettings {
-    pub fn to_vec(&self) -> Vec<(String, bool)> {
+    pub fn to_vec(&self) -> Vec<(String, Setting)> {
         vec![
-            (String::from("Use config proxy"), self.use_config_proxy),
-            (String::from("Allow redirects"), self.allow_redirects),
+            (
+                String::from("Use config proxy"),
+                Setting::Bool(self.use_config_proxy),
+            ),
+            (
+                String::from("Allow redirects"),
+                Setting::Bool(self.allow_redirects),
+            ),
             (
                 String::from("Store received cookies"),
-                self.store_received_cookies,
+                Setting::Bool(self.store_received_cookies),
             ),
             (
                 String::from("Pretty print response content"),
-                self.pretty_print_response_content,
+                Setting::Bool(self.pretty_print_response_content),
+            ),
+            (
+                String::from("Use config TLS"),
+                Setting::Bool(self.use_config_tls),
+            ),
+            (
+                String::from("Accept invalid certificates"),
+                Setting::Bool(self.accept_invalid_certs),
+            ),
+            (String::from("Timeout"), Setting::Duration(self.timeout_ms)),
+            (
+                String::from("Connect timeout"),
+                Setting::Duration(self.connect_timeout_ms),
+            ),
+            (
+                String::from("Max attempts"),
+                Setting::Count(self.max_attempts),
+            ),
+            (
+                String::from("Retry backoff"),
+                Setting::Duration(self.retry_backoff_ms),
+            ),
+            (
+                String::from("Retry on connection errors"),
+                Setting::Bool(self.retry_on_connection_errors),
+            ),
+            (
+                String::from("Retry on status codes"),
+                Setting::StatusCodes(self.retry_on_status_codes.clone()),
+            ),
+            (
+                String::from("HTTP version"),
+                Setting::HttpVersion(self.http_version),
             ),
         ]
     }
 
-    pub fn update_from_vec(&mut self, vec: &Vec<(String, bool)>) {
+    pub fn update_from_vec(&mut self, vec: &Vec<(String, Setting)>) {
         for (setting_name, setting_value) in vec {
-            match setting_name.as_str() {
-                "Use config proxy" => self.use_config_proxy = *setting_value,
-                "Allow redirects" => self.allow_redirects = *setting_value,
-                "Store received cookies" => self.store_received_cookies = *setting_value,
-                "Pretty print response content" => {
-                    self.pretty_print_response_content = *setting_value
+            match (setting_name.as_str(), setting_value.clone()) {
+                ("Use config proxy", Setting::Bool(value)) => self.use_config_proxy = value,
+                ("Allow redirects", Setting::Bool(value)) => self.allow_redirects = value,
+                ("Store received cookies", Setting::Bool(value)) => {
+                    self.store_received_cookies = value
+                }
+                ("Pretty print response content", Setting::Bool(value)) => {
+                    self.pretty_print_response_content = value
+                }
+                ("Use config TLS", Setting::Bool(value)) => self.use_config_tls = value,
+                ("Accept invalid certificates", Setting::Bool(value)) => {
+                    self.accept_invalid_certs = value
+                }
+                ("Timeout", Setting::Duration(value)) => self.timeout_ms = value,
+                ("Connect timeout", Setting::Duration(value)) => self.connect_timeout_ms = value,
+                ("Max attempts", Setting::Count(value)) => self.max_attempts = value,
+                ("Retry backoff", Setting::Duration(value)) => self.retry_backoff_ms = value,
+                ("Retry on connection errors", Setting::Bool(value)) => {
+                    self.retry_on_connection_errors = value
+                }
+                ("Retry on status codes", Setting::StatusCodes(value)) => {
+                    self.retry_on_status_codes = value
                 }
+                ("HTTP version", Setting::HttpVersion(value)) => self.http_version = value,
                 _ => {}
             }
         }
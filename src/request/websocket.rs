@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter};
+
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Open connection of a request with a `ws://` or `wss://` URL
+#[derive(Debug, Clone)]
+pub struct WebSocketSession {
+    pub status: WebSocketStatus,
+    /// Oldest first
+    pub messages: Vec<WebSocketMessage>,
+    /// Frames to write, none once the connection is closing
+    pub sender: Option<UnboundedSender<Message>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebSocketStatus {
+    Open,
+    /// A close frame has been sent, waiting for the server one
+    Closing,
+    Closed {
+        code: Option<u16>,
+        reason: String,
+    },
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct WebSocketMessage {
+    pub direction: WebSocketDirection,
+    /// Since the connection has been opened, e.g. +1.204s
+    pub time: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WebSocketDirection {
+    Sent,
+    Received,
+}
+
+impl Display for WebSocketStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebSocketStatus::Open => write!(f, "OPEN"),
+            WebSocketStatus::Closing => write!(f, "CLOSING"),
+            WebSocketStatus::Closed { code: None, reason } if reason.is_empty() => {
+                write!(f, "CLOSED")
+            }
+            WebSocketStatus::Closed { code: None, reason } => write!(f, "CLOSED ({reason})"),
+            WebSocketStatus::Closed {
+                code: Some(code),
+                reason,
+            } if reason.is_empty() => {
+                write!(f, "CLOSED {code}")
+            }
+            WebSocketStatus::Closed {
+                code: Some(code),
+                reason,
+            } => write!(f, "CLOSED {code} ({reason})"),
+            WebSocketStatus::Error(error) => write!(f, "ERROR ({error})"),
+        }
+    }
+}
+
+impl WebSocketSession {
+    pub fn is_open(&self) -> bool {
+        self.status == WebSocketStatus::Open
+    }
+}
+
+pub fn is_websocket_url(url: &str) -> bool {
+    let url = url.trim_start().to_lowercase();
+
+    url.starts_with("ws://") || url.starts_with("wss://")
+}
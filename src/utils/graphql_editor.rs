@@ -0,0 +1,21 @@
+use tui_textarea::TextArea;
+
+/// The GraphQL query is edited in the body text area, the variables in their own one
+#[derive(Default)]
+pub struct GraphQLEditor<'a> {
+    pub variables_text_area: TextArea<'a>,
+    /// 0 for the query, 1 for the variables
+    pub selection: u16,
+    /// Last completion candidates, displayed under the query
+    pub completions: Vec<String>,
+}
+
+impl GraphQLEditor<'_> {
+    pub fn change_selection(&mut self) {
+        self.selection = match self.selection {
+            0 => 1,
+            1 => 0,
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use ratatui::widgets::ListState;
+
+use crate::request::graphql::GraphQLSchema;
+
+#[derive(Default)]
+pub struct GraphQLSchemaPopup {
+    pub schema: Option<Arc<GraphQLSchema>>,
+    pub types_state: ListState,
+    /// Scroll of the selected type fields
+    pub scroll: u16,
+}
+
+impl GraphQLSchemaPopup {
+    pub fn next_type(&mut self) {
+        let types_len = self.types_len();
+
+        if types_len == 0 {
+            return;
+        }
+
+        let selection = match self.types_state.selected() {
+            Some(selection) if selection + 1 < types_len => selection + 1,
+            _ => 0,
+        };
+
+        self.types_state.select(Some(selection));
+        self.scroll = 0;
+    }
+
+    pub fn previous_type(&mut self) {
+        let types_len = self.types_len();
+
+        if types_len == 0 {
+            return;
+        }
+
+        let selection = match self.types_state.selected() {
+            Some(selection) if selection > 0 => selection - 1,
+            _ => types_len - 1,
+        };
+
+        self.types_state.select(Some(selection));
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        let fields_len = match (&self.schema, self.types_state.selected()) {
+            (Some(schema), Some(selection)) => schema.types[selection].fields.len(),
+            _ => 0,
+        };
+
+        if (self.scroll as usize) + 1 < fields_len {
+            self.scroll += 1;
+        }
+    }
+
+    fn types_len(&self) -> usize {
+        match &self.schema {
+            None => 0,
+            Some(schema) => schema.types.len(),
+        }
+    }
+}
@@ -3,6 +3,8 @@ pub mod choice_popup;
 pub mod colors;
 pub mod cookie_table;
 pub mod cookies_popup;
+pub mod graphql_editor;
+pub mod graphql_schema_popup;
 pub mod help_popup;
 pub mod script_console;
 pub mod settings_popup;
@@ -17,15 +19,30 @@ pub mod validation_popup;
 pub mod vim_emulation;
 
 
+use crate::request::auth;
 use crate::request::auth::Auth;
 use crate::request::auth::Auth::*;
+use crate::request::auth::DEFAULT_OAUTH2_REDIRECT_URI;
 
 
 pub fn next_auth(auth: &Auth) -> Auth {
     match auth {
         NoAuth => BasicAuth(String::new(), String::new()),
         BasicAuth(_, _) => BearerToken(String::new()),
-        BearerToken(_) => NoAuth,
+        BearerToken(_) => Digest(String::new(), String::new()),
+        Digest(_, _) => ApiKey(auth::ApiKey::default()),
+        ApiKey(_) => AwsSigV4(auth::AwsSigV4::default()),
+        AwsSigV4(_) => Jwt(auth::Jwt {
+            claims: String::from("{}"),
+            ..Default::default()
+        }),
+        Jwt(_) => OAuth1(auth::OAuth1::default()),
+        OAuth1(_) => OAuth2(auth::OAuth2 {
+            redirect_uri: String::from(DEFAULT_OAUTH2_REDIRECT_URI),
+            ..Default::default()
+        }),
+        OAuth2(_) => InheritFromCollection,
+        InheritFromCollection => NoAuth,
     }
 }
 
@@ -41,7 +58,11 @@ pub fn next_content_type(content_type: &ContentType) -> ContentType {
         Json(body) => Xml(body.to_string()),
         Xml(body) => Html(body.to_string()),
         Html(body) => Javascript(body.to_string()),
-        Javascript(_) => NoBody,
+        Javascript(_) => GraphQL {
+            query: String::new(),
+            variables: String::new(),
+        },
+        GraphQL { .. } => NoBody,
     }
 }
 
@@ -1,6 +1,10 @@
+use crate::request::settings::Setting;
+
+const DURATION_STEP: u32 = 1_000;
+
 #[derive(Default)]
 pub struct SettingsPopup {
-    pub settings: Vec<(String, bool)>,
+    pub settings: Vec<(String, Setting)>,
     pub selection: usize,
 }
 
@@ -21,7 +25,33 @@ impl SettingsPopup {
         }
     }
 
-    pub fn toggle_setting(&mut self) {
-        self.settings[self.selection].1 = !self.settings[self.selection].1
+    /// Toggle a boolean setting, decrease a number or select the previous choice
+    pub fn previous_value(&mut self) {
+        match &mut self.settings[self.selection].1 {
+            Setting::Bool(value) => *value = !*value,
+            Setting::Duration(milliseconds) => {
+                *milliseconds = milliseconds.saturating_sub(DURATION_STEP)
+            }
+            Setting::Count(value) => *value = value.saturating_sub(1).max(1),
+            Setting::HttpVersion(http_version) => *http_version = http_version.previous(),
+            Setting::StatusCodes(status_codes) => {
+                *status_codes = Setting::previous_status_codes(status_codes)
+            }
+        }
+    }
+
+    /// Toggle a boolean setting, increase a number or select the next choice
+    pub fn next_value(&mut self) {
+        match &mut self.settings[self.selection].1 {
+            Setting::Bool(value) => *value = !*value,
+            Setting::Duration(milliseconds) => {
+                *milliseconds = milliseconds.saturating_add(DURATION_STEP)
+            }
+            Setting::Count(value) => *value = value.saturating_add(1),
+            Setting::HttpVersion(http_version) => *http_version = http_version.next(),
+            Setting::StatusCodes(status_codes) => {
+                *status_codes = Setting::next_status_codes(status_codes)
+            }
+        }
     }
 }
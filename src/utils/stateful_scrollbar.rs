@@ -34,4 +34,15 @@ impl StatefulScrollbar {
 
         self.state = self.state.content_length(lines);
     }
+
+    /// Keep the current scroll, for a content that keeps growing
+    pub fn set_max_scroll(&mut self, lines: usize) {
+        self.max_scroll = lines.saturating_sub(1) as u16;
+        self.scroll = self.scroll.min(self.max_scroll);
+
+        self.state = self
+            .state
+            .content_length(lines)
+            .position(self.scroll as usize);
+    }
 }
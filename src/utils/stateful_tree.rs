@@ -4,7 +4,8 @@ use tui_tree_widget::{TreeItem, TreeState};
 pub struct StatefulTree<'a> {
     pub state: TreeState<usize>,
     pub items: Vec<TreeItem<'a, usize>>,
-    pub selected: Option<(usize, usize)>,
+    /// Tree path of the selected request, starting with its collection index
+    pub selected: Option<Vec<usize>>,
 }
 
 impl StatefulTree<'_> {
@@ -17,10 +18,78 @@ impl StatefulTree<'_> {
     }
 
     pub fn set_selected(&mut self) {
-        self.selected = Some((self.state.selected()[0], self.state.selected()[1]));
+        self.selected = Some(self.state.selected().to_vec());
     }
 
     pub fn set_unselected(&mut self) {
         self.selected = None;
     }
+
+    /// Exchange the opened state of two nodes and of their children
+    pub fn swap_opened(&mut self, first: &[usize], second: &[usize]) {
+        let opened = self.state.opened().clone();
+
+        self.state.close_all();
+
+        for path in opened {
+            let new_path = if path.starts_with(first) {
+                [second, &path[first.len()..]].concat()
+            } else if path.starts_with(second) {
+                [first, &path[second.len()..]].concat()
+            } else {
+                path
+            };
+
+            self.state.open(new_path);
+        }
+    }
+
+    /// Follow a node moved to another parent, its children and the nodes shifted by the move.
+    /// The destination path is the one of the node once moved
+    pub fn move_node(&mut self, from: &[usize], to: &[usize]) {
+        let opened = self.state.opened().clone();
+
+        self.state.close_all();
+
+        for path in opened {
+            self.state.open(moved_tree_path(&path, from, to));
+        }
+
+        let selection = moved_tree_path(self.state.selected(), from, to);
+        self.state.select(selection);
+
+        if let Some(selected) = &self.selected {
+            self.selected = Some(moved_tree_path(selected, from, to));
+        }
+    }
+}
+
+fn moved_tree_path(path: &[usize], from: &[usize], to: &[usize]) -> Vec<usize> {
+    if path.starts_with(from) {
+        return [to, &path[from.len()..]].concat();
+    }
+
+    let mut path = path.to_vec();
+
+    // The next siblings of the removed node move back
+    let (removed_index, removed_parent) = from.split_last().unwrap();
+
+    if path.len() > removed_parent.len()
+        && path.starts_with(removed_parent)
+        && path[removed_parent.len()] > *removed_index
+    {
+        path[removed_parent.len()] -= 1;
+    }
+
+    // The next siblings of the inserted node move forward
+    let (inserted_index, inserted_parent) = to.split_last().unwrap();
+
+    if path.len() > inserted_parent.len()
+        && path.starts_with(inserted_parent)
+        && path[inserted_parent.len()] >= *inserted_index
+    {
+        path[inserted_parent.len()] += 1;
+    }
+
+    path
 }